@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::merkle::LeafCodec;
+use crate::state::{AliasAccount, AttestedChainMapping, ChainMappingProof, ChainMappingTree, ChainMappingVerification, ReputationConfig};
+
+fn load<T: AccountDeserialize>(info: &AccountInfo, error: ErrorCode) -> Result<T> {
+    require_keys_eq!(*info.owner, crate::ID, error);
+    T::try_deserialize(&mut &info.data.borrow()[..])
+}
+
+/// Checks, in the caller's own program, that `proof` shows `mapping` belongs
+/// to `alias_account` under its current root, as `verify_chain_mapping` does.
+/// Recent roots are not accepted, so a mapping removed moments ago never
+/// passes. Callers still decide which alias they expect `alias_account` to be.
+pub fn verify_chain_mapping(alias_account: &AccountInfo, chain_mapping_tree: &AccountInfo, mapping: &AttestedChainMapping, proof: &ChainMappingProof) -> Result<ChainMappingVerification> {
+    LeafCodec::require_version(proof.leaf_version)?;
+    let alias = load::<AliasAccount>(alias_account, ErrorCode::InvalidChainMapping)?;
+    let tree = load::<ChainMappingTree>(chain_mapping_tree, ErrorCode::InvalidChainMapping)?;
+    require_keys_eq!(tree.alias, alias_account.key(), ErrorCode::InvalidChainMapping);
+
+    let now = Clock::get()?.unix_timestamp;
+    Ok(tree.verify(&alias, mapping, proof, 0, now))
+}
+
+/// Fails unless `alias_account` is usable and its general reputation,
+/// decayed to now under its suffix's config, is at least `min`. Pass the
+/// suffix's `ReputationConfig` address as `reputation_config`, created or
+/// not; the `require_min_reputation` instruction does the same check over
+/// CPI.
+pub fn require_min_reputation(alias_account: &AccountInfo, reputation_config: &AccountInfo, min: i64) -> Result<()> {
+    let alias = load::<AliasAccount>(alias_account, ErrorCode::InvalidAlias)?;
+    let (config_key, _) = Pubkey::find_program_address(&[b"reputation_config", alias.product_suffix.as_bytes()], &crate::ID);
+    require_keys_eq!(reputation_config.key(), config_key, ErrorCode::InvalidReputationConfig);
+    let config = ReputationConfig::load_or_default(reputation_config)?;
+    alias.require_min_reputation(&config, min, Clock::get()?.unix_timestamp)
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions as sysvar_instructions};
+use crate::error::EchoIDError as ErrorCode;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<usize> {
+    data.get(offset..offset + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize)
+}
+
+/// Returns true if `data` is an ed25519 program payload whose signatures
+/// include one by `pubkey` over exactly `message`, with all offsets pointing
+/// into the same instruction.
+fn contains_signature(data: &[u8], pubkey: &Pubkey, message: &[u8]) -> bool {
+    let Some(&count) = data.first() else {
+        return false;
+    };
+
+    (0..count as usize).any(|i| {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+        let field = |n: usize| read_u16(data, start + n * 2);
+        let (Some(sig_ix), Some(pk_offset), Some(pk_ix), Some(msg_offset), Some(msg_size), Some(msg_ix)) =
+            (field(1), field(2), field(3), field(4), field(5), field(6))
+        else {
+            return false;
+        };
+        let this_ix = u16::MAX as usize;
+        if sig_ix != this_ix || pk_ix != this_ix || msg_ix != this_ix {
+            return false;
+        }
+
+        data.get(pk_offset..pk_offset + PUBKEY_SIZE) == Some(pubkey.as_ref())
+            && data.get(msg_offset..msg_offset + msg_size) == Some(message)
+    })
+}
+
+/// Checks that the transaction carries an ed25519 program instruction in
+/// which `pubkey` signed `message`. The ed25519 program itself rejects the
+/// transaction if the signature is invalid, so finding the instruction is
+/// sufficient proof.
+pub fn verify_ed25519_signature(instructions: &AccountInfo, pubkey: &Pubkey, message: &[u8]) -> Result<()> {
+    require_keys_eq!(instructions.key(), sysvar_instructions::ID, ErrorCode::InvalidSignature);
+
+    let current = sysvar_instructions::load_current_index_checked(instructions)? as usize;
+    let mut index = 0;
+    while let Ok(ix) = sysvar_instructions::load_instruction_at_checked(index, instructions) {
+        if index != current && ix.program_id == ed25519_program::ID && contains_signature(&ix.data, pubkey, message) {
+            return Ok(());
+        }
+        index += 1;
+    }
+
+    err!(ErrorCode::SignatureVerificationFailed)
+}
@@ -95,4 +95,346 @@ pub enum EchoIDError {
 
     #[msg("Invalid user")]
     InvalidUser,
+
+    #[msg("Product owner co-signature required")]
+    ProductOwnerSignatureRequired,
+
+    #[msg("No pending transfer for this alias")]
+    NoPendingTransfer,
+
+    #[msg("Signer is not the pending owner")]
+    InvalidPendingOwner,
+
+    #[msg("Alias has expired")]
+    AliasExpired,
+
+    #[msg("Alias is still within its term or grace period")]
+    AliasNotReclaimable,
+
+    #[msg("A registration commitment is required for this suffix")]
+    CommitmentRequired,
+
+    #[msg("Revealed values do not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Commitment is too recent to be revealed")]
+    CommitmentTooNew,
+
+    #[msg("Commitment has expired")]
+    CommitmentExpired,
+
+    #[msg("Username is too short")]
+    UsernameTooShort,
+
+    #[msg("Username is too long")]
+    UsernameTooLong,
+
+    #[msg("Username must be lowercase")]
+    UsernameNotLowercase,
+
+    #[msg("Username contains an invalid character")]
+    UsernameInvalidCharacter,
+
+    #[msg("Username contains a confusable non-ASCII character")]
+    UsernameConfusableCharacter,
+
+    #[msg("Username separators cannot lead, trail or repeat")]
+    UsernameInvalidSeparator,
+
+    #[msg("Name is reserved")]
+    NameReserved,
+
+    #[msg("Record key is empty or too long")]
+    InvalidRecordKey,
+
+    #[msg("Record value is too long")]
+    RecordValueTooLong,
+
+    #[msg("Maximum number of records reached")]
+    MaxRecordsReached,
+
+    #[msg("Record not found")]
+    RecordNotFound,
+
+    #[msg("Registration must be approved by the product owner")]
+    RegistrationNotApproved,
+
+    #[msg("Voucher has expired")]
+    VoucherExpired,
+
+    #[msg("Voucher nonce has already been used")]
+    VoucherAlreadyUsed,
+
+    #[msg("Alias is frozen")]
+    AliasFrozen,
+
+    #[msg("Alias account is already at the latest version")]
+    AliasAccountUpToDate,
+
+    #[msg("Unsupported alias account layout")]
+    UnsupportedAccountVersion,
+
+    #[msg("Invalid guardian set or threshold")]
+    InvalidGuardianSet,
+
+    #[msg("Signer is not a guardian of this alias")]
+    NotAGuardian,
+
+    #[msg("A recovery is already in progress")]
+    RecoveryInProgress,
+
+    #[msg("No recovery has been initiated")]
+    RecoveryNotInitiated,
+
+    #[msg("Guardian has already approved this recovery")]
+    RecoveryAlreadyApproved,
+
+    #[msg("Recovery has not reached the guardian threshold")]
+    RecoveryThresholdNotMet,
+
+    #[msg("Recovery timelock has not elapsed")]
+    RecoveryTimelockActive,
+
+    #[msg("Reputation share must be at most 10000 basis points")]
+    InvalidReputationShare,
+
+    #[msg("Token account and token program are required to burn the alias token")]
+    AliasTokenAccountsRequired,
+
+    #[msg("Royalty plus protocol fee must not exceed 10000 basis points")]
+    InvalidRoyalty,
+
+    #[msg("Aliases under this suffix are not transferable")]
+    AliasNotTransferable,
+
+    #[msg("Listing price must be greater than zero")]
+    InvalidListingPrice,
+
+    #[msg("Listing price does not match the expected price")]
+    ListingPriceMismatch,
+
+    #[msg("Key is already registered for this purpose")]
+    DuplicateAliasKey,
+
+    #[msg("Maximum number of alias keys reached")]
+    MaxAliasKeysReached,
+
+    #[msg("Key not found")]
+    AliasKeyNotFound,
+
+    #[msg("Session scope must be a non-empty subset of the known scopes")]
+    InvalidSessionScope,
+
+    #[msg("Session expiry must be in the future")]
+    InvalidSessionExpiry,
+
+    #[msg("Linked aliases must be distinct and ordered by address")]
+    InvalidAliasLink,
+
+    #[msg("The alias owner has renounced product owner control")]
+    ProductOwnerControlRenounced,
+
+    #[msg("Referrer alias is missing or does not match the registration")]
+    InvalidReferrer,
+
+    #[msg("Merkle proof does not match the committed import root")]
+    InvalidImportProof,
+
+    #[msg("Alias cannot be transferred until its transfer lock has passed")]
+    TransferLocked,
+
+    #[msg("Display name is too long")]
+    DisplayNameTooLong,
+
+    #[msg("Display name does not normalize to the username")]
+    DisplayNameMismatch,
+
+    #[msg("EVM addresses must be 20 bytes")]
+    InvalidEvmAddress,
+
+    #[msg("Solana addresses must be 32 bytes")]
+    InvalidSolanaAddress,
+
+    #[msg("Cosmos addresses must be valid bech32 with a lowercase prefix")]
+    InvalidCosmosAddress,
+
+    #[msg("Bitcoin addresses must be valid base58 or bc/tb bech32")]
+    InvalidBitcoinAddress,
+
+    #[msg("Aptos addresses must be 32 bytes")]
+    InvalidAptosAddress,
+
+    #[msg("Solana chain mappings must be added with an ownership proof")]
+    ChainMappingProofRequired,
+
+    #[msg("Root history depth must be between 1 and the history capacity")]
+    InvalidRootHistoryDepth,
+
+    #[msg("Chain mapping registry is out of sync with the alias's mappings")]
+    ChainMappingRegistryMismatch,
+
+    #[msg("Chain mapping hash can only be chosen before any mapping is added")]
+    ChainMappingHashLocked,
+
+    #[msg("Proof was built for a different chain mapping leaf encoding")]
+    LeafVersionMismatch,
+
+    #[msg("Suffix config exceeds the protocol limits")]
+    InvalidSuffixConfig,
+
+    #[msg("Chain mapping label is too long")]
+    ChainMappingLabelTooLong,
+
+    #[msg("Reputation authority weight must be between 1 and 10000 basis points")]
+    InvalidReputationWeight,
+
+    #[msg("Alias reputation changed too much within the rate limit window")]
+    ReputationRateLimited,
+
+    #[msg("Reputation category does not exist or is invalid")]
+    InvalidReputationCategory,
+
+    #[msg("Invalid endorsement")]
+    InvalidEndorsement,
+
+    #[msg("Alias has given the maximum number of endorsements")]
+    EndorsementQuotaExceeded,
+
+    #[msg("Invalid reputation stake")]
+    InvalidStake,
+
+    #[msg("Reputation stake is still locked")]
+    StakeLocked,
+
+    #[msg("Invalid slash percentage")]
+    InvalidSlash,
+
+    #[msg("Invalid reputation dispute")]
+    InvalidDispute,
+
+    #[msg("Reputation does not change the badge tier")]
+    BadgeUnchanged,
+
+    #[msg("Invalid reputation threshold proof")]
+    InvalidThresholdProof,
+
+    #[msg("Reputation authority has not posted its bond")]
+    ReputationAuthorityUnbonded,
+
+    #[msg("Alias reputation is below the required minimum")]
+    InsufficientReputation,
+
+    #[msg("Alias is quarantined")]
+    AliasQuarantined,
+
+    #[msg("Alias is not quarantined")]
+    AliasNotQuarantined,
+
+    #[msg("Quarantine can only be lifted early on appeal")]
+    RehabilitationPending,
+
+    #[msg("Reputation config exceeds the protocol limits")]
+    InvalidReputationConfig,
+
+    #[msg("Invalid reputation delegation")]
+    InvalidDelegation,
+
+    #[msg("Invalid reputation boost")]
+    InvalidBoost,
+
+    #[msg("Product owner is still active")]
+    ProductOwnerActive,
+
+    #[msg("Product owner cannot be reactivated yet")]
+    ReactivationCooldown,
+
+    #[msg("Suffix is frozen")]
+    SuffixFrozen,
+
+    #[msg("Product owner has reached its suffix limit")]
+    TooManySuffixes,
+
+    #[msg("Suffix is paused")]
+    SuffixPaused,
+
+    #[msg("Suffix is not paused")]
+    SuffixNotPaused,
+
+    #[msg("Registrar has used up its quota")]
+    RegistrarQuotaExceeded,
+
+    #[msg("Registrar has expired")]
+    RegistrarExpired,
+
+    #[msg("Registration fee cut must not exceed 10000 basis points")]
+    InvalidFeeBps,
+
+    #[msg("Suffix treasury holds less than the requested amount")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Fee mint, token program and token accounts are required for token fees")]
+    FeeTokenAccountsRequired,
+
+    #[msg("Fee mint does not match the suffix config")]
+    InvalidFeeMint,
+
+    #[msg("Fee token account has the wrong mint or owner")]
+    InvalidFeeTokenAccount,
+
+    #[msg("Suffix has reached its total registration quota")]
+    RegistrationQuotaExceeded,
+
+    #[msg("Suffix has reached its daily registration limit")]
+    RegistrationRateLimited,
+
+    #[msg("Suffix must be 2-16 lowercase letters or digits")]
+    InvalidSuffixName,
+
+    #[msg("Suffix is reserved")]
+    SuffixReserved,
+
+    #[msg("Product owner still holds suffixes")]
+    ProductOwnerHasSuffixes,
+
+    #[msg("Owner is not on the suffix's allowlist")]
+    NotAllowlisted,
+
+    #[msg("Product owner profile field is too long")]
+    ProductOwnerProfileTooLong,
+
+    #[msg("Suffix lease has expired")]
+    SuffixExpired,
+
+    #[msg("Suffix lease is past its grace period and can no longer be renewed")]
+    SuffixLeaseLapsed,
+
+    #[msg("Suffix lease has not lapsed past its grace period")]
+    SuffixLeaseActive,
+
+    #[msg("Sub-suffix path is too long")]
+    SuffixPathTooLong,
+
+    #[msg("Suffix requires a registration proof")]
+    RegistrationProofRequired,
+
+    #[msg("Invalid registration proof")]
+    InvalidRegistrationProof,
+
+    #[msg("Guardian set was nominated by a previous owner")]
+    StaleGuardianSet,
+
+    #[msg("Alias token has already been minted")]
+    AliasTokenAlreadyMinted,
+
+    #[msg("Account is already at the latest layout")]
+    AccountLayoutUpToDate,
+
+    #[msg("Listing belongs to an earlier alias at this address")]
+    StaleListing,
+
+    #[msg("Alias is listed for sale; cancel the listing first")]
+    AliasListed,
+
+    #[msg("Registration consent or approval signature has expired")]
+    RegistrationSignatureExpired,
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::pubkey;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::mint_alias_token::TOKEN_2022_PROGRAM_ID;
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+const IX_TRANSFER_CHECKED: u8 = 12;
+// Offsets into the base mint and token account layouts, which Token-2022
+// shares with the original token program.
+const MINT_DECIMALS_OFFSET: usize = 44;
+const TOKEN_ACCOUNT_OWNER_END: usize = 64;
+
+/// The payer's side of a fee charged in a suffix's `fee_mint`, checked once
+/// and then used for every recipient's share.
+pub struct FeeTokenPayer<'a, 'info> {
+    token_program: &'a AccountInfo<'info>,
+    mint: &'a AccountInfo<'info>,
+    source: &'a AccountInfo<'info>,
+    authority: &'a AccountInfo<'info>,
+    decimals: u8,
+}
+
+impl<'a, 'info> FeeTokenPayer<'a, 'info> {
+    /// Checks that `mint` is `fee_mint` under either token program, with
+    /// `source` being the token account `authority` pays from.
+    pub fn new(
+        fee_mint: &Pubkey,
+        mint: Option<&'a AccountInfo<'info>>,
+        token_program: Option<&'a AccountInfo<'info>>,
+        source: Option<&'a AccountInfo<'info>>,
+        authority: &'a AccountInfo<'info>,
+    ) -> Result<Self> {
+        let (Some(mint), Some(token_program), Some(source)) = (mint, token_program, source) else {
+            return err!(ErrorCode::FeeTokenAccountsRequired);
+        };
+        require_keys_eq!(mint.key(), *fee_mint, ErrorCode::InvalidFeeMint);
+        require!(
+            token_program.key() == TOKEN_PROGRAM_ID || token_program.key() == TOKEN_2022_PROGRAM_ID,
+            ErrorCode::InvalidFeeMint
+        );
+        require_keys_eq!(*mint.owner, token_program.key(), ErrorCode::InvalidFeeMint);
+        let decimals = *mint.try_borrow_data()?.get(MINT_DECIMALS_OFFSET).ok_or(ErrorCode::InvalidFeeMint)?;
+
+        Ok(Self { token_program, mint, source, authority, decimals })
+    }
+
+    /// Transfers `amount` into `destination`, which must be a token account
+    /// of the fee mint owned by `recipient`.
+    pub fn pay(&self, destination: Option<&AccountInfo<'info>>, recipient: &Pubkey, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let destination = destination.ok_or(ErrorCode::FeeTokenAccountsRequired)?;
+        require_keys_eq!(*destination.owner, self.token_program.key(), ErrorCode::InvalidFeeTokenAccount);
+        {
+            let data = destination.try_borrow_data()?;
+            require!(
+                data.len() >= TOKEN_ACCOUNT_OWNER_END
+                    && data[..32] == self.mint.key().to_bytes()
+                    && data[32..TOKEN_ACCOUNT_OWNER_END] == recipient.to_bytes(),
+                ErrorCode::InvalidFeeTokenAccount
+            );
+        }
+
+        let mut data = vec![IX_TRANSFER_CHECKED];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(self.decimals);
+        invoke(
+            &Instruction::new_with_bytes(
+                self.token_program.key(),
+                &data,
+                vec![
+                    AccountMeta::new(self.source.key(), false),
+                    AccountMeta::new_readonly(self.mint.key(), false),
+                    AccountMeta::new(destination.key(), false),
+                    AccountMeta::new_readonly(self.authority.key(), true),
+                ],
+            ),
+            &[self.source.clone(), self.mint.clone(), destination.clone(), self.authority.clone()],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct AcceptOwnerKeyRotation<'info> {
+    pub new_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.pending_owner == Some(new_owner.key()) @ ErrorCode::Unauthorized,
+        constraint = !suffix_account.is_frozen @ ErrorCode::SuffixFrozen
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+}
+
+pub fn handler(ctx: Context<AcceptOwnerKeyRotation>, _suffix: String) -> Result<()> {
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    let previous_owner = suffix_account.owner;
+    suffix_account.owner = ctx.accounts.new_owner.key();
+    suffix_account.pending_owner = None;
+
+    emit!(ProductOwnerKeyRotated {
+        suffix: suffix_account.suffix.clone(),
+        previous_owner,
+        owner: suffix_account.owner,
+    });
+
+    Ok(())
+}
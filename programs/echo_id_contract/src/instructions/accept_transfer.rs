@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::instructions::set_primary_alias::clear_reverse_record;
+use crate::instructions::transfer_alias::transfer_reputation;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct AcceptTransfer<'info> {
+    pub new_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.pending_owner.is_some() @ ErrorCode::NoPendingTransfer,
+        constraint = alias_account.pending_owner == Some(new_owner.key()) @ ErrorCode::InvalidPendingOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: Primary alias record of the current owner, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<AcceptTransfer>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        None,
+    )?;
+    let alias_key = ctx.accounts.alias_account.key();
+    clear_reverse_record(&ctx.accounts.reverse_record, alias_key)?;
+
+    let alias_account = &mut ctx.accounts.alias_account;
+    let now = Clock::get()?.unix_timestamp;
+    alias_account.require_usable(now)?;
+
+    let previous_owner = alias_account.owner;
+    alias_account.owner = ctx.accounts.new_owner.key();
+    alias_account.controller = alias_account.owner;
+    alias_account.pending_owner = None;
+    transfer_reputation(alias_account, &ctx.accounts.reputation_config, now)?;
+
+    emit!(AliasTransferred {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        previous_owner,
+        new_owner: alias_account.owner,
+    });
+
+    Ok(())
+}
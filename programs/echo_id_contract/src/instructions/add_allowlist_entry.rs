@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, user: Pubkey)]
+pub struct AddAllowlistEntry<'info> {
+    /// Suffix owner admitting the user. Signing is all it does.
+    pub product_owner: Signer<'info>,
+    /// Covers rent for the user's `AllowlistEntry`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = AllowlistEntry::SPACE,
+        seeds = [b"allowlist_entry", suffix.as_bytes(), user.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Admits `user` to the suffix's registry without an allowlist proof.
+pub fn handler(ctx: Context<AddAllowlistEntry>, suffix: String, user: Pubkey) -> Result<()> {
+    let allowlist_entry = &mut ctx.accounts.allowlist_entry;
+    allowlist_entry.user = user;
+    allowlist_entry.added_at = Clock::get()?.unix_timestamp;
+
+    emit!(AllowlistEntryUpdated { suffix, user, removed: false });
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::merkle::hash_chain_mapping;
+use crate::validation::{validate_chain_address, validate_chain_mapping_label};
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct AddChainMapping<'info> {
+    /// The alias owner, its controller, or a session key scoped for mappings.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// Session key acting for the alias, when `authority` is not its controller.
+    #[account(
+        constraint = session.alias == alias_account.key() @ ErrorCode::Unauthorized
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ChainMappingTree::SPACE,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    /// Required when the alias keeps a registry.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: Option<Account<'info, ChainMappingRegistry>>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Solana mappings are only accepted with a signature from the mapped key,
+/// through `add_chain_mapping_with_proof`.
+pub(crate) fn require_unproven_allowed(mapping: &ChainMapping) -> Result<()> {
+    require!(mapping.chain_type != ChainType::Solana, ErrorCode::ChainMappingProofRequired);
+    Ok(())
+}
+
+/// Appends `mapping` to the alias's chain mappings tree.
+pub fn handler(ctx: Context<AddChainMapping>, _username: String, _project_suffix: String, _chain_name: String, mapping: ChainMapping) -> Result<()> {
+    require_unproven_allowed(&mapping)?;
+    let accounts = ctx.accounts;
+    let config = SuffixConfig::load_or_default(&accounts.suffix_config)?;
+    append_chain_mappings(&accounts.authority.key(), &mut accounts.alias_account, accounts.session.as_deref(), &mut accounts.chain_mapping_tree, accounts.chain_mapping_registry.as_deref_mut(), &config, vec![mapping])
+}
+
+/// Like `handler`, but appends every entry of `added` under one root update.
+pub fn batch_handler(ctx: Context<AddChainMapping>, _username: String, _project_suffix: String, _chain_name: String, added: Vec<ChainMapping>) -> Result<()> {
+    added.iter().try_for_each(require_unproven_allowed)?;
+    let accounts = ctx.accounts;
+    let config = SuffixConfig::load_or_default(&accounts.suffix_config)?;
+    append_chain_mappings(&accounts.authority.key(), &mut accounts.alias_account, accounts.session.as_deref(), &mut accounts.chain_mapping_tree, accounts.chain_mapping_registry.as_deref_mut(), &config, added)
+}
+
+pub(crate) fn append_chain_mappings(
+    authority: &Pubkey,
+    alias_account: &mut Account<AliasAccount>,
+    session: Option<&SessionKey>,
+    tree: &mut ChainMappingTree,
+    mut registry: Option<&mut ChainMappingRegistry>,
+    config: &SuffixConfig,
+    added: Vec<ChainMapping>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    alias_account.require_authority(authority, session, SESSION_SCOPE_ADD_MAPPING, now)?;
+    alias_account.require_usable(now)?;
+    alias_account.require_not_quarantined()?;
+    require!(!added.is_empty(), ErrorCode::InvalidChainMapping);
+    require!(alias_account.chain_mapping_count as usize + added.len() <= config.max_chain_mappings as usize, ErrorCode::MaxChainMappingsReached);
+    tree.require_registry(registry.as_deref())?;
+
+    let alias = alias_account.key();
+    tree.alias = alias;
+
+    let mut root = alias_account.chain_mappings_root;
+    let mut appended = Vec::with_capacity(added.len());
+    for mapping in added {
+        validate_chain_address(mapping.chain_type, &mapping.address)?;
+        validate_chain_mapping_label(&mapping.label)?;
+        tree.claim_chain_id(mapping.chain_id, config.allow_multiple_per_chain)?;
+        let mapping = AttestedChainMapping::new(mapping, now);
+        let leaf = hash_chain_mapping(tree.tree.hash, &mapping);
+        let (index, new_root) = tree.tree.append(leaf).ok_or(ErrorCode::MaxChainMappingsReached)?;
+        root = new_root;
+        if let Some(registry) = registry.as_deref_mut() {
+            registry.insert(index, mapping.clone())?;
+        }
+        appended.push(ChainMappingAdded { alias, index, leaf, mapping });
+    }
+    alias_account.set_chain_mappings_root(root);
+    tree.root_history.push(root);
+    alias_account.chain_mapping_count += appended.len() as u8;
+
+    for event in appended {
+        emit!(event);
+    }
+    emit!(RootUpdated {
+        alias,
+        chain_mappings_root: alias_account.chain_mappings_root,
+        chain_mapping_count: alias_account.chain_mapping_count,
+        chain_mappings_version: alias_account.chain_mappings_version,
+    });
+
+    Ok(())
+}
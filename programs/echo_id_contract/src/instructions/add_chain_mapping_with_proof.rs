@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::instructions as sysvar_instructions};
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::ed25519::verify_ed25519_signature;
+use crate::secp256k1::verify_secp256k1_signature;
+use super::add_chain_mapping::append_chain_mappings;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct AddChainMappingWithProof<'info> {
+    /// The alias owner, its controller, or a session key scoped for mappings.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// Session key acting for the alias, when `authority` is not its controller.
+    #[account(
+        constraint = session.alias == alias_account.key() @ ErrorCode::Unauthorized
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ChainMappingTree::SPACE,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    /// Required when the alias keeps a registry.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: Option<Account<'info, ChainMappingRegistry>>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar, used to find the secp256k1 or ed25519 proof.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The message the mapped address signs to prove it belongs to `alias`.
+pub fn chain_mapping_proof_message(alias: &Pubkey, chain_id: u32, address: &[u8]) -> [u8; 32] {
+    hashv(&[alias.as_ref(), &chain_id.to_le_bytes(), address]).to_bytes()
+}
+
+/// Checks that `mapping`'s address signed `chain_mapping_proof_message`:
+/// EVM addresses in a secp256k1 instruction, Solana keys in an ed25519
+/// instruction. Other chains cannot prove ownership on Solana.
+pub(crate) fn verify_mapping_ownership(instructions: &AccountInfo, alias: &Pubkey, mapping: &ChainMapping) -> Result<()> {
+    let message = chain_mapping_proof_message(alias, mapping.chain_id, &mapping.address);
+    match mapping.chain_type {
+        ChainType::Evm => verify_secp256k1_signature(instructions, &mapping.address, &message),
+        ChainType::Solana => {
+            let key = Pubkey::try_from(mapping.address.as_slice()).map_err(|_| ErrorCode::InvalidSolanaAddress)?;
+            verify_ed25519_signature(instructions, &key, &message)
+        }
+        _ => err!(ErrorCode::InvalidChainMapping),
+    }
+}
+
+/// Like `add_chain_mapping`, but only accepts a `mapping` whose address
+/// proves ownership through `verify_mapping_ownership`.
+pub fn handler(ctx: Context<AddChainMappingWithProof>, _username: String, _project_suffix: String, _chain_name: String, mapping: ChainMapping) -> Result<()> {
+    let accounts = ctx.accounts;
+    verify_mapping_ownership(&accounts.instructions, &accounts.alias_account.key(), &mapping)?;
+
+    let config = SuffixConfig::load_or_default(&accounts.suffix_config)?;
+    append_chain_mappings(&accounts.authority.key(), &mut accounts.alias_account, accounts.session.as_deref(), &mut accounts.chain_mapping_tree, accounts.chain_mapping_registry.as_deref_mut(), &config, vec![mapping])
+}
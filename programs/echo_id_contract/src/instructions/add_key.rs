@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct AddKey<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = AliasKeys::SPACE,
+        seeds = [b"keys", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_keys: Account<'info, AliasKeys>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddKey>, _username: String, _project_suffix: String, _chain_name: String, purpose: KeyPurpose, key: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.alias_account.require_usable(now)?;
+
+    let alias = ctx.accounts.alias_account.key();
+    let alias_keys = &mut ctx.accounts.alias_keys;
+    alias_keys.alias = alias;
+    if alias_keys.owner != ctx.accounts.owner.key() {
+        // Keys left by a previous owner do not carry over.
+        alias_keys.keys.clear();
+        alias_keys.owner = ctx.accounts.owner.key();
+    }
+    require!(!alias_keys.keys_for(purpose).any(|k| *k == key), ErrorCode::DuplicateAliasKey);
+    require!(alias_keys.keys.len() < MAX_ALIAS_KEYS, ErrorCode::MaxAliasKeysReached);
+    alias_keys.keys.push(AliasKey { purpose, key, added_at: now });
+
+    emit!(AliasKeyUpdated {
+        alias,
+        purpose,
+        key,
+        added: true,
+    });
+
+    Ok(())
+}
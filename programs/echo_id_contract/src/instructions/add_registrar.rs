@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, registrar: Pubkey)]
+pub struct AddRegistrar<'info> {
+    /// Suffix owner delegating registration. Only its signature is checked.
+    pub product_owner: Signer<'info>,
+    /// Pays rent for the `Registrar` record; often the registrar itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Registrar::space(&suffix),
+        seeds = [b"registrar", suffix.as_bytes(), registrar.as_ref()],
+        bump
+    )]
+    pub registrar_account: Account<'info, Registrar>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `registrar` approve and pay for `register_alias` calls on the
+/// suffix, up to `quota` in total until `expires_at`. Adding an existing
+/// registrar updates its quota and expiry without resetting what it used.
+pub fn handler(ctx: Context<AddRegistrar>, suffix: String, registrar: Pubkey, quota: u32, expires_at: i64) -> Result<()> {
+    require!(expires_at > Clock::get()?.unix_timestamp, ErrorCode::RegistrarExpired);
+
+    let registrar_account = &mut ctx.accounts.registrar_account;
+    registrar_account.suffix = suffix;
+    registrar_account.registrar = registrar;
+    registrar_account.quota = quota;
+    registrar_account.expires_at = expires_at;
+
+    emit!(RegistrarUpdated {
+        suffix: registrar_account.suffix.clone(),
+        registrar,
+        quota,
+        expires_at,
+        removed: false,
+    });
+
+    Ok(())
+}
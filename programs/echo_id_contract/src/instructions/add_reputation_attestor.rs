@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, attestor: Pubkey)]
+pub struct AddReputationAttestor<'info> {
+    /// Suffix owner approving the attestor; only its signature is needed.
+    pub product_owner: Signer<'info>,
+    /// Pays rent for the `ReputationAttestor` record.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReputationAttestor::space(&suffix),
+        seeds = [b"reputation_attestor", suffix.as_bytes(), attestor.as_ref()],
+        bump
+    )]
+    pub reputation_attestor: Account<'info, ReputationAttestor>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `attestor` grant the suffix's aliases boosts of up to `max_boost`.
+pub fn handler(ctx: Context<AddReputationAttestor>, suffix: String, attestor: Pubkey, max_boost: i64) -> Result<()> {
+    require!(max_boost > 0 && max_boost <= MAX_BOOST_BONUS, ErrorCode::InvalidBoost);
+    let reputation_attestor = &mut ctx.accounts.reputation_attestor;
+    reputation_attestor.suffix = suffix;
+    reputation_attestor.attestor = attestor;
+    reputation_attestor.max_boost = max_boost;
+
+    emit!(ReputationAttestorUpdated {
+        suffix: reputation_attestor.suffix.clone(),
+        attestor,
+        max_boost,
+        removed: false,
+    });
+
+    Ok(())
+}
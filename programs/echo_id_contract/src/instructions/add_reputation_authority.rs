@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, authority: Pubkey)]
+pub struct AddReputationAuthority<'info> {
+    /// Suffix owner appointing the authority; it only signs.
+    pub product_owner: Signer<'info>,
+    /// Pays rent for the `ReputationAuthority` record.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReputationAuthority::space(&suffix),
+        seeds = [b"reputation_authority", suffix.as_bytes(), authority.as_ref()],
+        bump
+    )]
+    pub reputation_authority: Account<'info, ReputationAuthority>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `authority` call `update_reputation` for the suffix's aliases, with
+/// its changes scaled by `weight_bps`. Adding an existing authority updates
+/// its weight.
+pub fn handler(ctx: Context<AddReputationAuthority>, suffix: String, authority: Pubkey, weight_bps: u16) -> Result<()> {
+    require!(weight_bps > 0 && weight_bps <= 10_000, ErrorCode::InvalidReputationWeight);
+
+    let reputation_authority = &mut ctx.accounts.reputation_authority;
+    reputation_authority.suffix = suffix;
+    reputation_authority.authority = authority;
+    reputation_authority.weight_bps = weight_bps;
+
+    emit!(ReputationAuthorityUpdated {
+        suffix: reputation_authority.suffix.clone(),
+        authority,
+        weight_bps,
+        removed: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, slasher: Pubkey)]
+pub struct AddReputationSlasher<'info> {
+    /// Suffix owner granting slashing rights. It may sign through CPI.
+    pub product_owner: Signer<'info>,
+    /// Rent payer for the `ReputationSlasher` record.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReputationSlasher::space(&suffix),
+        seeds = [b"reputation_slasher", suffix.as_bytes(), slasher.as_ref()],
+        bump
+    )]
+    pub reputation_slasher: Account<'info, ReputationSlasher>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `slasher` call `slash_reputation` for the suffix's aliases.
+pub fn handler(ctx: Context<AddReputationSlasher>, suffix: String, slasher: Pubkey) -> Result<()> {
+    let reputation_slasher = &mut ctx.accounts.reputation_slasher;
+    reputation_slasher.suffix = suffix;
+    reputation_slasher.slasher = slasher;
+
+    emit!(ReputationSlasherUpdated {
+        suffix: reputation_slasher.suffix.clone(),
+        slasher,
+        removed: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct AggregateReputationView<'info> {
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixWeight`, read through
+    /// `SuffixWeight::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_weight", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_weight: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ReputationDelegation`, read through
+    /// `ReputationDelegation::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_delegation", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_delegation: UncheckedAccount<'info>,
+}
+
+/// The alias's general score at `now` after delegations, weighted by its
+/// suffix's weight.
+fn weighted_score(alias: &AliasAccount, config: &ReputationConfig, weight: &SuffixWeight, delegation: &ReputationDelegation, now: i64) -> (i128, i128) {
+    let score = delegation.apply(alias.general_reputation_at(config, now));
+    (score as i128 * weight.weight_bps as i128, weight.weight_bps as i128)
+}
+
+/// Weighted average of the general scores of `alias_account` and the aliases
+/// linked to it, after delegations, each weighted by its suffix's
+/// `SuffixWeight`. `linked` holds groups of five accounts per linked alias,
+/// in increasing link address order: the `AliasLink`, the linked alias, its
+/// suffix's `ReputationConfig` and `SuffixWeight` addresses, and its
+/// `ReputationDelegation` address. Returns the score and how many aliases it
+/// covers.
+pub(crate) fn aggregate_reputation(alias_account: &Account<AliasAccount>, reputation_config: &AccountInfo, suffix_weight: &AccountInfo, reputation_delegation: &AccountInfo, linked: &[AccountInfo], now: i64) -> Result<(i64, u8)> {
+    require!(linked.len().is_multiple_of(5) && linked.len() / 5 <= MAX_AGGREGATED_ALIASES, ErrorCode::InvalidAliasLink);
+    let alias = alias_account.key();
+    let config = ReputationConfig::load_or_default(reputation_config)?;
+    let weight = SuffixWeight::load_or_default(suffix_weight)?;
+    let delegation = ReputationDelegation::load_or_default(reputation_delegation)?;
+    let (mut total, mut weights) = weighted_score(alias_account, &config, &weight, &delegation, now);
+
+    let mut previous = None;
+    for group in linked.chunks_exact(5) {
+        let (link_info, linked_info, config_info, weight_info, delegation_info) = (&group[0], &group[1], &group[2], &group[3], &group[4]);
+        require!(previous < Some(link_info.key), ErrorCode::InvalidAliasLink);
+        previous = Some(link_info.key);
+
+        require_keys_eq!(*link_info.owner, crate::ID, ErrorCode::InvalidAliasLink);
+        let link = AliasLink::try_deserialize(&mut &link_info.try_borrow_data()?[..])?;
+        require!(link.other(&alias) == Some(linked_info.key()), ErrorCode::InvalidAliasLink);
+        require_keys_eq!(*linked_info.owner, crate::ID, ErrorCode::InvalidAliasLink);
+        let linked_alias = AliasAccount::try_deserialize(&mut &linked_info.try_borrow_data()?[..])?;
+
+        let suffix = linked_alias.product_suffix.as_bytes();
+        let (config_key, _) = Pubkey::find_program_address(&[b"reputation_config", suffix], &crate::ID);
+        let (weight_key, _) = Pubkey::find_program_address(&[b"suffix_weight", suffix], &crate::ID);
+        require_keys_eq!(config_info.key(), config_key, ErrorCode::InvalidAliasLink);
+        require_keys_eq!(weight_info.key(), weight_key, ErrorCode::InvalidAliasLink);
+        let (delegation_key, _) = Pubkey::find_program_address(&[b"reputation_delegation", linked_info.key.as_ref()], &crate::ID);
+        require_keys_eq!(delegation_info.key(), delegation_key, ErrorCode::InvalidAliasLink);
+
+        let linked_config = ReputationConfig::load_or_default(config_info)?;
+        let linked_weight = SuffixWeight::load_or_default(weight_info)?;
+        let linked_delegation = ReputationDelegation::load_or_default(delegation_info)?;
+        let (score, weight) = weighted_score(&linked_alias, &linked_config, &linked_weight, &linked_delegation, now);
+        total += score;
+        weights += weight;
+    }
+
+    let score = if weights == 0 { 0 } else { (total / weights) as i64 };
+    Ok((score, (linked.len() / 5 + 1) as u8))
+}
+
+/// Returns, as return data, the alias's reputation aggregated across the
+/// aliases linked to it, passed as remaining accounts as described on
+/// `aggregate_reputation`.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, AggregateReputationView<'info>>, _username: String, _project_suffix: String, _chain_name: String) -> Result<i64> {
+    let accounts = &ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+    aggregate_reputation(&accounts.alias_account, &accounts.reputation_config, &accounts.suffix_weight, &accounts.reputation_delegation, ctx.remaining_accounts, now).map(|(score, _)| score)
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct ApproveRecovery<'info> {
+    pub guardian: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"guardians", alias_account.key().as_ref()],
+        bump,
+        constraint = guardian_set.owner == alias_account.owner @ ErrorCode::StaleGuardianSet
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+}
+
+pub fn handler(ctx: Context<ApproveRecovery>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    let index = guardian_set.guardian_index(&guardian).ok_or(ErrorCode::NotAGuardian)?;
+    let alias = guardian_set.alias;
+
+    let recovery = guardian_set.recovery.as_mut().ok_or(ErrorCode::RecoveryNotInitiated)?;
+    require!(recovery.approvals & (1 << index) == 0, ErrorCode::RecoveryAlreadyApproved);
+    recovery.approvals |= 1 << index;
+
+    emit!(RecoveryApproved {
+        alias,
+        guardian,
+        approvals: recovery.approval_count(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::instructions::set_primary_alias::clear_reverse_record;
+use crate::instructions::transfer_alias::transfer_reputation;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct BuyAlias<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    /// CHECK: Receives the protocol fee; must be the configured admin.
+    #[account(
+        mut,
+        constraint = admin.key() == admin_config.admin @ ErrorCode::Unauthorized
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.transferable @ ErrorCode::AliasNotTransferable
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// CHECK: Receives the royalty; must be the suffix owner, and must sign
+    /// when the suffix enforces co-signature on an owner-managed alias.
+    #[account(
+        mut,
+        constraint = product_owner.key() == suffix_account.owner @ ErrorCode::InvalidOwner
+    )]
+    pub product_owner: AccountInfo<'info>,
+    /// CHECK: Receives the sale proceeds and the listing rent; must be the seller.
+    #[account(
+        mut,
+        constraint = seller.key() == listing.seller @ ErrorCode::InvalidOwner
+    )]
+    pub seller: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", alias_account.key().as_ref()],
+        bump,
+        constraint = listing.alias_created_at == alias_account.created_at @ ErrorCode::StaleListing
+    )]
+    pub listing: Account<'info, Listing>,
+    /// CHECK: Primary alias record of the seller, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", listing.seller.as_ref()],
+        bump,
+    )]
+    pub seller_reverse_record: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays the seller the listing price minus the protocol fee and product
+/// owner royalty, then hands the alias to the buyer. `price` must match the
+/// listing so a seller cannot reprice a pending purchase.
+pub fn handler(ctx: Context<BuyAlias>, username: String, project_suffix: String, chain_name: String, price: u64) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        None,
+    )?;
+    require!(ctx.accounts.listing.price == price, ErrorCode::ListingPriceMismatch);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.alias_account.require_usable(now)?;
+    if ctx.accounts.suffix_account.transfer_requires_cosign && ctx.accounts.alias_account.owner_managed {
+        require!(ctx.accounts.product_owner.is_signer, ErrorCode::ProductOwnerSignatureRequired);
+    }
+
+    let protocol_fee = (price as u128 * PROTOCOL_FEE_BPS as u128 / 10_000) as u64;
+    let royalty = (price as u128 * ctx.accounts.suffix_account.royalty_bps as u128 / 10_000) as u64;
+    let proceeds = price - protocol_fee - royalty;
+
+    for (to, amount) in [
+        (ctx.accounts.admin.to_account_info(), protocol_fee),
+        (ctx.accounts.product_owner.to_account_info(), royalty),
+        (ctx.accounts.seller.to_account_info(), proceeds),
+    ] {
+        if amount > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.buyer.to_account_info(),
+                        to,
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    let alias_key = ctx.accounts.alias_account.key();
+    clear_reverse_record(&ctx.accounts.seller_reverse_record, alias_key)?;
+
+    let buyer = ctx.accounts.buyer.key();
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.owner = buyer;
+    alias_account.controller = buyer;
+    transfer_reputation(alias_account, &ctx.accounts.reputation_config, now)?;
+
+    emit!(AliasSold {
+        alias: alias_key,
+        seller: ctx.accounts.listing.seller,
+        buyer,
+        price,
+        protocol_fee,
+        royalty,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use super::aggregate_reputation::aggregate_reputation;
+
+#[derive(Accounts)]
+pub struct CacheAggregateReputation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixWeight`, read through
+    /// `SuffixWeight::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_weight", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_weight: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ReputationDelegation`, read through
+    /// `ReputationDelegation::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_delegation", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_delegation: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AggregateReputation::SPACE,
+        seeds = [b"aggregate_reputation", alias_account.key().as_ref()],
+        bump
+    )]
+    pub aggregate_reputation: Account<'info, AggregateReputation>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Stores the alias's aggregate reputation, computed as in
+/// `aggregate_reputation`, so other programs can read it without passing
+/// every linked alias. Anyone may refresh it.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CacheAggregateReputation<'info>>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+    let (score, alias_count) = aggregate_reputation(&accounts.alias_account, &accounts.reputation_config, &accounts.suffix_weight, &accounts.reputation_delegation, ctx.remaining_accounts, now)?;
+
+    let aggregate = &mut accounts.aggregate_reputation;
+    aggregate.alias = accounts.alias_account.key();
+    aggregate.score = score;
+    aggregate.alias_count = alias_count;
+    aggregate.updated_at = now;
+
+    emit!(AggregateReputationUpdated {
+        alias: aggregate.alias,
+        score,
+        alias_count,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", alias_account.key().as_ref()],
+        bump,
+        constraint = listing.seller == seller.key() @ ErrorCode::Unauthorized,
+        constraint = listing.alias_created_at == alias_account.created_at @ ErrorCode::StaleListing
+    )]
+    pub listing: Account<'info, Listing>,
+}
+
+pub fn handler(ctx: Context<CancelListing>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let seller = ctx.accounts.seller.key();
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.owner = seller;
+    alias_account.controller = seller;
+
+    emit!(AliasListingCancelled {
+        alias: alias_account.key(),
+        seller,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct CancelRecovery<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"guardians", alias_account.key().as_ref()],
+        bump,
+        constraint = guardian_set.recovery.is_some() @ ErrorCode::RecoveryNotInitiated
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+}
+
+/// Lets an owner who still holds their key abort a recovery during the timelock.
+pub fn handler(ctx: Context<CancelRecovery>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.recovery = None;
+
+    emit!(RecoveryCancelled {
+        alias: guardian_set.alias,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct CancelTransfer<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner,
+        constraint = alias_account.pending_owner.is_some() @ ErrorCode::NoPendingTransfer
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+}
+
+pub fn handler(ctx: Context<CancelTransfer>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.pending_owner = None;
+
+    emit!(AliasTransferCancelled {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        owner: alias_account.owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::open_dispute::open_dispute;
+
+#[derive(Accounts)]
+#[instruction(history_index: u8)]
+pub struct ChallengeUpdate<'info> {
+    /// Anyone contesting the update.
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"reputation_history", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_history: Box<Account<'info, ReputationHistory>>,
+    /// The bonded authority that made the update.
+    #[account(
+        constraint = reputation_authority.suffix == alias_account.product_suffix @ ErrorCode::InvalidDispute,
+        constraint = reputation_history.entries.get(history_index as usize).map(|entry| entry.authority) == Some(reputation_authority.authority) @ ErrorCode::InvalidDispute
+    )]
+    pub reputation_authority: Account<'info, ReputationAuthority>,
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = Dispute::SPACE,
+        seeds = [b"dispute", alias_account.key().as_ref(), &[history_index]],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Contests a reputation authority's update at `history_index`, posting
+/// `DISPUTE_BOND`. If the arbiter reverts it, the challenger also receives
+/// `REPUTATION_BOND_SLASH_BPS` of the authority's bond.
+pub fn handler(ctx: Context<ChallengeUpdate>, history_index: u8) -> Result<()> {
+    let accounts = ctx.accounts;
+    open_dispute(&accounts.challenger, accounts.alias_account.key(), &accounts.reputation_history, &mut accounts.dispute, &accounts.system_program, history_index, true)
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::{
+    instructions::reserve_name::reserved_name_hash,
+    state::*,
+    validation::validate_suffix_username,
+};
+
+/// Result of `check_availability`, returned as return data so wallets can
+/// simulate the instruction instead of replicating every registration rule.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Availability {
+    Available,
+    InvalidUsername,
+    UnknownSuffix,
+    SuffixInactive,
+    Reserved,
+    Taken,
+    /// Registered but past its grace period; `reclaim_expired_alias` frees it.
+    Reclaimable,
+}
+
+#[derive(Accounts)]
+#[instruction(username: String, suffix: String, chain_name: String)]
+pub struct CheckAvailability<'info> {
+    /// CHECK: Deserialized in the handler; may not exist.
+    #[account(seeds = [b"suffix", suffix.as_bytes()], bump)]
+    pub suffix_account: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(seeds = [b"suffix_config", suffix.as_bytes()], bump)]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: Only inspected for existence.
+    #[account(
+        seeds = [b"reserved", suffix.as_bytes(), reserved_name_hash(&username).as_ref()],
+        bump
+    )]
+    pub reserved_name: UncheckedAccount<'info>,
+    /// CHECK: Deserialized in the handler; may not exist.
+    #[account(
+        seeds = [username.as_bytes(), b"@", suffix.as_bytes(), chain_name.as_bytes()],
+        bump
+    )]
+    pub alias_account: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CheckAvailability>, username: String, _suffix: String, _chain_name: String) -> Result<Availability> {
+    let suffix_config = SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?;
+    if validate_suffix_username(&username, &suffix_config).is_err() {
+        return Ok(Availability::InvalidUsername);
+    }
+
+    let suffix_info = ctx.accounts.suffix_account.to_account_info();
+    if suffix_info.owner != &crate::ID {
+        return Ok(Availability::UnknownSuffix);
+    }
+    let suffix_account = SuffixAccount::try_deserialize(&mut &suffix_info.data.borrow()[..])?;
+    if !suffix_account.is_active {
+        return Ok(Availability::SuffixInactive);
+    }
+
+    if !ctx.accounts.reserved_name.data_is_empty() {
+        return Ok(Availability::Reserved);
+    }
+
+    let alias_info = ctx.accounts.alias_account.to_account_info();
+    if alias_info.data_is_empty() {
+        return Ok(Availability::Available);
+    }
+    let now = Clock::get()?.unix_timestamp;
+    let alias = AliasAccount::try_deserialize(&mut &alias_info.data.borrow()[..]);
+    match alias {
+        Ok(alias) if alias.is_reclaimable(now) => Ok(Availability::Reclaimable),
+        _ => Ok(Availability::Taken),
+    }
+}
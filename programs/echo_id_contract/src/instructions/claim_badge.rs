@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::get_effective_reputation::general_reputation_bonus;
+
+#[derive(Accounts)]
+pub struct ClaimBadge<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ReputationVault`, read through
+    /// `ReputationVault::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_vault", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_vault: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Badge::SPACE,
+        seeds = [b"badge", alias_account.key().as_ref()],
+        bump
+    )]
+    pub badge: Account<'info, Badge>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The alias's effective general reputation, with endorsements passed as
+/// remaining accounts as in `get_effective_reputation`.
+pub(crate) fn effective_reputation(alias_account: &Account<AliasAccount>, config: &ReputationConfig, reputation_vault: &AccountInfo, endorsements: &[AccountInfo], now: i64) -> Result<i64> {
+    let bonus = general_reputation_bonus(&alias_account.key(), reputation_vault, endorsements, now)?;
+    Ok(alias_account.general_reputation_at(config, now).saturating_add(bonus))
+}
+
+/// Records the highest badge tier the alias's effective reputation reaches,
+/// failing unless that is above the tier it already holds.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimBadge<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    let reputation = effective_reputation(&ctx.accounts.alias_account, &config, &ctx.accounts.reputation_vault, ctx.remaining_accounts, now)?;
+
+    let badge = &mut ctx.accounts.badge;
+    let tier = config.tier_for(reputation);
+    require!(tier > badge.tier, ErrorCode::BadgeUnchanged);
+
+    let previous_tier = badge.tier;
+    badge.alias = ctx.accounts.alias_account.key();
+    badge.tier = tier;
+    badge.updated_at = now;
+
+    emit!(BadgeUpdated {
+        alias: badge.alias,
+        tier,
+        previous_tier,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::{
+    error::EchoIDError as ErrorCode,
+    instructions::register_alias::{build_alias_account, emit_alias_registered, RegisterAliasParams},
+    instructions::reserve_name::reserved_name_hash,
+    merkle::verify_proof,
+    state::*,
+};
+
+/// One identity in an import snapshot.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ImportedAlias {
+    pub username: String,
+    pub chain_info: ChainInfo,
+    pub metadata: AliasMetadata,
+    pub owner: Pubkey,
+}
+
+impl ImportedAlias {
+    /// The snapshot leaf, domain-separated from interior nodes.
+    pub fn leaf_hash(&self) -> Result<[u8; 32]> {
+        Ok(hashv(&[b"import_leaf", &self.try_to_vec()?]).to_bytes())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(suffix: String, leaf: ImportedAlias)]
+pub struct ClaimImportedAlias<'info> {
+    /// Any payer; the alias is always created for `leaf.owner`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub suffix_account: Box<Account<'info, SuffixAccount>>,
+    /// CHECK: The suffix owner's `ProductOwnerProfile`, read through
+    /// `ProductOwnerProfile::load_branding` since it may not exist.
+    #[account(
+        seeds = [b"product_owner_profile", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_profile: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"import_root", suffix.as_bytes()],
+        bump,
+    )]
+    pub import_root: Box<Account<'info, ImportRoot>>,
+    #[account(
+        init,
+        payer = payer,
+        space = ImportClaim::SPACE,
+        seeds = [b"import_claim", suffix.as_bytes(), leaf.leaf_hash()?.as_ref()],
+        bump
+    )]
+    pub import_claim: Account<'info, ImportClaim>,
+    #[account(
+        init,
+        payer = payer,
+        space = AliasAccount::space(&leaf.username, &suffix, &leaf.chain_info, &leaf.metadata),
+        seeds = [leaf.username.as_bytes(), b"@", suffix.as_bytes(), leaf.chain_info.name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Box<Account<'info, AliasAccount>>,
+    /// CHECK: Must be empty; an initialized account here means the name is reserved.
+    #[account(
+        seeds = [b"reserved", suffix.as_bytes(), reserved_name_hash(&leaf.username).as_ref()],
+        bump,
+        constraint = reserved_name.data_is_empty() @ ErrorCode::NameReserved
+    )]
+    pub reserved_name: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_stats: Box<Account<'info, SuffixStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the alias described by `leaf` once `proof` ties it to the
+/// committed snapshot root. Each leaf can be claimed once; its
+/// `ImportClaim` outlives the alias.
+pub fn handler(ctx: Context<ClaimImportedAlias>, suffix: String, leaf: ImportedAlias, proof: Vec<[u8; 32]>) -> Result<()> {
+    require!(
+        verify_proof(&proof, &ctx.accounts.import_root.root, leaf.leaf_hash()?),
+        ErrorCode::InvalidImportProof
+    );
+
+    let params = RegisterAliasParams {
+        username: leaf.username,
+        suffix,
+        chain_info: leaf.chain_info,
+        metadata: leaf.metadata,
+        user_address: leaf.owner,
+        salt: None,
+        owner_managed: true,
+        referrer: None,
+        allowlist_proof: None,
+        registration_proof: None,
+        signature_expires_at: 0,
+    };
+    let initial_reputation = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?.initial_reputation;
+    let suffix_config = SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.suffix_account.require_leased(now)?;
+    let alias = build_alias_account(params, &suffix_config, initial_reputation, now)?;
+    emit_alias_registered(&alias, ProductOwnerProfile::load_branding(&ctx.accounts.product_owner_profile)?);
+    emit!(ImportedAliasClaimed {
+        alias: ctx.accounts.alias_account.key(),
+        owner: alias.owner,
+    });
+    ctx.accounts.alias_account.set_inner(alias);
+    let import_claim = &mut ctx.accounts.import_claim;
+    import_claim.alias = ctx.accounts.alias_account.key();
+    import_claim.claimed_at = now;
+    ctx.accounts.suffix_stats.record_limited_registration(&suffix_config, now)?;
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::set_primary_alias::clear_reverse_record;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct CloseAlias<'info> {
+    /// Either the alias owner or, for an owner-managed alias, the product
+    /// owner of the suffix.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == authority.key()
+            || (alias_account.owner_managed && suffix_account.owner == authority.key()) @ ErrorCode::Unauthorized,
+        constraint = alias_account.owner != listing.key() @ ErrorCode::AliasListed
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The alias's `Listing` PDA. A listed alias is owned by it and
+    /// must be cancelled or bought before it can be closed.
+    #[account(
+        seeds = [b"listing", alias_account.key().as_ref()],
+        bump
+    )]
+    pub listing: UncheckedAccount<'info>,
+    /// CHECK: Primary alias record of the current owner, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// CHECK: Only receives the reclaimed rent lamports; the alias owner
+    /// chooses it, otherwise the rent goes back to the alias owner.
+    #[account(
+        mut,
+        constraint = alias_account.owner == authority.key() || recipient.key() == alias_account.owner @ ErrorCode::InvalidOwner
+    )]
+    pub recipient: AccountInfo<'info>,
+    /// CHECK: The alias token mint; its token is burned and the mint
+    /// closed if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// Redirect record left at the closed address, paid for by `authority`.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Tombstone::SPACE,
+        seeds = [b"tombstone", alias_account.key().as_ref()],
+        bump
+    )]
+    pub tombstone: Option<Account<'info, Tombstone>>,
+    /// CHECK: The alias's `ChainMappingTree`, closed with it if it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ChainMappingRegistry`, closed with it if it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: UncheckedAccount<'info>,
+    /// CHECK: The alias's `AliasRecords`, closed with it if they exist so a
+    /// later alias at this address starts without them.
+    #[account(
+        mut,
+        seeds = [b"records", alias_account.key().as_ref()],
+        bump
+    )]
+    pub records: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_stats: Box<Account<'info, SuffixStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Records `successor` (or `None` for gone) as the redirect for `alias`, when
+/// the caller asked for a tombstone.
+pub(crate) fn write_tombstone(tombstone: &mut Option<Account<Tombstone>>, alias: Pubkey, successor: Option<Pubkey>) -> Result<()> {
+    if let Some(tombstone) = tombstone.as_mut() {
+        tombstone.alias = alias;
+        tombstone.successor = successor;
+        tombstone.created_at = Clock::get()?.unix_timestamp;
+
+        emit!(TombstoneWritten { alias, successor });
+    }
+    Ok(())
+}
+
+/// Closes `account` into `recipient` if it is one of this program's
+/// accounts, for PDAs that may never have been created.
+pub(crate) fn close_if_initialized<'info>(account: &AccountInfo<'info>, recipient: &AccountInfo<'info>) -> Result<()> {
+    if *account.owner != crate::ID {
+        return Ok(());
+    }
+    let lamports = account.lamports();
+    account.sub_lamports(lamports)?;
+    recipient.add_lamports(lamports)?;
+    account.assign(&anchor_lang::system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+/// Closes an alias's chain mapping tree and registry, so a later alias at
+/// the same address starts without its mappings or root history.
+pub(crate) fn close_chain_mappings<'info>(tree: &AccountInfo<'info>, registry: &AccountInfo<'info>, recipient: &AccountInfo<'info>) -> Result<()> {
+    close_if_initialized(tree, recipient)?;
+    close_if_initialized(registry, recipient)
+}
+
+pub fn handler(ctx: Context<CloseAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        Some(&ctx.accounts.recipient),
+    )?;
+
+    let alias_key = ctx.accounts.alias_account.key();
+    clear_reverse_record(&ctx.accounts.reverse_record, alias_key)?;
+    write_tombstone(&mut ctx.accounts.tombstone, alias_key, None)?;
+    close_chain_mappings(&ctx.accounts.chain_mapping_tree, &ctx.accounts.chain_mapping_registry, &ctx.accounts.recipient)?;
+    close_if_initialized(&ctx.accounts.records, &ctx.accounts.recipient)?;
+    ctx.accounts.suffix_stats.record_removal();
+
+    let alias_account = &ctx.accounts.alias_account;
+
+    emit!(AliasClosed {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        owner: alias_account.owner,
+        closed_by: ctx.accounts.authority.key(),
+        recipient: ctx.accounts.recipient.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct CloseProductOwner<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"product_owner", owner.key().as_ref()],
+        bump,
+        constraint = product_owner_account.suffix_count == 0 @ ErrorCode::ProductOwnerHasSuffixes
+    )]
+    pub product_owner_account: Account<'info, ProductOwner>,
+}
+
+/// A clean exit: once every suffix has been removed or transferred away, the
+/// owner closes its record and gets its remaining bond back with the rent.
+pub fn handler(ctx: Context<CloseProductOwner>) -> Result<()> {
+    emit!(ProductOwnerBondUpdated {
+        owner: ctx.accounts.owner.key(),
+        bond: 0,
+        slashed: 0,
+    });
+
+    Ok(())
+}
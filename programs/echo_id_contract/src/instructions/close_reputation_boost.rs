@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct CloseReputationBoost<'info> {
+    /// Anyone once the boost has expired; the attestor at any time.
+    pub closer: Signer<'info>,
+    #[account(
+        mut,
+        close = attestor
+    )]
+    pub reputation_boost: Account<'info, ReputationBoost>,
+    /// CHECK: Receives the boost's rent.
+    #[account(mut, address = reputation_boost.attestor @ ErrorCode::InvalidBoost)]
+    pub attestor: UncheckedAccount<'info>,
+}
+
+/// Closes a boost, returning its rent to the attestor that paid for it.
+pub fn handler(ctx: Context<CloseReputationBoost>) -> Result<()> {
+    let reputation_boost = &ctx.accounts.reputation_boost;
+    let now = Clock::get()?.unix_timestamp;
+    require!(!reputation_boost.is_active(now) || ctx.accounts.closer.key() == reputation_boost.attestor, ErrorCode::InvalidBoost);
+
+    emit!(ReputationBoostUpdated {
+        alias: reputation_boost.alias,
+        attestor: reputation_boost.attestor,
+        amount: 0,
+        expires_at: reputation_boost.expires_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, label: String)]
+pub struct CloseSubAlias<'info> {
+    /// The parent alias owner.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = parent_alias.owner == authority.key() @ ErrorCode::InvalidOwner
+    )]
+    pub parent_alias: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [label.as_bytes(), parent_alias.key().as_ref()],
+        bump
+    )]
+    pub sub_alias: Account<'info, SubAliasAccount>,
+}
+
+pub fn handler(ctx: Context<CloseSubAlias>, _username: String, _project_suffix: String, _chain_name: String, label: String) -> Result<()> {
+    emit!(SubAliasClosed {
+        parent: ctx.accounts.parent_alias.key(),
+        label,
+    });
+
+    Ok(())
+}
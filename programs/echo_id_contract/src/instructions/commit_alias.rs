@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct CommitAlias<'info> {
+    #[account(mut)]
+    pub registrant: Signer<'info>,
+    #[account(
+        init,
+        payer = registrant,
+        space = 8 + 32 + 32 + 8,
+        seeds = [b"commitment", commitment.as_ref()],
+        bump
+    )]
+    pub commitment_account: Account<'info, CommitmentAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The value a registrant commits to before revealing it in `register_alias`.
+pub fn commitment_hash(username: &str, suffix: &str, salt: &[u8; 32], registrant: &Pubkey) -> [u8; 32] {
+    hashv(&[username.as_bytes(), b"@", suffix.as_bytes(), salt, registrant.as_ref()]).to_bytes()
+}
+
+pub fn handler(ctx: Context<CommitAlias>, commitment: [u8; 32]) -> Result<()> {
+    let commitment_account = &mut ctx.accounts.commitment_account;
+    commitment_account.registrant = ctx.accounts.registrant.key();
+    commitment_account.commitment = commitment;
+    commitment_account.created_at = Clock::get()?.unix_timestamp;
+    Ok(())
+}
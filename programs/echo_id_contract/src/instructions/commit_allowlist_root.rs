@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct CommitAllowlistRoot<'info> {
+    /// The suffix owner. It only signs, so a PDA or multisig vault works.
+    pub product_owner: Signer<'info>,
+    /// Pays for the suffix's `Allowlist` the first time a root is committed.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Allowlist::SPACE,
+        seeds = [b"allowlist", suffix.as_bytes()],
+        bump
+    )]
+    pub allowlist: Account<'info, Allowlist>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Commits (or replaces) the root of `allowlist_leaf`s that `register_alias`
+/// proves against while the suffix is in allowlist mode.
+pub fn handler(ctx: Context<CommitAllowlistRoot>, suffix: String, root: [u8; 32]) -> Result<()> {
+    let allowlist = &mut ctx.accounts.allowlist;
+    allowlist.root = root;
+    allowlist.committed_at = Clock::get()?.unix_timestamp;
+
+    emit!(AllowlistRootCommitted { suffix, root });
+
+    Ok(())
+}
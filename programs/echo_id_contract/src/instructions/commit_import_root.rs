@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct CommitImportRoot<'info> {
+    /// Either the admin or the product owner of the suffix.
+    #[account(
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    /// Pays for the suffix's `ImportRoot` on the first commit, so the
+    /// authority never has to hold lamports.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ImportRoot::space(&suffix),
+        seeds = [b"import_root", suffix.as_bytes()],
+        bump
+    )]
+    pub import_root: Account<'info, ImportRoot>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Commits (or replaces) the snapshot root that `claim_imported_alias` proves against.
+pub fn handler(ctx: Context<CommitImportRoot>, suffix: String, root: [u8; 32]) -> Result<()> {
+    let import_root = &mut ctx.accounts.import_root;
+    import_root.suffix = suffix;
+    import_root.root = root;
+    import_root.committed_by = ctx.accounts.authority.key();
+    import_root.committed_at = Clock::get()?.unix_timestamp;
+
+    emit!(ImportRootCommitted {
+        suffix: import_root.suffix.clone(),
+        root,
+        authority: import_root.committed_by,
+    });
+
+    Ok(())
+}
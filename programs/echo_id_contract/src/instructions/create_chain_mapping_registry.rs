@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct CreateChainMappingRegistry<'info> {
+    /// The alias owner or its controller.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ChainMappingTree::SPACE,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    #[account(
+        init,
+        payer = authority,
+        space = ChainMappingRegistry::SPACE,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: Account<'info, ChainMappingRegistry>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opts the alias into keeping its chain mappings on-chain. Leaves already
+/// in the tree cannot be recovered on-chain, so this must happen before any
+/// mapping is added.
+pub fn handler(ctx: Context<CreateChainMappingRegistry>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let alias = ctx.accounts.alias_account.key();
+    require!(ctx.accounts.alias_account.chain_mapping_count == 0, ErrorCode::ChainMappingRegistryMismatch);
+
+    let tree = &mut ctx.accounts.chain_mapping_tree;
+    tree.alias = alias;
+    tree.has_registry = true;
+    ctx.accounts.chain_mapping_registry.alias = alias;
+    Ok(())
+}
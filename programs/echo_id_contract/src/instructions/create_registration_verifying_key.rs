@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::zkp::Groth16VerifyingKey;
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateRegistrationVerifyingKey<'info> {
+    /// Publisher the key is namespaced under; suffixes trust it by this key.
+    /// It may be a PDA, since rent comes from `payer`.
+    pub authority: Signer<'info>,
+    /// Pays rent for the `RegistrationVerifyingKey`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = RegistrationVerifyingKey::SPACE,
+        seeds = [b"registration_verifying_key", authority.key().as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub registration_verifying_key: Box<Account<'info, RegistrationVerifyingKey>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Publishes a registration circuit's verifying key for suffixes to
+/// reference. A changed circuit gets a new `id`, and suffixes move to it
+/// through `set_suffix_config`.
+pub fn handler(ctx: Context<CreateRegistrationVerifyingKey>, id: u64, verifying_key: Groth16VerifyingKey, predicate: [u8; 16]) -> Result<()> {
+    let registration_verifying_key = &mut ctx.accounts.registration_verifying_key;
+    registration_verifying_key.authority = ctx.accounts.authority.key();
+    registration_verifying_key.verifying_key = verifying_key;
+    registration_verifying_key.predicate = predicate;
+
+    emit!(RegistrationVerifyingKeyCreated {
+        key: registration_verifying_key.key(),
+        authority: registration_verifying_key.authority,
+        id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, epoch: u64)]
+pub struct CreateReputationSnapshot<'info> {
+    /// Either the admin or the product owner of the suffix.
+    #[account(
+        mut,
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = ReputationSnapshot::space(&suffix),
+        seeds = [b"reputation_snapshot", suffix.as_bytes(), &epoch.to_le_bytes()],
+        bump
+    )]
+    pub reputation_snapshot: Account<'info, ReputationSnapshot>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Commits the root of the suffix's reputation scores for `epoch`, pinned to
+/// the current slot, for `verify_snapshot_score` to prove against.
+pub fn handler(ctx: Context<CreateReputationSnapshot>, suffix: String, epoch: u64, root: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let snapshot = &mut ctx.accounts.reputation_snapshot;
+    snapshot.suffix = suffix;
+    snapshot.epoch = epoch;
+    snapshot.root = root;
+    snapshot.slot = clock.slot;
+    snapshot.committed_by = ctx.accounts.authority.key();
+    snapshot.committed_at = clock.unix_timestamp;
+
+    emit!(ReputationSnapshotCreated {
+        suffix: snapshot.suffix.clone(),
+        epoch,
+        root,
+        slot: snapshot.slot,
+        authority: snapshot.committed_by,
+    });
+
+    Ok(())
+}
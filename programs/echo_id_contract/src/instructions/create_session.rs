@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = SessionKey::SPACE,
+        seeds = [b"session", alias_account.key().as_ref(), session_key.as_ref()],
+        bump
+    )]
+    pub session: Account<'info, SessionKey>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates or refreshes a session key. Re-creating an existing session
+/// replaces its scope and expiry.
+pub fn handler(ctx: Context<CreateSession>, _username: String, _project_suffix: String, _chain_name: String, session_key: Pubkey, scope: u8, expires_at: i64) -> Result<()> {
+    require!(scope != 0 && scope & !SESSION_SCOPE_ALL == 0, ErrorCode::InvalidSessionScope);
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, ErrorCode::InvalidSessionExpiry);
+    ctx.accounts.alias_account.require_usable(now)?;
+
+    let session = &mut ctx.accounts.session;
+    session.alias = ctx.accounts.alias_account.key();
+    session.key = session_key;
+    session.scope = scope;
+    session.expires_at = expires_at;
+    session.owner = ctx.accounts.owner.key();
+
+    emit!(SessionUpdated {
+        alias: session.alias,
+        key: session_key,
+        scope,
+        expires_at,
+        revoked: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::validation::{sub_suffix_path, validate_sub_suffix};
+use super::register_product_owner::{init_suffix_account, post_product_owner_bond};
+
+#[derive(Accounts)]
+#[instruction(parent: String, label: String)]
+pub struct CreateSubSuffix<'info> {
+    /// Owner of the parent suffix, which also owns the child. It posts the
+    /// product-owner bond, so it must hold lamports even when it is a PDA.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// Pays rent for the child's `SuffixAccount`, `SuffixConfig` and
+    /// `ReputationConfig`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [b"product_owner", owner.key().as_ref()],
+        bump
+    )]
+    pub product_owner_account: Account<'info, ProductOwner>,
+    #[account(
+        seeds = [b"suffix", parent.as_bytes()],
+        bump,
+        constraint = parent_suffix_account.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = parent_suffix_account.is_active @ ErrorCode::InvalidProjectSuffix,
+        constraint = !parent_suffix_account.is_frozen @ ErrorCode::SuffixFrozen
+    )]
+    pub parent_suffix_account: Box<Account<'info, SuffixAccount>>,
+    /// CHECK: The parent's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", parent.as_bytes()],
+        bump
+    )]
+    pub parent_suffix_config: UncheckedAccount<'info>,
+    /// CHECK: The parent's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", parent.as_bytes()],
+        bump
+    )]
+    pub parent_reputation_config: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = SuffixAccount::space(&sub_suffix_path(&label, &parent)),
+        seeds = [b"suffix", sub_suffix_path(&label, &parent).as_bytes()],
+        bump
+    )]
+    pub suffix_account: Box<Account<'info, SuffixAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = SuffixConfig::SPACE,
+        seeds = [b"suffix_config", sub_suffix_path(&label, &parent).as_bytes()],
+        bump
+    )]
+    pub suffix_config: Box<Account<'info, SuffixConfig>>,
+    #[account(
+        init,
+        payer = payer,
+        space = ReputationConfig::SPACE,
+        seeds = [b"reputation_config", sub_suffix_path(&label, &parent).as_bytes()],
+        bump
+    )]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the `label.parent` namespace for the parent's owner. The child
+/// starts from a copy of the parent's policy and configs and shares its
+/// lease, after which each is managed on its own. Aliases under the child
+/// are seeded by the full dotted path like any other suffix.
+pub fn handler(ctx: Context<CreateSubSuffix>, parent: String, label: String) -> Result<()> {
+    validate_sub_suffix(&label, &parent)?;
+    let now = Clock::get()?.unix_timestamp;
+    let parent_account = &ctx.accounts.parent_suffix_account;
+    parent_account.require_leased(now)?;
+
+    let owner = ctx.accounts.owner.key();
+    post_product_owner_bond(
+        &mut ctx.accounts.product_owner_account,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin_config.product_owner_bond,
+    )?;
+    let suffix = sub_suffix_path(&label, &parent);
+    init_suffix_account(&mut ctx.accounts.suffix_account, &mut ctx.accounts.product_owner_account, owner, suffix.clone())?;
+
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    suffix_account.transfer_requires_cosign = parent_account.transfer_requires_cosign;
+    suffix_account.renewal_fee = parent_account.renewal_fee;
+    suffix_account.require_commitment = parent_account.require_commitment;
+    suffix_account.require_registration_approval = parent_account.require_registration_approval;
+    suffix_account.transferable = parent_account.transferable;
+    suffix_account.royalty_bps = parent_account.royalty_bps;
+    suffix_account.transfer_lock_duration = parent_account.transfer_lock_duration;
+    suffix_account.expires_at = parent_account.expires_at;
+    suffix_account.parent = Some(parent_account.key());
+
+    ctx.accounts.suffix_config.set_inner(SuffixConfig::load_or_default(&ctx.accounts.parent_suffix_config)?);
+    ctx.accounts.reputation_config.set_inner(ReputationConfig::load_or_default(&ctx.accounts.parent_reputation_config)?);
+
+    emit!(SubSuffixCreated { suffix, parent, owner });
+
+    Ok(())
+}
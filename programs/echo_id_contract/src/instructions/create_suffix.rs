@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::validation::validate_suffix;
+use super::register_product_owner::{init_suffix_account, post_product_owner_bond};
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct CreateSuffix<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [b"product_owner", owner.key().as_ref()],
+        bump
+    )]
+    pub product_owner_account: Account<'info, ProductOwner>,
+    #[account(
+        init,
+        payer = owner,
+        space = SuffixAccount::space(&suffix),
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Attaches another suffix to a product owner the admin has already
+/// registered, paid for by the owner.
+pub fn handler(ctx: Context<CreateSuffix>, suffix: String) -> Result<()> {
+    validate_suffix(&suffix)?;
+    let owner = ctx.accounts.owner.key();
+    post_product_owner_bond(
+        &mut ctx.accounts.product_owner_account,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin_config.product_owner_bond,
+    )?;
+    init_suffix_account(&mut ctx.accounts.suffix_account, &mut ctx.accounts.product_owner_account, owner, suffix)
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetProductOwnerStatus<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+}
+
+/// Stops new registrations under the suffix. With `freeze`, the product
+/// owner also can no longer change the suffix's policy or configs.
+pub fn deactivate_handler(ctx: Context<SetProductOwnerStatus>, _suffix: String, freeze: bool) -> Result<()> {
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    require!(suffix_account.is_active, ErrorCode::ProductOwnerNotActive);
+    suffix_account.is_active = false;
+    suffix_account.is_frozen = freeze;
+    suffix_account.deactivated_at = Clock::get()?.unix_timestamp;
+    emit_status(suffix_account)
+}
+
+/// Reactivates and unfreezes the suffix once
+/// `PRODUCT_OWNER_REACTIVATION_COOLDOWN` has passed since deactivation.
+pub fn reactivate_handler(ctx: Context<SetProductOwnerStatus>, _suffix: String) -> Result<()> {
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    require!(!suffix_account.is_active, ErrorCode::ProductOwnerActive);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= suffix_account.deactivated_at.saturating_add(PRODUCT_OWNER_REACTIVATION_COOLDOWN), ErrorCode::ReactivationCooldown);
+    suffix_account.is_active = true;
+    suffix_account.is_frozen = false;
+    emit_status(suffix_account)
+}
+
+fn emit_status(suffix_account: &SuffixAccount) -> Result<()> {
+    emit!(ProductOwnerStatusUpdated {
+        suffix: suffix_account.suffix.clone(),
+        owner: suffix_account.owner,
+        is_active: suffix_account.is_active,
+        is_frozen: suffix_account.is_frozen,
+    });
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct DelegateReputation<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        constraint = target_alias.key() != alias_account.key() @ ErrorCode::InvalidDelegation
+    )]
+    pub target_alias: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ReputationDelegation::SPACE,
+        seeds = [b"reputation_delegation", alias_account.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, ReputationDelegation>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ReputationDelegation::SPACE,
+        seeds = [b"reputation_delegation", target_alias.key().as_ref()],
+        bump
+    )]
+    pub target_delegation: Account<'info, ReputationDelegation>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Delegates `amount` of the alias's general reputation to `target_alias`,
+/// which counts it instead of the alias in aggregate reputation and
+/// snapshots. The alias must `undelegate` before delegating again.
+pub fn handler(ctx: Context<DelegateReputation>, amount: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let alias_account = &ctx.accounts.alias_account;
+    alias_account.require_usable(now)?;
+    ctx.accounts.target_alias.require_usable(now)?;
+
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    require!(amount > 0 && amount <= alias_account.general_reputation_at(&config, now), ErrorCode::InvalidDelegation);
+
+    let delegation = &mut ctx.accounts.delegation;
+    require!(delegation.delegated == 0, ErrorCode::InvalidDelegation);
+    delegation.alias = alias_account.key();
+    delegation.delegate = ctx.accounts.target_alias.key();
+    delegation.delegated = amount;
+
+    let target_delegation = &mut ctx.accounts.target_delegation;
+    target_delegation.alias = ctx.accounts.target_alias.key();
+    target_delegation.received = target_delegation.received.saturating_add(amount);
+
+    emit!(ReputationDelegated {
+        alias: delegation.alias,
+        delegate: delegation.delegate,
+        amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_record::resize_account;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct DeleteRecord<'info> {
+    /// The alias owner, its controller, or a session key scoped for records.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// Session key acting for the alias, when `authority` is not its controller.
+    #[account(
+        constraint = session.alias == alias_account.key() @ ErrorCode::Unauthorized
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    #[account(
+        mut,
+        seeds = [b"records", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_records: Account<'info, AliasRecords>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DeleteRecord>, _username: String, _project_suffix: String, _chain_name: String, key: String) -> Result<()> {
+    ctx.accounts.alias_account.require_authority(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.session.as_deref(),
+        SESSION_SCOPE_SET_RECORD,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let alias_records = &mut ctx.accounts.alias_records;
+    let index = alias_records.records.iter().position(|r| r.key == key).ok_or(ErrorCode::RecordNotFound)?;
+    alias_records.records.remove(index);
+
+    let new_len = AliasRecords::space(&alias_records.records);
+    resize_account(
+        &alias_records.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    emit!(AliasRecordUpdated {
+        alias: alias_records.alias,
+        key,
+        value: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct EndorseAlias<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        constraint = endorser.owner == owner.key() @ ErrorCode::InvalidOwner,
+        constraint = endorser.key() != endorsee.key() @ ErrorCode::InvalidEndorsement
+    )]
+    pub endorser: Account<'info, AliasAccount>,
+    pub endorsee: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = EndorsementQuota::SPACE,
+        seeds = [b"endorsement_quota", endorser.key().as_ref()],
+        bump
+    )]
+    pub endorsement_quota: Account<'info, EndorsementQuota>,
+    #[account(
+        init,
+        payer = owner,
+        space = Endorsement::SPACE,
+        seeds = [b"endorsement", endorser.key().as_ref(), endorsee.key().as_ref()],
+        bump
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Vouches for `endorsee` with `weight` until `expires_at`, adding to its
+/// effective reputation. Each alias may have at most
+/// `MAX_ENDORSEMENTS_PER_ALIAS` endorsements outstanding.
+pub fn handler(ctx: Context<EndorseAlias>, weight: u16, expires_at: i64) -> Result<()> {
+    require!(weight > 0 && weight <= MAX_ENDORSEMENT_WEIGHT, ErrorCode::InvalidEndorsement);
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, ErrorCode::InvalidEndorsement);
+    ctx.accounts.endorser.require_usable(now)?;
+    ctx.accounts.endorsee.require_usable(now)?;
+
+    let quota = &mut ctx.accounts.endorsement_quota;
+    require!(quota.given < MAX_ENDORSEMENTS_PER_ALIAS, ErrorCode::EndorsementQuotaExceeded);
+    quota.alias = ctx.accounts.endorser.key();
+    quota.given += 1;
+
+    let endorsement = &mut ctx.accounts.endorsement;
+    endorsement.endorser = ctx.accounts.endorser.key();
+    endorsement.endorsee = ctx.accounts.endorsee.key();
+    endorsement.weight = weight;
+    endorsement.expires_at = expires_at;
+
+    emit!(EndorsementUpdated {
+        endorser: endorsement.endorser,
+        endorsee: endorsement.endorsee,
+        weight,
+        expires_at,
+        revoked: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_primary_alias::clear_reverse_record;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct ExecuteRecovery<'info> {
+    /// Anyone may execute a recovery once it is approved and the timelock elapsed.
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"guardians", alias_account.key().as_ref()],
+        bump,
+        constraint = guardian_set.owner == alias_account.owner @ ErrorCode::StaleGuardianSet
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    /// CHECK: Primary alias record of the current owner, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<ExecuteRecovery>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        None,
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+    let alias_key = ctx.accounts.alias_account.key();
+    require!(!ctx.accounts.alias_account.is_expired(now), ErrorCode::AliasExpired);
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    let recovery = guardian_set.recovery.take().ok_or(ErrorCode::RecoveryNotInitiated)?;
+    require!(recovery.approval_count() >= guardian_set.threshold, ErrorCode::RecoveryThresholdNotMet);
+    require!(now >= recovery.initiated_at.saturating_add(RECOVERY_TIMELOCK), ErrorCode::RecoveryTimelockActive);
+    // The guardians that recovered the alias keep guarding it for its new owner.
+    guardian_set.owner = recovery.new_owner;
+
+    clear_reverse_record(&ctx.accounts.reverse_record, alias_key)?;
+
+    let alias_account = &mut ctx.accounts.alias_account;
+    let old_owner = alias_account.owner;
+    alias_account.owner = recovery.new_owner;
+    alias_account.controller = recovery.new_owner;
+    alias_account.pending_owner = None;
+
+    emit!(RecoveryExecuted {
+        alias: alias_key,
+        old_owner,
+        new_owner: recovery.new_owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct FreezeAlias<'info> {
+    /// Either the admin or the product owner of the suffix.
+    #[account(
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+}
+
+pub fn freeze_handler(ctx: Context<FreezeAlias>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    set_frozen(ctx, true)
+}
+
+pub fn unfreeze_handler(ctx: Context<FreezeAlias>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    set_frozen(ctx, false)
+}
+
+fn set_frozen(ctx: Context<FreezeAlias>, is_frozen: bool) -> Result<()> {
+    let is_admin = ctx.accounts.authority.key() == ctx.accounts.admin_config.admin;
+    let alias_account = &mut ctx.accounts.alias_account;
+    require!(is_admin || alias_account.owner_managed, ErrorCode::ProductOwnerControlRenounced);
+    alias_account.is_frozen = is_frozen;
+
+    emit!(AliasFreezeUpdated {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        authority: ctx.accounts.authority.key(),
+        is_frozen,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct GetEffectiveReputation<'info> {
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationCategories`, read through
+    /// `ReputationCategories::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_categories", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_categories: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ReputationVault`, read through
+    /// `ReputationVault::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_vault", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_vault: UncheckedAccount<'info>,
+}
+
+/// Returns, as return data, the alias's reputation in `category` decayed to
+/// the current time under that category's half-life. For the general score,
+/// reputation escrowed by a transfer counts as it decays, SOL staked behind
+/// the alias adds up to `MAX_STAKE_BONUS`, and live endorsements and
+/// attestor boosts passed as remaining accounts add their weight, up to
+/// `MAX_ENDORSEMENT_BONUS` and `MAX_BOOST_BONUS`; they must be in increasing
+/// address order.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, GetEffectiveReputation<'info>>, _username: String, _project_suffix: String, _chain_name: String, category: u8) -> Result<i64> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    let categories = ReputationCategories::load_or_default(&ctx.accounts.reputation_categories)?;
+    let half_life = categories.half_life(category, &config)?;
+    let alias = ctx.accounts.alias_account.key();
+    let (reputation, updated_at) = ctx.accounts.alias_account.category_reputation(category)?;
+    let reputation = decay_reputation(reputation, updated_at, now, half_life);
+    if category != 0 {
+        return Ok(reputation);
+    }
+    let escrowed = ctx.accounts.alias_account.escrowed_reputation_at(now);
+    general_reputation_bonus(&alias, &ctx.accounts.reputation_vault, ctx.remaining_accounts, now).map(|bonus| reputation.saturating_add(escrowed).saturating_add(bonus))
+}
+
+/// What the general score gains from SOL staked in `reputation_vault` and
+/// from the live `endorsements`, which may be `Endorsement` or
+/// `ReputationBoost` accounts, each kind up to its cap. They must be in
+/// increasing address order so none is counted twice.
+pub(crate) fn general_reputation_bonus(alias: &Pubkey, reputation_vault: &AccountInfo, endorsements: &[AccountInfo], now: i64) -> Result<i64> {
+    let (mut endorsement_bonus, mut boost_bonus) = (0i64, 0i64);
+    let mut previous = None;
+    for info in endorsements {
+        require!(previous < Some(info.key), ErrorCode::InvalidEndorsement);
+        previous = Some(info.key);
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidEndorsement);
+        let data = info.try_borrow_data()?;
+        if data.starts_with(&ReputationBoost::DISCRIMINATOR) {
+            let boost = ReputationBoost::try_deserialize(&mut &data[..])?;
+            require_keys_eq!(boost.alias, *alias, ErrorCode::InvalidBoost);
+            if boost.is_active(now) {
+                boost_bonus = boost_bonus.saturating_add(boost.amount);
+            }
+            continue;
+        }
+        let endorsement = Endorsement::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(endorsement.endorsee, *alias, ErrorCode::InvalidEndorsement);
+        if endorsement.is_active(now) {
+            endorsement_bonus += endorsement.weight as i64;
+        }
+    }
+    let stake_bonus = ReputationVault::load_or_default(reputation_vault)?.bonus();
+    Ok(endorsement_bonus.min(MAX_ENDORSEMENT_BONUS) + boost_bonus.min(MAX_BOOST_BONUS) + stake_bonus)
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct GrantReputationBoost<'info> {
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"reputation_attestor", alias_account.product_suffix.as_bytes(), attestor.key().as_ref()],
+        bump
+    )]
+    pub reputation_attestor: Account<'info, ReputationAttestor>,
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = ReputationBoost::SPACE,
+        seeds = [b"reputation_boost", alias_account.key().as_ref(), attestor.key().as_ref()],
+        bump
+    )]
+    pub reputation_boost: Account<'info, ReputationBoost>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Boosts the alias's effective reputation by `amount` until `expires_at`,
+/// replacing any earlier boost from the same attestor.
+pub fn handler(ctx: Context<GrantReputationBoost>, amount: i64, expires_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.alias_account.require_usable(now)?;
+    require!(amount > 0 && amount <= ctx.accounts.reputation_attestor.max_boost, ErrorCode::InvalidBoost);
+    require!(expires_at > now && expires_at <= now.saturating_add(MAX_BOOST_DURATION), ErrorCode::InvalidBoost);
+
+    let reputation_boost = &mut ctx.accounts.reputation_boost;
+    reputation_boost.alias = ctx.accounts.alias_account.key();
+    reputation_boost.attestor = ctx.accounts.attestor.key();
+    reputation_boost.amount = amount;
+    reputation_boost.expires_at = expires_at;
+
+    emit!(ReputationBoostUpdated {
+        alias: reputation_boost.alias,
+        attestor: reputation_boost.attestor,
+        amount,
+        expires_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct IncrementNonce<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+}
+
+/// Lets the owner revoke every authorization it has signed but not yet used.
+pub fn handler(ctx: Context<IncrementNonce>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.advance_nonce();
+
+    emit!(AliasNonceAdvanced {
+        alias: alias_account.key(),
+        nonce: alias_account.nonce,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::AdminConfig;
+use crate::state::{AdminConfig, MAX_ROOT_HISTORY, PRODUCT_OWNER_BOND, PROTOCOL_FEE_BPS, SUFFIX_RENEWAL_FEE};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -8,7 +8,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32,
+        space = AdminConfig::SPACE,
         seeds = [b"admin"],
         bump
     )]
@@ -19,5 +19,9 @@ pub struct Initialize<'info> {
 pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     let admin_config = &mut ctx.accounts.admin_config;
     admin_config.admin = ctx.accounts.admin.key();
+    admin_config.root_history_depth = MAX_ROOT_HISTORY as u8;
+    admin_config.registration_fee_bps = PROTOCOL_FEE_BPS;
+    admin_config.product_owner_bond = PRODUCT_OWNER_BOND;
+    admin_config.suffix_renewal_fee = SUFFIX_RENEWAL_FEE;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct InitiateRecovery<'info> {
+    pub guardian: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"guardians", alias_account.key().as_ref()],
+        bump,
+        constraint = guardian_set.owner == alias_account.owner @ ErrorCode::StaleGuardianSet,
+        constraint = guardian_set.recovery.is_none() @ ErrorCode::RecoveryInProgress
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+}
+
+/// Starts a recovery to `new_owner`; the initiating guardian counts as the
+/// first approval. A listed alias is owned by its listing, so its guardian
+/// set reads as stale until the listing is cancelled.
+pub fn handler(ctx: Context<InitiateRecovery>, _username: String, _project_suffix: String, _chain_name: String, new_owner: Pubkey) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    let index = guardian_set.guardian_index(&guardian).ok_or(ErrorCode::NotAGuardian)?;
+    require!(new_owner != ctx.accounts.alias_account.owner, ErrorCode::InvalidOwner);
+
+    guardian_set.recovery = Some(RecoveryRequest {
+        new_owner,
+        approvals: 1 << index,
+        initiated_at: Clock::get()?.unix_timestamp,
+    });
+
+    emit!(RecoveryInitiated {
+        alias: guardian_set.alias,
+        guardian,
+        new_owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct LiftQuarantine<'info> {
+    /// Anyone once the rehabilitation period has passed; before that, the
+    /// admin or the suffix's dispute arbiter upholding an appeal.
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<LiftQuarantine>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    let authority = ctx.accounts.authority.key();
+    let alias_key = ctx.accounts.alias_account.key();
+    let alias_account = &mut ctx.accounts.alias_account;
+    require!(alias_account.is_quarantined, ErrorCode::AliasNotQuarantined);
+
+    let now = Clock::get()?.unix_timestamp;
+    let rehabilitated = now >= alias_account.quarantined_at.saturating_add(config.rehabilitation_period);
+    let appeal = authority == ctx.accounts.admin_config.admin || (config.dispute_arbiter != Pubkey::default() && authority == config.dispute_arbiter);
+    require!(rehabilitated || appeal, ErrorCode::RehabilitationPending);
+    alias_account.is_quarantined = false;
+
+    emit!(AliasQuarantineUpdated {
+        alias: alias_key,
+        is_quarantined: false,
+        reputation: alias_account.reputation,
+        authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct LinkAliases<'info> {
+    #[account(mut)]
+    pub owner_a: Signer<'info>,
+    pub owner_b: Signer<'info>,
+    #[account(
+        constraint = alias_a.owner == owner_a.key() @ ErrorCode::InvalidOwner,
+        constraint = alias_a.key() < alias_b.key() @ ErrorCode::InvalidAliasLink
+    )]
+    pub alias_a: Account<'info, AliasAccount>,
+    #[account(
+        constraint = alias_b.owner == owner_b.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_b: Account<'info, AliasAccount>,
+    #[account(
+        init,
+        payer = owner_a,
+        space = AliasLink::SPACE,
+        seeds = [b"link", alias_a.key().as_ref(), alias_b.key().as_ref()],
+        bump
+    )]
+    pub alias_link: Account<'info, AliasLink>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<LinkAliases>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.alias_a.require_usable(now)?;
+    ctx.accounts.alias_b.require_usable(now)?;
+
+    let alias_link = &mut ctx.accounts.alias_link;
+    alias_link.alias_a = ctx.accounts.alias_a.key();
+    alias_link.alias_b = ctx.accounts.alias_b.key();
+    alias_link.created_at = now;
+
+    emit!(AliasesLinked {
+        alias_a: alias_link.alias_a,
+        alias_b: alias_link.alias_b,
+    });
+
+    Ok(())
+}
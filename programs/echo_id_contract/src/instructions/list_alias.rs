@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct ListAlias<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.transferable @ ErrorCode::AliasNotTransferable
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = Listing::SPACE,
+        seeds = [b"listing", alias_account.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows the alias by handing ownership and control to the listing PDA
+/// until it is bought or the listing is cancelled.
+pub fn handler(ctx: Context<ListAlias>, _username: String, _project_suffix: String, _chain_name: String, price: u64) -> Result<()> {
+    require!(price > 0, ErrorCode::InvalidListingPrice);
+
+    let now = Clock::get()?.unix_timestamp;
+    let listing_key = ctx.accounts.listing.key();
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_usable(now)?;
+    alias_account.require_transfer_unlocked(&ctx.accounts.suffix_account, now)?;
+    alias_account.owner = listing_key;
+    alias_account.controller = listing_key;
+    alias_account.pending_owner = None;
+
+    let listing = &mut ctx.accounts.listing;
+    listing.alias = alias_account.key();
+    listing.seller = ctx.accounts.owner.key();
+    listing.price = price;
+    listing.created_at = now;
+    listing.alias_created_at = alias_account.created_at;
+
+    emit!(AliasListed {
+        alias: listing.alias,
+        seller: listing.seller,
+        price,
+    });
+
+    Ok(())
+}
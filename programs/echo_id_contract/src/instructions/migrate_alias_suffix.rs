@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_primary_alias::repoint_reverse_record;
+use crate::instructions::reserve_name::reserved_name_hash;
+use crate::instructions::close_alias::write_tombstone;
+use crate::instructions::rename_alias::{move_alias_pda, move_chain_mappings};
+use crate::validation::validate_suffix_username;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, new_suffix: String)]
+pub struct MigrateAliasSuffix<'info> {
+    #[account(
+        constraint = source_product_owner.key() == source_suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub source_product_owner: Signer<'info>,
+    /// Pays for the migrated alias account.
+    #[account(
+        mut,
+        constraint = destination_product_owner.key() == destination_suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub destination_product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+    )]
+    pub source_suffix_account: Box<Account<'info, SuffixAccount>>,
+    #[account(
+        seeds = [b"suffix", new_suffix.as_bytes()],
+        bump,
+        constraint = destination_suffix_account.is_active @ ErrorCode::InvalidProjectSuffix,
+        constraint = !destination_suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub destination_suffix_account: Box<Account<'info, SuffixAccount>>,
+    /// CHECK: Receives the rent of the old alias account; must be the alias owner.
+    #[account(
+        mut,
+        constraint = owner.key() == alias_account.owner @ ErrorCode::InvalidOwner
+    )]
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner != listing.key() @ ErrorCode::AliasListed
+    )]
+    pub alias_account: Box<Account<'info, AliasAccount>>,
+    /// CHECK: The alias's `Listing` PDA. A listed alias is escrowed by it
+    /// and cannot move until the listing is cancelled or bought.
+    #[account(
+        seeds = [b"listing", alias_account.key().as_ref()],
+        bump
+    )]
+    pub listing: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = destination_product_owner,
+        space = AliasAccount::space(&username, &new_suffix, &alias_account.chain_info, &alias_account.metadata) + alias_account.display_name.len(),
+        seeds = [username.as_bytes(), b"@", new_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub new_alias_account: Box<Account<'info, AliasAccount>>,
+    /// CHECK: Must be empty; an initialized account here means the name is reserved.
+    #[account(
+        seeds = [b"reserved", new_suffix.as_bytes(), reserved_name_hash(&username).as_ref()],
+        bump,
+        constraint = reserved_name.data_is_empty() @ ErrorCode::NameReserved
+    )]
+    pub reserved_name: UncheckedAccount<'info>,
+    /// CHECK: The destination suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", new_suffix.as_bytes()],
+        bump
+    )]
+    pub destination_suffix_config: UncheckedAccount<'info>,
+    /// CHECK: Primary alias record of the owner, moved to the new PDA if it points at the old one.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = destination_product_owner,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", project_suffix.as_bytes()],
+        bump
+    )]
+    pub source_suffix_stats: Box<Account<'info, SuffixStats>>,
+    #[account(
+        init_if_needed,
+        payer = destination_product_owner,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", new_suffix.as_bytes()],
+        bump
+    )]
+    pub destination_suffix_stats: Box<Account<'info, SuffixStats>>,
+    /// Redirect from the old address to the migrated alias.
+    #[account(
+        init_if_needed,
+        payer = destination_product_owner,
+        space = Tombstone::SPACE,
+        seeds = [b"tombstone", alias_account.key().as_ref()],
+        bump
+    )]
+    pub tombstone: Option<Account<'info, Tombstone>>,
+    /// CHECK: The old alias's `ChainMappingTree`, moved to the new alias if
+    /// it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: UncheckedAccount<'info>,
+    /// CHECK: The `ChainMappingTree` address of the new alias; must be free.
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", new_alias_account.key().as_ref()],
+        bump
+    )]
+    pub new_chain_mapping_tree: UncheckedAccount<'info>,
+    /// CHECK: The old alias's `ChainMappingRegistry`, moved to the new alias
+    /// if it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: UncheckedAccount<'info>,
+    /// CHECK: The `ChainMappingRegistry` address of the new alias; must be free.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", new_alias_account.key().as_ref()],
+        bump
+    )]
+    pub new_chain_mapping_registry: UncheckedAccount<'info>,
+    /// CHECK: The old alias's `AliasRecords`, moved to the new alias if they
+    /// exist.
+    #[account(
+        mut,
+        seeds = [b"records", alias_account.key().as_ref()],
+        bump
+    )]
+    pub records: UncheckedAccount<'info>,
+    /// CHECK: The `AliasRecords` address of the new alias; must be free.
+    #[account(
+        mut,
+        seeds = [b"records", new_alias_account.key().as_ref()],
+        bump
+    )]
+    pub new_records: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateAliasSuffix>, username: String, project_suffix: String, chain_name: String, new_suffix: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        Some(&ctx.accounts.owner),
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.destination_suffix_account.require_leased(now)?;
+    validate_suffix_username(&ctx.accounts.alias_account.username, &SuffixConfig::load_or_default(&ctx.accounts.destination_suffix_config)?)?;
+
+    let old_key = ctx.accounts.alias_account.key();
+    let new_key = ctx.accounts.new_alias_account.key();
+    ctx.accounts.source_suffix_stats.record_removal();
+    ctx.accounts.destination_suffix_stats.record_registration();
+    repoint_reverse_record(&ctx.accounts.reverse_record, old_key, Some(new_key))?;
+    write_tombstone(&mut ctx.accounts.tombstone, old_key, Some(new_key))?;
+    move_chain_mappings(
+        (&ctx.accounts.chain_mapping_tree, &ctx.accounts.new_chain_mapping_tree, ctx.bumps.new_chain_mapping_tree),
+        (&ctx.accounts.chain_mapping_registry, &ctx.accounts.new_chain_mapping_registry, ctx.bumps.new_chain_mapping_registry),
+        new_key,
+        &ctx.accounts.system_program,
+    )?;
+    move_alias_pda(&ctx.accounts.records, &ctx.accounts.new_records, b"records", new_key, ctx.bumps.new_records, &ctx.accounts.system_program)?;
+
+    let old_alias = &ctx.accounts.alias_account;
+    old_alias.require_usable(now)?;
+
+    ctx.accounts.new_alias_account.set_inner(AliasAccount {
+        product_suffix: new_suffix,
+        pending_owner: None,
+        ..(***old_alias).clone()
+    });
+    let new_alias = &ctx.accounts.new_alias_account;
+
+    emit!(AliasSuffixMigrated {
+        username: new_alias.username.clone(),
+        old_suffix: old_alias.product_suffix.clone(),
+        new_suffix: new_alias.product_suffix.clone(),
+        chain_info: new_alias.chain_info.clone(),
+        owner: new_alias.owner,
+    });
+
+    Ok(())
+}
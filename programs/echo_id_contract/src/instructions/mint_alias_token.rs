@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+};
+use anchor_lang::pubkey;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Base mint padded to the account length, the account type byte, then the
+// NonTransferable (no data), PermanentDelegate (32 bytes) and
+// MintCloseAuthority (32 bytes) TLV entries.
+const ALIAS_MINT_SPACE: usize = 165 + 1 + 4 + (4 + 32) + (4 + 32);
+// The supply follows the mint's `COption<Pubkey>` mint authority.
+const MINT_SUPPLY_OFFSET: usize = 4 + 32;
+
+const IX_MINT_TO: u8 = 7;
+const IX_BURN: u8 = 8;
+const IX_CLOSE_ACCOUNT: u8 = 9;
+const IX_INITIALIZE_MINT_2: u8 = 20;
+const IX_INITIALIZE_MINT_CLOSE_AUTHORITY: u8 = 25;
+const IX_INITIALIZE_NON_TRANSFERABLE_MINT: u8 = 32;
+const IX_INITIALIZE_PERMANENT_DELEGATE: u8 = 35;
+const IX_CREATE_ASSOCIATED_TOKEN_ACCOUNT_IDEMPOTENT: u8 = 1;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct MintAliasToken<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: Created and initialized here as a non-transferable Token-2022
+    /// mint, unless an earlier owner's token was already burned from it.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: The owner's associated token account, created by the associated token program.
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+    /// CHECK: Token-2022 program.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+    /// CHECK: Associated token account program.
+    #[account(address = ASSOCIATED_TOKEN_PROGRAM_ID)]
+    pub associated_token_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mints a single soulbound token representing the alias. The alias PDA is
+/// the mint authority, the permanent delegate and the close authority, so
+/// the program can burn the token when the alias changes hands and close the
+/// mint with the alias, without the holder's signature.
+pub fn handler(ctx: Context<MintAliasToken>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+    ctx.accounts.alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+
+    let accounts = &ctx.accounts;
+    let alias_key = accounts.alias_account.key();
+    let mint = accounts.alias_mint.to_account_info();
+
+    if mint.data_is_empty() {
+        create_alias_mint(accounts, alias_key, ctx.bumps.alias_mint)?;
+    } else {
+        require!(alias_token_supply(&mint)? == 0, ErrorCode::AliasTokenAlreadyMinted);
+    }
+
+    invoke(
+        &Instruction::new_with_bytes(
+            ASSOCIATED_TOKEN_PROGRAM_ID,
+            &[IX_CREATE_ASSOCIATED_TOKEN_ACCOUNT_IDEMPOTENT],
+            vec![
+                AccountMeta::new(accounts.owner.key(), true),
+                AccountMeta::new(accounts.token_account.key(), false),
+                AccountMeta::new_readonly(accounts.owner.key(), false),
+                AccountMeta::new_readonly(mint.key(), false),
+                AccountMeta::new_readonly(accounts.system_program.key(), false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+            ],
+        ),
+        &[
+            accounts.owner.to_account_info(),
+            accounts.token_account.to_account_info(),
+            mint.clone(),
+            accounts.system_program.to_account_info(),
+            accounts.token_program.to_account_info(),
+        ],
+    )?;
+
+    let mut data = vec![IX_MINT_TO];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    invoke_signed(
+        &Instruction::new_with_bytes(
+            TOKEN_2022_PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(mint.key(), false),
+                AccountMeta::new(accounts.token_account.key(), false),
+                AccountMeta::new_readonly(alias_key, true),
+            ],
+        ),
+        &[mint, accounts.token_account.to_account_info(), accounts.alias_account.to_account_info()],
+        &[&[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]]],
+    )?;
+
+    emit!(AliasTokenMinted {
+        alias: alias_key,
+        mint: accounts.alias_mint.key(),
+        owner: accounts.owner.key(),
+    });
+
+    Ok(())
+}
+
+fn create_alias_mint(accounts: &MintAliasToken, alias_key: Pubkey, bump: u8) -> Result<()> {
+    let mint = accounts.alias_mint.to_account_info();
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: accounts.owner.to_account_info(),
+                to: mint.clone(),
+            },
+            &[&[b"alias_mint", alias_key.as_ref(), &[bump]]],
+        ),
+        Rent::get()?.minimum_balance(ALIAS_MINT_SPACE),
+        ALIAS_MINT_SPACE as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    )?;
+
+    let mint_meta = vec![AccountMeta::new(mint.key(), false)];
+    invoke(
+        &Instruction::new_with_bytes(TOKEN_2022_PROGRAM_ID, &[IX_INITIALIZE_NON_TRANSFERABLE_MINT], mint_meta.clone()),
+        std::slice::from_ref(&mint),
+    )?;
+
+    let mut data = vec![IX_INITIALIZE_PERMANENT_DELEGATE];
+    data.extend_from_slice(alias_key.as_ref());
+    invoke(&Instruction::new_with_bytes(TOKEN_2022_PROGRAM_ID, &data, mint_meta.clone()), std::slice::from_ref(&mint))?;
+
+    let mut data = vec![IX_INITIALIZE_MINT_CLOSE_AUTHORITY, 1];
+    data.extend_from_slice(alias_key.as_ref());
+    invoke(&Instruction::new_with_bytes(TOKEN_2022_PROGRAM_ID, &data, mint_meta.clone()), std::slice::from_ref(&mint))?;
+
+    // Zero decimals, the alias PDA as mint authority and no freeze authority.
+    let mut data = vec![IX_INITIALIZE_MINT_2, 0];
+    data.extend_from_slice(alias_key.as_ref());
+    data.push(0);
+    invoke(&Instruction::new_with_bytes(TOKEN_2022_PROGRAM_ID, &data, mint_meta), std::slice::from_ref(&mint))?;
+    Ok(())
+}
+
+fn alias_token_supply(alias_mint: &AccountInfo) -> Result<u64> {
+    let data = alias_mint.try_borrow_data()?;
+    let supply = data.get(MINT_SUPPLY_OFFSET..MINT_SUPPLY_OFFSET + 8).ok_or(ErrorCode::InvalidDerivedAccount)?;
+    Ok(u64::from_le_bytes(supply.try_into().unwrap()))
+}
+
+/// Burns the alias token, if one is outstanding, so it never stays with an
+/// owner or address the alias has left; the new owner may mint it again.
+/// With `close_to`, also closes the mint into it so a later alias at the
+/// same address starts without one. Mints created before they carried a
+/// close authority cannot be closed and are left empty.
+pub(crate) fn retire_alias_token<'info>(
+    alias_account: &AccountInfo<'info>,
+    alias_seeds: &[&[u8]],
+    alias_mint: &AccountInfo<'info>,
+    token_account: Option<&AccountInfo<'info>>,
+    token_program: Option<&AccountInfo<'info>>,
+    close_to: Option<&AccountInfo<'info>>,
+) -> Result<()> {
+    if alias_mint.data_is_empty() {
+        return Ok(());
+    }
+    require!(token_program.is_some(), ErrorCode::AliasTokenAccountsRequired);
+    if alias_token_supply(alias_mint)? > 0 {
+        let token_account = token_account.ok_or(ErrorCode::AliasTokenAccountsRequired)?;
+        burn_alias_token(alias_account, alias_seeds, alias_mint, token_account)?;
+    }
+
+    if let Some(recipient) = close_to.filter(|_| alias_mint.data_len() == ALIAS_MINT_SPACE) {
+        invoke_signed(
+            &Instruction::new_with_bytes(
+                TOKEN_2022_PROGRAM_ID,
+                &[IX_CLOSE_ACCOUNT],
+                vec![
+                    AccountMeta::new(alias_mint.key(), false),
+                    AccountMeta::new(recipient.key(), false),
+                    AccountMeta::new_readonly(alias_account.key(), true),
+                ],
+            ),
+            &[alias_mint.clone(), recipient.clone(), alias_account.clone()],
+            &[alias_seeds],
+        )?;
+    }
+    Ok(())
+}
+
+/// Burns the alias token held in `token_account`, signing as the alias PDA
+/// through its permanent delegate authority.
+pub(crate) fn burn_alias_token<'info>(
+    alias_account: &AccountInfo<'info>,
+    alias_seeds: &[&[u8]],
+    alias_mint: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+) -> Result<()> {
+    let mut data = vec![IX_BURN];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    invoke_signed(
+        &Instruction::new_with_bytes(
+            TOKEN_2022_PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(token_account.key(), false),
+                AccountMeta::new(alias_mint.key(), false),
+                AccountMeta::new_readonly(alias_account.key(), true),
+            ],
+        ),
+        &[token_account.clone(), alias_mint.clone(), alias_account.clone()],
+        &[alias_seeds],
+    )?;
+    Ok(())
+}
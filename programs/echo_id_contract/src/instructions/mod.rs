@@ -1,9 +1,249 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
 pub mod register_alias;
 pub mod update_reputation;
 pub mod register_product_owner;
+pub mod transfer_alias;
+pub mod set_suffix_policy;
+pub mod propose_transfer;
+pub mod accept_transfer;
+pub mod cancel_transfer;
+pub mod close_alias;
+pub mod renew_alias;
+pub mod reclaim_expired_alias;
+pub mod rename_alias;
+pub mod register_aliases_batch;
+pub mod commit_alias;
+pub mod reserve_name;
+pub mod release_name;
+pub mod set_primary_alias;
+pub mod set_controller;
+pub mod update_alias_metadata;
+pub mod set_record;
+pub mod delete_record;
+pub mod set_content_hash;
+pub mod register_alias_with_voucher;
+pub mod freeze_alias;
+pub mod migrate_alias_suffix;
+pub mod upgrade_alias_account;
+pub mod rotate_owner_key;
+pub mod set_guardians;
+pub mod initiate_recovery;
+pub mod approve_recovery;
+pub mod cancel_recovery;
+pub mod execute_recovery;
+pub mod register_sub_alias;
+pub mod close_sub_alias;
+pub mod check_availability;
+pub mod mint_alias_token;
+pub mod list_alias;
+pub mod buy_alias;
+pub mod cancel_listing;
+pub mod add_key;
+pub mod remove_key;
+pub mod verify_alias_ownership;
+pub mod increment_nonce;
+pub mod create_session;
+pub mod revoke_session;
+pub mod link_aliases;
+pub mod unlink_aliases;
+pub mod renounce_product_owner_control;
+pub mod commit_import_root;
+pub mod claim_imported_alias;
+pub mod set_display_name;
+pub mod add_chain_mapping;
+pub mod add_chain_mapping_with_proof;
+pub mod remove_chain_mapping;
+pub mod update_chain_mapping;
+pub mod verify_chain_mapping;
+pub mod set_root_history_depth;
+pub mod create_chain_mapping_registry;
+pub mod reattest_chain_mapping;
+pub mod set_chain_mapping_hash;
+pub mod set_suffix_config;
+pub mod verify_chain_mappings_multi;
+pub mod get_effective_reputation;
+pub mod add_reputation_authority;
+pub mod remove_reputation_authority;
+pub mod update_reputation_batch;
+pub mod set_reputation_category;
+pub mod endorse_alias;
+pub mod revoke_endorsement;
+pub mod stake_for_reputation;
+pub mod unstake;
+pub mod withdraw_stake;
+pub mod add_reputation_slasher;
+pub mod remove_reputation_slasher;
+pub mod slash_reputation;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod claim_badge;
+pub mod revoke_badge;
+pub mod create_reputation_snapshot;
+pub mod verify_snapshot_score;
+pub mod set_reputation_verifying_key;
+pub mod verify_reputation_threshold;
+pub mod post_reputation_bond;
+pub mod challenge_update;
+pub mod set_suffix_weight;
+pub mod aggregate_reputation;
+pub mod cache_aggregate_reputation;
+pub mod require_min_reputation;
+pub mod lift_quarantine;
+pub mod set_reputation_config;
+pub mod delegate_reputation;
+pub mod undelegate;
+pub mod add_reputation_attestor;
+pub mod remove_reputation_attestor;
+pub mod grant_reputation_boost;
+pub mod close_reputation_boost;
+pub mod deactivate_product_owner;
+pub mod remove_product_owner;
+pub mod propose_owner_key_rotation;
+pub mod accept_owner_key_rotation;
+pub mod create_suffix;
+pub mod pause_suffix;
+pub mod transfer_suffix;
+pub mod add_registrar;
+pub mod remove_registrar;
+pub mod withdraw_suffix_treasury;
+pub mod set_registration_fee_bps;
+pub mod close_product_owner;
+pub mod slash_product_owner_bond;
+pub mod set_product_owner_bond;
+pub mod commit_allowlist_root;
+pub mod add_allowlist_entry;
+pub mod remove_allowlist_entry;
+pub mod set_product_owner_profile;
+pub mod renew_suffix;
+pub mod reclaim_suffix;
+pub mod set_suffix_renewal_fee;
+pub mod create_sub_suffix;
+pub mod create_registration_verifying_key;
+pub mod upgrade_admin_config;
+pub mod upgrade_suffix_account;
 
 pub use initialize::*;
 pub use register_alias::*;
 pub use update_reputation::*;
 pub use register_product_owner::*;
+pub use transfer_alias::*;
+pub use set_suffix_policy::*;
+pub use propose_transfer::*;
+pub use accept_transfer::*;
+pub use cancel_transfer::*;
+pub use close_alias::*;
+pub use renew_alias::*;
+pub use reclaim_expired_alias::*;
+pub use rename_alias::*;
+pub use register_aliases_batch::*;
+pub use commit_alias::*;
+pub use reserve_name::*;
+pub use release_name::*;
+pub use set_primary_alias::*;
+pub use set_controller::*;
+pub use update_alias_metadata::*;
+pub use set_record::*;
+pub use delete_record::*;
+pub use set_content_hash::*;
+pub use register_alias_with_voucher::*;
+pub use freeze_alias::*;
+pub use migrate_alias_suffix::*;
+pub use upgrade_alias_account::*;
+pub use rotate_owner_key::*;
+pub use set_guardians::*;
+pub use initiate_recovery::*;
+pub use approve_recovery::*;
+pub use cancel_recovery::*;
+pub use execute_recovery::*;
+pub use register_sub_alias::*;
+pub use close_sub_alias::*;
+pub use check_availability::*;
+pub use mint_alias_token::*;
+pub use list_alias::*;
+pub use buy_alias::*;
+pub use cancel_listing::*;
+pub use add_key::*;
+pub use remove_key::*;
+pub use verify_alias_ownership::*;
+pub use increment_nonce::*;
+pub use create_session::*;
+pub use revoke_session::*;
+pub use link_aliases::*;
+pub use unlink_aliases::*;
+pub use renounce_product_owner_control::*;
+pub use commit_import_root::*;
+pub use claim_imported_alias::*;
+pub use set_display_name::*;
+pub use add_chain_mapping::*;
+pub use add_chain_mapping_with_proof::*;
+pub use remove_chain_mapping::*;
+pub use update_chain_mapping::*;
+pub use verify_chain_mapping::*;
+pub use set_root_history_depth::*;
+pub use create_chain_mapping_registry::*;
+pub use reattest_chain_mapping::*;
+pub use set_chain_mapping_hash::*;
+pub use set_suffix_config::*;
+pub use verify_chain_mappings_multi::*;
+pub use get_effective_reputation::*;
+pub use add_reputation_authority::*;
+pub use remove_reputation_authority::*;
+pub use update_reputation_batch::*;
+pub use set_reputation_category::*;
+pub use endorse_alias::*;
+pub use revoke_endorsement::*;
+pub use stake_for_reputation::*;
+pub use unstake::*;
+pub use withdraw_stake::*;
+pub use add_reputation_slasher::*;
+pub use remove_reputation_slasher::*;
+pub use slash_reputation::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use claim_badge::*;
+pub use revoke_badge::*;
+pub use create_reputation_snapshot::*;
+pub use verify_snapshot_score::*;
+pub use set_reputation_verifying_key::*;
+pub use verify_reputation_threshold::*;
+pub use post_reputation_bond::*;
+pub use challenge_update::*;
+pub use set_suffix_weight::*;
+pub use aggregate_reputation::*;
+pub use cache_aggregate_reputation::*;
+pub use require_min_reputation::*;
+pub use lift_quarantine::*;
+pub use set_reputation_config::*;
+pub use delegate_reputation::*;
+pub use undelegate::*;
+pub use add_reputation_attestor::*;
+pub use remove_reputation_attestor::*;
+pub use grant_reputation_boost::*;
+pub use close_reputation_boost::*;
+pub use deactivate_product_owner::*;
+pub use remove_product_owner::*;
+pub use propose_owner_key_rotation::*;
+pub use accept_owner_key_rotation::*;
+pub use create_suffix::*;
+pub use pause_suffix::*;
+pub use transfer_suffix::*;
+pub use add_registrar::*;
+pub use remove_registrar::*;
+pub use withdraw_suffix_treasury::*;
+pub use set_registration_fee_bps::*;
+pub use close_product_owner::*;
+pub use slash_product_owner_bond::*;
+pub use set_product_owner_bond::*;
+pub use commit_allowlist_root::*;
+pub use add_allowlist_entry::*;
+pub use remove_allowlist_entry::*;
+pub use set_product_owner_profile::*;
+pub use renew_suffix::*;
+pub use reclaim_suffix::*;
+pub use set_suffix_renewal_fee::*;
+pub use create_sub_suffix::*;
+pub use create_registration_verifying_key::*;
+pub use upgrade_admin_config::*;
+pub use upgrade_suffix_account::*;
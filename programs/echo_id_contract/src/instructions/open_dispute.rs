@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(history_index: u8)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"reputation_history", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_history: Box<Account<'info, ReputationHistory>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Dispute::SPACE,
+        seeds = [b"dispute", alias_account.key().as_ref(), &[history_index]],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Challenges the reputation change at `history_index` in the alias's
+/// history, posting `DISPUTE_BOND` until an arbiter resolves it. A slot's
+/// dispute account is reused once the history wraps around to a new entry.
+pub fn handler(ctx: Context<OpenDispute>, history_index: u8) -> Result<()> {
+    let accounts = ctx.accounts;
+    open_dispute(&accounts.owner, accounts.alias_account.key(), &accounts.reputation_history, &mut accounts.dispute, &accounts.system_program, history_index, false)
+}
+
+/// Records a dispute over `history`'s entry at `history_index`, moving the
+/// bond from `opener` into the dispute account.
+pub(crate) fn open_dispute<'info>(opener: &Signer<'info>, alias: Pubkey, history: &ReputationHistory, dispute: &mut Account<'info, Dispute>, system_program: &Program<'info, System>, history_index: u8, challenge: bool) -> Result<()> {
+    require!(history_index < history.len, ErrorCode::InvalidDispute);
+    let entry = history.entries[history_index as usize];
+    require!(entry.delta != 0, ErrorCode::InvalidDispute);
+
+    if dispute.alias != Pubkey::default() {
+        require!(dispute.status != DisputeStatus::Open, ErrorCode::InvalidDispute);
+        require!(dispute.entry.timestamp != entry.timestamp || dispute.entry.authority != entry.authority, ErrorCode::InvalidDispute);
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: opener.to_account_info(),
+                to: dispute.to_account_info(),
+            },
+        ),
+        DISPUTE_BOND,
+    )?;
+
+    dispute.alias = alias;
+    dispute.opener = opener.key();
+    dispute.history_index = history_index;
+    dispute.entry = entry;
+    dispute.bond = DISPUTE_BOND;
+    dispute.opened_at = Clock::get()?.unix_timestamp;
+    dispute.status = DisputeStatus::Open;
+    dispute.challenge = challenge;
+
+    emit!(DisputeOpened {
+        alias,
+        opener: dispute.opener,
+        history_index,
+        bond: dispute.bond,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetSuffixPause<'info> {
+    pub product_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+}
+
+/// Stops registrations, chain mapping additions and reputation updates
+/// under the suffix until `unpause_suffix`.
+pub fn pause_handler(ctx: Context<SetSuffixPause>, _suffix: String) -> Result<()> {
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    require!(!suffix_account.is_paused, ErrorCode::SuffixPaused);
+    suffix_account.is_paused = true;
+    emit_pause(suffix_account)
+}
+
+pub fn unpause_handler(ctx: Context<SetSuffixPause>, _suffix: String) -> Result<()> {
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    require!(suffix_account.is_paused, ErrorCode::SuffixNotPaused);
+    suffix_account.is_paused = false;
+    emit_pause(suffix_account)
+}
+
+fn emit_pause(suffix_account: &SuffixAccount) -> Result<()> {
+    emit!(SuffixPauseUpdated {
+        suffix: suffix_account.suffix.clone(),
+        is_paused: suffix_account.is_paused,
+    });
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct PostReputationBond<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"reputation_authority", suffix.as_bytes(), authority.key().as_ref()],
+        bump
+    )]
+    pub reputation_authority: Account<'info, ReputationAuthority>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Tops the authority's bond up to `REPUTATION_AUTHORITY_BOND`, after which
+/// its reputation updates apply.
+pub fn handler(ctx: Context<PostReputationBond>, _suffix: String) -> Result<()> {
+    let amount = REPUTATION_AUTHORITY_BOND.saturating_sub(ctx.accounts.reputation_authority.bond);
+    if amount > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.reputation_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let reputation_authority = &mut ctx.accounts.reputation_authority;
+    reputation_authority.bond += amount;
+
+    emit!(ReputationBondUpdated {
+        suffix: reputation_authority.suffix.clone(),
+        authority: reputation_authority.authority,
+        bond: reputation_authority.bond,
+        slashed: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct ProposeOwnerKeyRotation<'info> {
+    pub product_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized,
+        constraint = !suffix_account.is_frozen @ ErrorCode::SuffixFrozen
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+}
+
+/// Proposes `new_key`, such as a multisig, as the suffix's product owner. The
+/// suffix and its aliases stay as they are; only the key changes once
+/// `new_key` accepts. A later proposal replaces an earlier one.
+pub fn handler(ctx: Context<ProposeOwnerKeyRotation>, _suffix: String, new_key: Pubkey) -> Result<()> {
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    require!(new_key != suffix_account.owner, ErrorCode::InvalidOwner);
+    suffix_account.pending_owner = Some(new_key);
+
+    emit!(ProductOwnerKeyRotationProposed {
+        suffix: suffix_account.suffix.clone(),
+        owner: suffix_account.owner,
+        pending_owner: new_key,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct ProposeTransfer<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// Only required when the suffix enforces product owner co-signature and
+    /// the alias owner has not renounced product owner control.
+    #[account(
+        constraint = product_owner.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub product_owner: Option<Signer<'info>>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+}
+
+pub fn handler(ctx: Context<ProposeTransfer>, _username: String, _project_suffix: String, _chain_name: String, new_owner: Pubkey) -> Result<()> {
+    if ctx.accounts.suffix_account.transfer_requires_cosign && ctx.accounts.alias_account.owner_managed {
+        require!(ctx.accounts.product_owner.is_some(), ErrorCode::ProductOwnerSignatureRequired);
+    }
+
+    let alias_account = &mut ctx.accounts.alias_account;
+    require!(new_owner != alias_account.owner, ErrorCode::InvalidOwner);
+    let now = Clock::get()?.unix_timestamp;
+    alias_account.require_usable(now)?;
+    alias_account.require_transfer_unlocked(&ctx.accounts.suffix_account, now)?;
+
+    alias_account.pending_owner = Some(new_owner);
+
+    emit!(AliasTransferProposed {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        owner: alias_account.owner,
+        pending_owner: new_owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::merkle::{hash_chain_mapping, LeafCodec};
+use super::add_chain_mapping_with_proof::verify_mapping_ownership;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct ReattestChainMapping<'info> {
+    /// The alias owner, its controller, or a session key scoped for mappings.
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// Session key acting for the alias, when `authority` is not its controller.
+    #[account(
+        constraint = session.alias == alias_account.key() @ ErrorCode::Unauthorized
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    /// Required when the alias keeps a registry.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: Option<Account<'info, ChainMappingRegistry>>,
+    /// CHECK: Instructions sysvar, used to find the ownership proof for
+    /// Solana mappings.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Restamps the mapping in the slot `proof` points at as attested now.
+/// Solana mappings must be proven again by their key.
+pub fn handler(ctx: Context<ReattestChainMapping>, _username: String, _project_suffix: String, _chain_name: String, mapping: AttestedChainMapping, proof: ChainMappingProof) -> Result<()> {
+    LeafCodec::require_version(proof.leaf_version)?;
+    let now = Clock::get()?.unix_timestamp;
+    let alias = ctx.accounts.alias_account.key();
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_authority(&ctx.accounts.authority.key(), ctx.accounts.session.as_deref(), SESSION_SCOPE_ADD_MAPPING, now)?;
+    alias_account.require_usable(now)?;
+    ctx.accounts.chain_mapping_tree.require_registry(ctx.accounts.chain_mapping_registry.as_deref())?;
+    if mapping.mapping.chain_type == ChainType::Solana {
+        verify_mapping_ownership(&ctx.accounts.instructions, &alias, &mapping.mapping)?;
+    }
+
+    let renewed = AttestedChainMapping::new(mapping.mapping.clone(), now);
+    let hash = ctx.accounts.chain_mapping_tree.tree.hash;
+    let leaf = hash_chain_mapping(hash, &renewed);
+    let root = ctx.accounts.chain_mapping_tree.tree
+        .replace(&alias_account.chain_mappings_root, proof.index, &proof.siblings, hash_chain_mapping(hash, &mapping), leaf)
+        .ok_or(ErrorCode::InvalidChainMapping)?;
+    alias_account.set_chain_mappings_root(root);
+    ctx.accounts.chain_mapping_tree.root_history.push(root);
+    if let Some(registry) = ctx.accounts.chain_mapping_registry.as_deref_mut() {
+        registry.replace(proof.index, renewed.clone());
+    }
+
+    emit!(ChainMappingReattested {
+        alias,
+        index: proof.index,
+        leaf,
+        chain_id: renewed.mapping.chain_id,
+        attested_at: renewed.attested_at,
+        expires_at: renewed.expires_at,
+    });
+    emit!(RootUpdated {
+        alias,
+        chain_mappings_root: alias_account.chain_mappings_root,
+        chain_mapping_count: alias_account.chain_mapping_count,
+        chain_mappings_version: alias_account.chain_mappings_version,
+    });
+
+    Ok(())
+}
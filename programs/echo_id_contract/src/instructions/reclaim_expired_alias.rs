@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::instructions::set_primary_alias::clear_reverse_record;
+use crate::instructions::close_alias::{close_chain_mappings, close_if_initialized};
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct ReclaimExpiredAlias<'info> {
+    /// Suffix owner reclaiming the name. It receives the alias's rent, so it
+    /// is writable, but a PDA signing through CPI is enough.
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    /// Pays for `SuffixStats` if the suffix does not have one yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = product_owner,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The alias's `Listing`, closed with it if the alias lapsed while
+    /// listed. The seller abandoned it, so its rent goes with the alias's.
+    #[account(
+        mut,
+        seeds = [b"listing", alias_account.key().as_ref()],
+        bump
+    )]
+    pub listing: UncheckedAccount<'info>,
+    /// CHECK: Primary alias record of the current owner, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ChainMappingTree`, closed with it if it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ChainMappingRegistry`, closed with it if it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: UncheckedAccount<'info>,
+    /// CHECK: The alias's `AliasRecords`, closed with it if they exist so a
+    /// later alias at this address starts without them.
+    #[account(
+        mut,
+        seeds = [b"records", alias_account.key().as_ref()],
+        bump
+    )]
+    pub records: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_stats: Box<Account<'info, SuffixStats>>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReclaimExpiredAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        Some(&ctx.accounts.product_owner),
+    )?;
+    let alias_key = ctx.accounts.alias_account.key();
+    clear_reverse_record(&ctx.accounts.reverse_record, alias_key)?;
+    close_chain_mappings(&ctx.accounts.chain_mapping_tree, &ctx.accounts.chain_mapping_registry, &ctx.accounts.product_owner)?;
+    close_if_initialized(&ctx.accounts.records, &ctx.accounts.product_owner)?;
+    close_if_initialized(&ctx.accounts.listing, &ctx.accounts.product_owner)?;
+    ctx.accounts.suffix_stats.record_removal();
+
+    let alias_account = &ctx.accounts.alias_account;
+    require!(alias_account.is_reclaimable(Clock::get()?.unix_timestamp), ErrorCode::AliasNotReclaimable);
+
+    emit!(AliasClosed {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        owner: alias_account.owner,
+        closed_by: ctx.accounts.product_owner.key(),
+        recipient: ctx.accounts.product_owner.key(),
+    });
+
+    Ok(())
+}
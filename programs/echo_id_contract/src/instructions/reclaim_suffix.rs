@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::register_product_owner::post_product_owner_bond;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct ReclaimSuffix<'info> {
+    pub admin: Signer<'info>,
+    /// The product owner the namespace is reassigned to; pays for its
+    /// `ProductOwner` record if it has none yet, and tops up its bond.
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner != new_owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// CHECK: The lapsed owner's `ProductOwner` record, released from the
+    /// suffix if it exists.
+    #[account(
+        mut,
+        seeds = [b"product_owner", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = ProductOwner::SPACE,
+        seeds = [b"product_owner", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_product_owner_account: Account<'info, ProductOwner>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Counts one suffix fewer against the `ProductOwner` record at
+/// `product_owner_account`, if it was ever created.
+fn detach_lapsed_suffix(product_owner_account: &AccountInfo) -> Result<()> {
+    if product_owner_account.data_is_empty() || *product_owner_account.owner != crate::ID {
+        return Ok(());
+    }
+    let mut product_owner = ProductOwner::try_deserialize(&mut &product_owner_account.data.borrow()[..])?;
+    product_owner.detach_suffix();
+    product_owner.try_serialize(&mut &mut product_owner_account.try_borrow_mut_data()?[..])
+}
+
+/// Reassigns a suffix whose lease lapsed past its grace period to
+/// `new_owner` on a fresh lease, clearing the lapsed owner's freeze, pause
+/// and pending handover. Existing aliases under the suffix stay valid.
+pub fn handler(ctx: Context<ReclaimSuffix>, _suffix: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.suffix_account.is_reclaimable(now), ErrorCode::SuffixLeaseActive);
+
+    let new_owner = ctx.accounts.new_owner.key();
+    post_product_owner_bond(
+        &mut ctx.accounts.new_product_owner_account,
+        &ctx.accounts.new_owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin_config.product_owner_bond,
+    )?;
+    ctx.accounts.new_product_owner_account.attach_suffix(new_owner, now)?;
+    detach_lapsed_suffix(&ctx.accounts.product_owner_account)?;
+
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    let previous_owner = suffix_account.owner;
+    suffix_account.owner = new_owner;
+    suffix_account.pending_owner = None;
+    suffix_account.is_frozen = false;
+    suffix_account.is_paused = false;
+    suffix_account.expires_at = now + SUFFIX_LEASE_DURATION;
+
+    emit!(SuffixTransferred {
+        suffix: suffix_account.suffix.clone(),
+        previous_owner,
+        owner: new_owner,
+        by_admin: true,
+    });
+    emit!(SuffixLeaseUpdated {
+        suffix: suffix_account.suffix.clone(),
+        owner: new_owner,
+        expires_at: suffix_account.expires_at,
+        fee_paid: 0,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::instructions as sysvar_instructions};
+use anchor_lang::system_program;
 use crate::{
+    ed25519::verify_ed25519_signature,
+    fee_token::FeeTokenPayer,
     error::EchoIDError as ErrorCode,
+    instructions::commit_alias::commitment_hash,
+    instructions::reserve_name::reserved_name_hash,
+    merkle::verify_proof,
     state::*,
+    validation::validate_suffix_username,
+    zkp::{registration_public_inputs, verify_groth16, Groth16Proof},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -11,6 +20,21 @@ pub struct RegisterAliasParams {
     pub chain_info: ChainInfo,
     pub metadata: AliasMetadata,
     pub user_address: Pubkey,
+    /// Salt revealed against a prior `commit_alias`, when the suffix requires it.
+    pub salt: Option<[u8; 32]>,
+    /// Whether the product owner may later freeze, adjust reputation on, or
+    /// co-sign transfers of the alias.
+    pub owner_managed: bool,
+    /// Existing alias credited with referring this registration.
+    pub referrer: Option<Pubkey>,
+    /// Proof that `user_address` is in the suffix's allowlist, when the
+    /// suffix is in allowlist mode and the user has no allowlist entry.
+    pub allowlist_proof: Option<Vec<[u8; 32]>>,
+    /// Proof for the suffix's registration circuit, when it requires one.
+    pub registration_proof: Option<Groth16Proof>,
+    /// When the owner's consent or the product owner's approval, given as an
+    /// ed25519 signature, stops being accepted. Unused otherwise.
+    pub signature_expires_at: i64,
 }
 
 #[derive(Accounts)]
@@ -18,7 +42,8 @@ pub struct RegisterAliasParams {
 pub struct RegisterAlias<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    /// CHECK: This account is not written to or read from. It's just used for authorization.
+    /// CHECK: The alias owner. Must either sign the transaction or provide an
+    /// ed25519 signature over the registration message.
     #[account(
         constraint = user.key() == params.user_address @ ErrorCode::InvalidUser
     )]
@@ -26,44 +51,364 @@ pub struct RegisterAlias<'info> {
     #[account(
         seeds = [b"suffix", params.suffix.as_bytes()],
         bump,
-        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix
+        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
     )]
-    pub suffix_account: Account<'info, SuffixAccount>,
+    pub suffix_account: Box<Account<'info, SuffixAccount>>,
+    /// CHECK: The suffix owner's `ProductOwnerProfile`, read through
+    /// `ProductOwnerProfile::load_branding` since it may not exist.
+    #[account(
+        seeds = [b"product_owner_profile", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_profile: UncheckedAccount<'info>,
+    /// Co-signature approving the registration, when the suffix requires
+    /// approval; the only form of approval a PDA owner can give.
+    #[account(
+        constraint = product_owner.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub product_owner: Option<Signer<'info>>,
+    /// Record of `payer` as one of the suffix's registrars, when it
+    /// approves the registration in the product owner's place.
+    #[account(
+        mut,
+        seeds = [b"registrar", params.suffix.as_bytes(), payer.key().as_ref()],
+        bump
+    )]
+    pub registrar: Option<Box<Account<'info, Registrar>>>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+    /// CHECK: Receives the protocol's cut of the registration fee; must be
+    /// the configured admin.
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == admin_config.admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_treasury: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SuffixTreasury::SPACE,
+        seeds = [b"suffix_treasury", params.suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_treasury: Box<Account<'info, SuffixTreasury>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", params.suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_stats: Box<Account<'info, SuffixStats>>,
+    /// CHECK: The suffix's `Allowlist`, read when a registration in
+    /// allowlist mode brings a proof; may not exist.
+    #[account(
+        seeds = [b"allowlist", params.suffix.as_bytes()],
+        bump
+    )]
+    pub allowlist: UncheckedAccount<'info>,
+    /// CHECK: The user's `AllowlistEntry`; only inspected for existence.
+    #[account(
+        seeds = [b"allowlist_entry", params.suffix.as_bytes(), params.user_address.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+    /// The key named by the suffix's `registration_verifying_key`, when it
+    /// requires a registration proof.
+    pub registration_verifying_key: Option<Box<Account<'info, RegistrationVerifyingKey>>>,
+    /// CHECK: The suffix's `fee_mint`, when fees are charged in a token;
+    /// checked against the suffix config.
+    pub fee_mint: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token program owning `fee_mint`; checked against the mint.
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token account the payer pays token fees from; validated by the
+    /// token program.
+    #[account(mut)]
+    pub payer_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: The admin's token account for `fee_mint`; checked when paid.
+    #[account(mut)]
+    pub protocol_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: The product owner's token account for `fee_mint`; checked
+    /// when paid.
+    #[account(mut)]
+    pub owner_token_account: Option<UncheckedAccount<'info>>,
     #[account(
         init,
         payer = payer,
-        space = 8 + 32 + 4 + params.username.len() + 4 + params.suffix.len() + 8 + 4 + params.chain_info.name.len() + 4 + params.chain_info.address.len() + 8 + 8 + 4 + params.metadata.name.len() + 4 + params.metadata.image_url.len(),
+        space = AliasAccount::space(&params.username, &params.suffix, &params.chain_info, &params.metadata),
 
         seeds = [params.username.as_bytes(), b"@", params.suffix.as_bytes(),params.chain_info.name.as_bytes()],
         bump,
     )]
-    pub alias_account: Account<'info, AliasAccount>,
+    pub alias_account: Box<Account<'info, AliasAccount>>,
+    /// CHECK: Must be empty; an initialized account here means the name is reserved.
+    #[account(
+        seeds = [b"reserved", params.suffix.as_bytes(), reserved_name_hash(&params.username).as_ref()],
+        bump,
+        constraint = reserved_name.data_is_empty() @ ErrorCode::NameReserved
+    )]
+    pub reserved_name: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", params.suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", params.suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = payer,
+        constraint = commitment_account.registrant == payer.key() @ ErrorCode::CommitmentMismatch
+    )]
+    pub commitment_account: Option<Account<'info, CommitmentAccount>>,
+    /// The alias named by `params.referrer`, when there is one.
+    pub referrer_alias: Option<Box<Account<'info, AliasAccount>>>,
+    /// CHECK: Instructions sysvar, required when `user` or `product_owner`
+    /// consent is given as an ed25519 signature instead of a transaction signature.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
 }
 
+/// Domain of the alias owner's consent to a registration submitted by
+/// someone else.
+pub const REGISTRATION_CONSENT_DOMAIN: &[u8] = b"registration_consent";
+/// Domain of the product owner's approval of a registration the user
+/// submits and pays for.
+pub const REGISTRATION_APPROVAL_DOMAIN: &[u8] = b"registration_approval";
+
+/// Identifies the registration of `username@suffix` on `chain_name` for
+/// `owner`; signed messages and vouchers are built on it.
+pub fn registration_digest(username: &str, suffix: &str, chain_name: &str, owner: &Pubkey) -> [u8; 32] {
+    hashv(&[username.as_bytes(), b"@", suffix.as_bytes(), chain_name.as_bytes(), owner.as_ref()]).to_bytes()
+}
+
+/// The message signed under `domain` to authorize `params`. The domain keeps
+/// a consent from passing as an approval, and `signature_expires_at` bounds
+/// how long either can be replayed.
+pub fn registration_message(domain: &[u8], params: &RegisterAliasParams) -> [u8; 32] {
+    let registration = registration_digest(&params.username, &params.suffix, &params.chain_info.name, &params.user_address);
+    hashv(&[domain, &registration, &params.signature_expires_at.to_le_bytes()]).to_bytes()
+}
+
+/// Verifies the owner's ed25519 consent to `params` via instruction introspection.
+pub(crate) fn verify_owner_consent(instructions: &AccountInfo, params: &RegisterAliasParams, now: i64) -> Result<()> {
+    require!(now < params.signature_expires_at, ErrorCode::RegistrationSignatureExpired);
+    verify_ed25519_signature(instructions, &params.user_address, &registration_message(REGISTRATION_CONSENT_DOMAIN, params))
+}
+
+/// Admits `user` to a suffix in allowlist mode through either its
+/// `AllowlistEntry` or `proof` against the committed `Allowlist` root.
+fn require_allowlisted(allowlist: &AccountInfo, allowlist_entry: &AccountInfo, user: &Pubkey, proof: Option<&[[u8; 32]]>) -> Result<()> {
+    if *allowlist_entry.owner == crate::ID && !allowlist_entry.data_is_empty() {
+        return Ok(());
+    }
+    let proof = proof.ok_or(ErrorCode::NotAllowlisted)?;
+    require!(*allowlist.owner == crate::ID && !allowlist.data_is_empty(), ErrorCode::NotAllowlisted);
+    let allowlist = Allowlist::try_deserialize(&mut &allowlist.data.borrow()[..])?;
+    require!(verify_proof(proof, &allowlist.root, allowlist_leaf(user)), ErrorCode::NotAllowlisted);
+    Ok(())
+}
+
+/// Checks `proof` for the registration of `params` against the circuit the
+/// suffix config names.
+fn verify_registration_proof(config: &SuffixConfig, verifying_key: Option<&Account<RegistrationVerifyingKey>>, params: &RegisterAliasParams) -> Result<()> {
+    let verifying_key = verifying_key.ok_or(ErrorCode::RegistrationProofRequired)?;
+    require!(config.registration_verifying_key == Some(verifying_key.key()), ErrorCode::InvalidRegistrationProof);
+    let proof = params.registration_proof.as_ref().ok_or(ErrorCode::RegistrationProofRequired)?;
+    let inputs = registration_public_inputs(&params.user_address, &params.username, &params.suffix, &verifying_key.predicate);
+    require!(verify_groth16(&verifying_key.verifying_key, proof, &inputs, ErrorCode::InvalidRegistrationProof)?, ErrorCode::InvalidRegistrationProof);
+    Ok(())
+}
+
+/// Checks that `referrer_alias` is the live alias named by `referrer`.
+pub(crate) fn validate_referrer(referrer: Option<Pubkey>, referrer_alias: Option<&Account<AliasAccount>>, now: i64) -> Result<()> {
+    if let Some(referrer) = referrer {
+        let referrer_alias = referrer_alias.ok_or(ErrorCode::InvalidReferrer)?;
+        require_keys_eq!(referrer_alias.key(), referrer, ErrorCode::InvalidReferrer);
+        referrer_alias.require_usable(now)?;
+    }
+    Ok(())
+}
+
+/// Charges the registration fee for `username` to the payer, sending the
+/// admin-configured protocol cut to the protocol and the rest to the product
+/// owner. Lamport fees land in the suffix treasury, whose share it returns;
+/// token fees go straight to the owners' token accounts.
+fn collect_registration_fee(ctx: &Context<RegisterAlias>, username: &str, suffix: &str, config: &SuffixConfig) -> Result<u64> {
+    let fee = config.registration_fee_for(username);
+    if fee == 0 {
+        return Ok(0);
+    }
+    let accounts = &ctx.accounts;
+    let protocol_fee = (fee as u128 * accounts.admin_config.registration_fee_bps as u128 / 10_000) as u64;
+    let credited = match config.fee_mint {
+        Some(fee_mint) => {
+            let payer_info = accounts.payer.to_account_info();
+            let payer = FeeTokenPayer::new(
+                &fee_mint,
+                accounts.fee_mint.as_deref(),
+                accounts.token_program.as_deref(),
+                accounts.payer_token_account.as_deref(),
+                &payer_info,
+            )?;
+            payer.pay(accounts.protocol_token_account.as_deref(), &accounts.admin_config.admin, protocol_fee)?;
+            payer.pay(accounts.owner_token_account.as_deref(), &accounts.suffix_account.owner, fee - protocol_fee)?;
+            0
+        }
+        None => {
+            pay_lamports(ctx, protocol_fee, fee - protocol_fee)?;
+            fee - protocol_fee
+        }
+    };
+
+    emit!(RegistrationFeePaid {
+        suffix: suffix.to_string(),
+        alias: accounts.alias_account.key(),
+        username_length: username.len() as u8,
+        fee,
+        protocol_fee,
+    });
+    Ok(credited)
+}
+
+fn pay_lamports(ctx: &Context<RegisterAlias>, protocol_fee: u64, owner_share: u64) -> Result<()> {
+    for (to, amount) in [
+        (ctx.accounts.protocol_treasury.to_account_info(), protocol_fee),
+        (ctx.accounts.suffix_treasury.to_account_info(), owner_share),
+    ] {
+        if amount > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to,
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+    Ok(())
+}
+
 pub fn handler(ctx: Context<RegisterAlias>, params: RegisterAliasParams) -> Result<()> {
-    require!(!params.username.contains('@'), ErrorCode::InvalidUsername);
-    
-    let alias_account = &mut ctx.accounts.alias_account;
-    
-    alias_account.owner = params.user_address;
-    alias_account.username = params.username;
-    alias_account.product_suffix = params.suffix;
-
-    
-    alias_account.chain_info = params.chain_info;
-    alias_account.metadata = params.metadata;
-    
-    alias_account.reputation = 10; // Initial reputation
-    alias_account.reputation_updated_at = Clock::get()?.unix_timestamp;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.suffix_account.require_leased(now)?;
+
+    if !ctx.accounts.user.is_signer {
+        let instructions = ctx.accounts.instructions.as_ref().ok_or(ErrorCode::InvalidSignature)?;
+        verify_owner_consent(instructions, &params, now)?;
+    }
+
+    if let Some(registrar) = ctx.accounts.registrar.as_mut() {
+        registrar.consume(now)?;
+    } else if ctx.accounts.suffix_account.require_registration_approval && ctx.accounts.product_owner.is_none() {
+        let instructions = ctx.accounts.instructions.as_ref().ok_or(ErrorCode::RegistrationNotApproved)?;
+        require!(now < params.signature_expires_at, ErrorCode::RegistrationSignatureExpired);
+        let message = registration_message(REGISTRATION_APPROVAL_DOMAIN, &params);
+        verify_ed25519_signature(instructions, &ctx.accounts.suffix_account.owner, &message)
+            .map_err(|_| ErrorCode::RegistrationNotApproved)?;
+    }
+
+    if ctx.accounts.suffix_account.require_commitment {
+        let commitment_account = ctx.accounts.commitment_account.as_ref().ok_or(ErrorCode::CommitmentRequired)?;
+        let salt = params.salt.ok_or(ErrorCode::CommitmentRequired)?;
+        let expected = commitment_hash(&params.username, &params.suffix, &salt, &ctx.accounts.payer.key());
+        require!(commitment_account.commitment == expected, ErrorCode::CommitmentMismatch);
+
+        let age = now.saturating_sub(commitment_account.created_at);
+        require!(age >= COMMITMENT_MIN_AGE, ErrorCode::CommitmentTooNew);
+        require!(age <= COMMITMENT_MAX_AGE, ErrorCode::CommitmentExpired);
+    }
+
+    validate_referrer(params.referrer, ctx.accounts.referrer_alias.as_deref(), now)?;
+
+    let suffix_config = SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?;
+    if suffix_config.allowlist_mode && ctx.accounts.product_owner.is_none() {
+        require_allowlisted(&ctx.accounts.allowlist, &ctx.accounts.allowlist_entry, &params.user_address, params.allowlist_proof.as_deref())?;
+    }
+    if suffix_config.registration_proof_required {
+        verify_registration_proof(&suffix_config, ctx.accounts.registration_verifying_key.as_deref(), &params)?;
+    }
+    let credited = collect_registration_fee(&ctx, &params.username, &params.suffix, &suffix_config)?;
+    ctx.accounts.suffix_treasury.collected = ctx.accounts.suffix_treasury.collected.saturating_add(credited);
+
+    let initial_reputation = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?.initial_reputation;
+    let alias = build_alias_account(params, &suffix_config, initial_reputation, now)?;
+    emit_alias_registered(&alias, ProductOwnerProfile::load_branding(&ctx.accounts.product_owner_profile)?);
+    emit_referral_recorded(ctx.accounts.alias_account.key(), &alias);
+    ctx.accounts.alias_account.set_inner(alias);
+    ctx.accounts.suffix_stats.record_limited_registration(&suffix_config, now)?;
 
+    Ok(())
+}
+
+/// Validates the registration params against the suffix's policy and builds
+/// the initial alias state, starting from the suffix's `initial_reputation`.
+pub(crate) fn build_alias_account(params: RegisterAliasParams, suffix_config: &SuffixConfig, initial_reputation: i64, now: i64) -> Result<AliasAccount> {
+    validate_suffix_username(&params.username, suffix_config)?;
+
+    Ok(AliasAccount {
+        owner: params.user_address,
+        controller: params.user_address,
+        username: params.username,
+        product_suffix: params.suffix,
+        chain_info: params.chain_info,
+        reputation: initial_reputation,
+        reputation_updated_at: now,
+        metadata: params.metadata,
+        pending_owner: None,
+        expires_at: now + ALIAS_TERM_DURATION,
+        content_hash: [0; 34],
+        is_frozen: false,
+        nonce: 0,
+        owner_managed: params.owner_managed,
+        referrer: params.referrer,
+        created_at: now,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    })
+}
+
+pub(crate) fn emit_alias_registered(alias: &AliasAccount, operator: Option<ProductOwnerBranding>) {
     emit!(AliasRegistered {
-        username: alias_account.username.clone(),
-        suffix: alias_account.product_suffix.clone(),
-        chain_info: alias_account.chain_info.clone(),
-        metadata: alias_account.metadata.clone(),
-        user_address: alias_account.owner,
+        username: alias.username.clone(),
+        display_name: alias.display_name.clone(),
+        suffix: alias.product_suffix.clone(),
+        chain_info: alias.chain_info.clone(),
+        metadata: alias.metadata.clone(),
+        user_address: alias.owner,
+        operator,
     });
+}
 
-    Ok(())
-}
\ No newline at end of file
+pub(crate) fn emit_referral_recorded(alias_key: Pubkey, alias: &AliasAccount) {
+    if let Some(referrer) = alias.referrer {
+        emit!(ReferralRecorded { alias: alias_key, referrer });
+    }
+}
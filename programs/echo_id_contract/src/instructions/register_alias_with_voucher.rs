@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::instructions as sysvar_instructions};
+use crate::{
+    ed25519::verify_ed25519_signature,
+    error::EchoIDError as ErrorCode,
+    instructions::register_alias::{build_alias_account, emit_alias_registered, emit_referral_recorded, registration_digest, validate_referrer, verify_owner_consent, RegisterAliasParams},
+    instructions::reserve_name::reserved_name_hash,
+    state::*,
+};
+
+/// Off-chain authorization from the product owner for a single registration.
+/// Vouchers are ed25519 signatures, so PDA owners co-sign `register_alias`
+/// as its `product_owner` instead.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegistrationVoucher {
+    pub expires_at: i64,
+    pub nonce: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RegisterAliasParams, voucher: RegistrationVoucher)]
+pub struct RegisterAliasWithVoucher<'info> {
+    /// Any relayer; pays for the new accounts.
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    /// CHECK: The alias owner. Must either sign the transaction or provide an
+    /// ed25519 signature over the registration message.
+    #[account(
+        constraint = user.key() == params.user_address @ ErrorCode::InvalidUser
+    )]
+    pub user: AccountInfo<'info>,
+    #[account(
+        seeds = [b"suffix", params.suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub suffix_account: Box<Account<'info, SuffixAccount>>,
+    /// CHECK: The suffix owner's `ProductOwnerProfile`, read through
+    /// `ProductOwnerProfile::load_branding` since it may not exist.
+    #[account(
+        seeds = [b"product_owner_profile", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_profile: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = relayer,
+        space = AliasAccount::space(&params.username, &params.suffix, &params.chain_info, &params.metadata),
+        seeds = [params.username.as_bytes(), b"@", params.suffix.as_bytes(), params.chain_info.name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Box<Account<'info, AliasAccount>>,
+    /// CHECK: Must be empty; an initialized account here means the name is reserved.
+    #[account(
+        seeds = [b"reserved", params.suffix.as_bytes(), reserved_name_hash(&params.username).as_ref()],
+        bump,
+        constraint = reserved_name.data_is_empty() @ ErrorCode::NameReserved
+    )]
+    pub reserved_name: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", params.suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", params.suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = VoucherNonces::SPACE,
+        seeds = [b"voucher_nonces", params.suffix.as_bytes(), &(voucher.nonce / VOUCHER_NONCES_PER_PAGE).to_le_bytes()],
+        bump
+    )]
+    pub voucher_nonces: Box<Account<'info, VoucherNonces>>,
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", params.suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_stats: Box<Account<'info, SuffixStats>>,
+    /// The alias named by `params.referrer`, when there is one.
+    pub referrer_alias: Option<Box<Account<'info, AliasAccount>>>,
+    /// CHECK: Instructions sysvar, used to verify the voucher signature.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The message the product owner signs to issue a voucher.
+pub fn voucher_message(params: &RegisterAliasParams, voucher: &RegistrationVoucher) -> [u8; 32] {
+    let registration = registration_digest(&params.username, &params.suffix, &params.chain_info.name, &params.user_address);
+    hashv(&[b"voucher", &registration, &voucher.expires_at.to_le_bytes(), &voucher.nonce.to_le_bytes()]).to_bytes()
+}
+
+pub fn handler(ctx: Context<RegisterAliasWithVoucher>, params: RegisterAliasParams, voucher: RegistrationVoucher) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < voucher.expires_at, ErrorCode::VoucherExpired);
+    ctx.accounts.suffix_account.require_leased(now)?;
+
+    verify_ed25519_signature(&ctx.accounts.instructions, &ctx.accounts.suffix_account.owner, &voucher_message(&params, &voucher))?;
+    if !ctx.accounts.user.is_signer {
+        verify_owner_consent(&ctx.accounts.instructions, &params, now)?;
+    }
+
+    let voucher_nonces = &mut ctx.accounts.voucher_nonces;
+    voucher_nonces.page = voucher.nonce / VOUCHER_NONCES_PER_PAGE;
+    require!(voucher_nonces.consume(voucher.nonce), ErrorCode::VoucherAlreadyUsed);
+
+    validate_referrer(params.referrer, ctx.accounts.referrer_alias.as_deref(), now)?;
+
+    let initial_reputation = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?.initial_reputation;
+    let suffix_config = SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?;
+    let alias = build_alias_account(params, &suffix_config, initial_reputation, now)?;
+    emit_alias_registered(&alias, ProductOwnerProfile::load_branding(&ctx.accounts.product_owner_profile)?);
+    emit_referral_recorded(ctx.accounts.alias_account.key(), &alias);
+    ctx.accounts.alias_account.set_inner(alias);
+    ctx.accounts.suffix_stats.record_limited_registration(&suffix_config, now)?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program;
+use crate::{
+    error::EchoIDError as ErrorCode,
+    instructions::register_alias::{build_alias_account, emit_alias_registered, verify_owner_consent, RegisterAliasParams},
+    instructions::reserve_name::reserved_name_hash,
+    state::*,
+};
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct RegisterAliasesBatch<'info> {
+    /// Suffix owner vouching for the batch. It pays nothing.
+    pub product_owner: Signer<'info>,
+    /// Pays rent for every alias in the batch and for `SuffixStats`, usually
+    /// the product's backend key rather than the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// CHECK: The suffix owner's `ProductOwnerProfile`, read through
+    /// `ProductOwnerProfile::load_branding` since it may not exist.
+    #[account(
+        seeds = [b"product_owner_profile", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_profile: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar, used to verify each owner's ed25519 consent.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SuffixStats::SPACE,
+        seeds = [b"suffix_stats", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_stats: Box<Account<'info, SuffixStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers one alias per entry in `params`. Each entry's alias PDA and
+/// reserved-name PDA are passed as a pair of remaining accounts, in the same
+/// order as `params`, and every owner must have signed its registration
+/// message in an ed25519 instruction. Referrals are not supported in batches.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, RegisterAliasesBatch<'info>>, suffix: String, params: Vec<RegisterAliasParams>) -> Result<()> {
+    require!(!params.is_empty(), ErrorCode::InvalidInstruction);
+    require!(params.len() * 2 == ctx.remaining_accounts.len(), ErrorCode::InvalidInstruction);
+
+    let rent = Rent::get()?;
+    let suffix_config = SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?;
+    let initial_reputation = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?.initial_reputation;
+    let operator = ProductOwnerProfile::load_branding(&ctx.accounts.product_owner_profile)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.suffix_account.require_leased(now)?;
+
+    for (params, accounts) in params.into_iter().zip(ctx.remaining_accounts.chunks_exact(2)) {
+        let (alias_info, reserved_name) = (&accounts[0], &accounts[1]);
+        require!(params.suffix == suffix, ErrorCode::InvalidProjectSuffix);
+        require!(params.referrer.is_none(), ErrorCode::InvalidReferrer);
+        verify_owner_consent(&ctx.accounts.instructions, &params, now)?;
+
+        let (reserved, _) = Pubkey::find_program_address(
+            &[b"reserved", params.suffix.as_bytes(), reserved_name_hash(&params.username).as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(reserved_name.key(), reserved, ErrorCode::InvalidDerivedAccount);
+        require!(reserved_name.data_is_empty(), ErrorCode::NameReserved);
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[params.username.as_bytes(), b"@", params.suffix.as_bytes(), params.chain_info.name.as_bytes()],
+            ctx.program_id,
+        );
+        require_keys_eq!(alias_info.key(), expected, ErrorCode::InvalidDerivedAccount);
+        require!(alias_info.lamports() == 0 && alias_info.data_is_empty(), ErrorCode::AliasAlreadyExists);
+
+        let space = AliasAccount::space(&params.username, &params.suffix, &params.chain_info, &params.metadata);
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: alias_info.clone(),
+                },
+                &[&[params.username.as_bytes(), b"@", params.suffix.as_bytes(), params.chain_info.name.as_bytes(), &[bump]]],
+            ),
+            rent.minimum_balance(space),
+            space as u64,
+            ctx.program_id,
+        )?;
+
+        let alias = build_alias_account(params, &suffix_config, initial_reputation, now)?;
+        alias.try_serialize(&mut &mut alias_info.try_borrow_mut_data()?[..])?;
+        emit_alias_registered(&alias, operator.clone());
+        ctx.accounts.suffix_stats.record_limited_registration(&suffix_config, now)?;
+    }
+
+    Ok(())
+}
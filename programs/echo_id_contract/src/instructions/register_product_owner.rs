@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, error::EchoIDError as ErrorCode};
+use anchor_lang::system_program;
+use crate::{state::*, error::EchoIDError as ErrorCode, validation::validate_suffix};
 
 #[derive(Accounts)]
 #[instruction(suffix: String)]
@@ -15,21 +16,46 @@ pub struct RegisterProductOwner<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 1 + 4 + suffix.len(),
+        space = SuffixAccount::space(&suffix),
         seeds = [b"suffix", suffix.as_bytes()],
         bump
     )]
     pub suffix_account: Account<'info, SuffixAccount>,
-    /// CHECK: This is the new product owner being added
-    pub new_product_owner: AccountInfo<'info>,
+    /// The new product owner, which posts the protocol's bond.
+    #[account(mut)]
+    pub new_product_owner: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ProductOwner::SPACE,
+        seeds = [b"product_owner", new_product_owner.key().as_ref()],
+        bump
+    )]
+    pub product_owner_account: Account<'info, ProductOwner>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<RegisterProductOwner>, suffix: String) -> Result<()> {
+/// Registers `suffix`, already validated by the caller, with default policy
+/// for `owner`, counting it against the owner's suffix limit.
+pub(crate) fn init_suffix_account(suffix_account: &mut SuffixAccount, product_owner: &mut ProductOwner, owner: Pubkey, suffix: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    product_owner.attach_suffix(owner, now)?;
 
-    let suffix_account = &mut ctx.accounts.suffix_account;
-    suffix_account.owner = *ctx.accounts.new_product_owner.key;
+    suffix_account.owner = owner;
     suffix_account.is_active = true;
+    suffix_account.transfer_requires_cosign = false;
+    suffix_account.renewal_fee = 0;
+    suffix_account.require_commitment = false;
+    suffix_account.require_registration_approval = false;
+    suffix_account.transferable = false;
+    suffix_account.royalty_bps = 0;
+    suffix_account.transfer_lock_duration = 0;
+    suffix_account.is_frozen = false;
+    suffix_account.deactivated_at = 0;
+    suffix_account.pending_owner = None;
+    suffix_account.is_paused = false;
+    suffix_account.expires_at = now + SUFFIX_LEASE_DURATION;
+    suffix_account.parent = None;
     suffix_account.suffix = suffix;
 
     emit!(SuffixUpdated {
@@ -38,4 +64,48 @@ pub fn handler(ctx: Context<RegisterProductOwner>, suffix: String) -> Result<()>
     });
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Tops `product_owner`'s bond up to the admin's current
+/// `product_owner_bond`, paid by `payer`.
+pub(crate) fn post_product_owner_bond<'info>(
+    product_owner: &mut Account<'info, ProductOwner>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    required: u64,
+) -> Result<()> {
+    let amount = required.saturating_sub(product_owner.bond);
+    if amount == 0 {
+        return Ok(());
+    }
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: payer.clone(),
+                to: product_owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    product_owner.bond += amount;
+
+    emit!(ProductOwnerBondUpdated {
+        owner: payer.key(),
+        bond: product_owner.bond,
+        slashed: 0,
+    });
+    Ok(())
+}
+
+pub fn handler(ctx: Context<RegisterProductOwner>, suffix: String) -> Result<()> {
+    validate_suffix(&suffix)?;
+    let owner = ctx.accounts.new_product_owner.key();
+    post_product_owner_bond(
+        &mut ctx.accounts.product_owner_account,
+        &ctx.accounts.new_product_owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin_config.product_owner_bond,
+    )?;
+    init_suffix_account(&mut ctx.accounts.suffix_account, &mut ctx.accounts.product_owner_account, owner, suffix)
+}
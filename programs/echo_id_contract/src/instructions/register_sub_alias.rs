@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::validation::validate_username;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterSubAliasParams {
+    pub label: String,
+    pub owner: Pubkey,
+    pub reputation_share_bps: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, params: RegisterSubAliasParams)]
+pub struct RegisterSubAlias<'info> {
+    /// The parent alias owner.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = parent_alias.owner == authority.key() @ ErrorCode::InvalidOwner
+    )]
+    pub parent_alias: Account<'info, AliasAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = SubAliasAccount::space(&params.label),
+        seeds = [params.label.as_bytes(), parent_alias.key().as_ref()],
+        bump
+    )]
+    pub sub_alias: Account<'info, SubAliasAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterSubAlias>, _username: String, _project_suffix: String, _chain_name: String, params: RegisterSubAliasParams) -> Result<()> {
+    validate_username(&params.label)?;
+    require!(params.reputation_share_bps <= 10_000, ErrorCode::InvalidReputationShare);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.parent_alias.require_usable(now)?;
+
+    let sub_alias = &mut ctx.accounts.sub_alias;
+    sub_alias.parent = ctx.accounts.parent_alias.key();
+    sub_alias.label = params.label;
+    sub_alias.owner = params.owner;
+    sub_alias.reputation_share_bps = params.reputation_share_bps;
+    sub_alias.created_at = now;
+
+    emit!(SubAliasRegistered {
+        parent: sub_alias.parent,
+        label: sub_alias.label.clone(),
+        owner: sub_alias.owner,
+        reputation_share_bps: sub_alias.reputation_share_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, name_hash: [u8; 32])]
+pub struct ReleaseName<'info> {
+    /// Either the admin or the product owner of the suffix.
+    #[account(
+        mut,
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"reserved", suffix.as_bytes(), name_hash.as_ref()],
+        bump
+    )]
+    pub reserved_name: Account<'info, ReservedName>,
+}
+
+pub fn handler(ctx: Context<ReleaseName>, suffix: String, name_hash: [u8; 32]) -> Result<()> {
+    emit!(NameReservationUpdated {
+        suffix,
+        name_hash,
+        authority: ctx.accounts.authority.key(),
+        reserved: false,
+    });
+
+    Ok(())
+}
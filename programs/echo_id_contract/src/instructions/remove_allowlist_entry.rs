@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, user: Pubkey)]
+pub struct RemoveAllowlistEntry<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = product_owner,
+        seeds = [b"allowlist_entry", suffix.as_bytes(), user.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+}
+
+pub fn handler(_ctx: Context<RemoveAllowlistEntry>, suffix: String, user: Pubkey) -> Result<()> {
+    emit!(AllowlistEntryUpdated { suffix, user, removed: true });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::merkle::{hash_chain_mapping, LeafCodec, EMPTY_LEAF};
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct RemoveChainMapping<'info> {
+    /// The alias owner or its controller.
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    /// Required when the alias keeps a registry.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: Option<Account<'info, ChainMappingRegistry>>,
+}
+
+/// Empties the slot `proof` points at, given that it holds `mapping`. Slots
+/// are not reused.
+pub fn handler(ctx: Context<RemoveChainMapping>, _username: String, _project_suffix: String, _chain_name: String, mapping: AttestedChainMapping, proof: ChainMappingProof) -> Result<()> {
+    LeafCodec::require_version(proof.leaf_version)?;
+    let alias_account = &mut ctx.accounts.alias_account;
+    ctx.accounts.chain_mapping_tree.require_registry(ctx.accounts.chain_mapping_registry.as_deref())?;
+    let hash = ctx.accounts.chain_mapping_tree.tree.hash;
+    let leaf = hash_chain_mapping(hash, &mapping);
+    let root = ctx.accounts.chain_mapping_tree.tree
+        .replace(&alias_account.chain_mappings_root, proof.index, &proof.siblings, leaf, EMPTY_LEAF)
+        .ok_or(ErrorCode::InvalidChainMapping)?;
+    alias_account.set_chain_mappings_root(root);
+    ctx.accounts.chain_mapping_tree.root_history.push(root);
+    alias_account.chain_mapping_count = alias_account.chain_mapping_count.checked_sub(1).ok_or(ErrorCode::InvalidChainMapping)?;
+    ctx.accounts.chain_mapping_tree.release_chain_id(mapping.mapping.chain_id);
+    if let Some(registry) = ctx.accounts.chain_mapping_registry.as_deref_mut() {
+        registry.remove(proof.index);
+    }
+
+    emit!(ChainMappingRemoved {
+        alias: alias_account.key(),
+        index: proof.index,
+        leaf,
+        mapping,
+    });
+    emit!(RootUpdated {
+        alias: alias_account.key(),
+        chain_mappings_root: alias_account.chain_mappings_root,
+        chain_mapping_count: alias_account.chain_mapping_count,
+        chain_mappings_version: alias_account.chain_mappings_version,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct RemoveKey<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"keys", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_keys: Account<'info, AliasKeys>,
+}
+
+pub fn handler(ctx: Context<RemoveKey>, _username: String, _project_suffix: String, _chain_name: String, purpose: KeyPurpose, key: Pubkey) -> Result<()> {
+    let alias_keys = &mut ctx.accounts.alias_keys;
+    let index = alias_keys
+        .keys
+        .iter()
+        .position(|k| k.purpose == purpose && k.key == key)
+        .ok_or(ErrorCode::AliasKeyNotFound)?;
+    alias_keys.keys.remove(index);
+
+    emit!(AliasKeyUpdated {
+        alias: alias_keys.alias,
+        purpose,
+        key,
+        added: false,
+    });
+
+    Ok(())
+}
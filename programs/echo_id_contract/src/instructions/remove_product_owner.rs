@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct RemoveProductOwner<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = !suffix_account.is_active @ ErrorCode::ProductOwnerActive
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// The owner's record, when it has one, so the suffix stops counting
+    /// against it.
+    #[account(
+        mut,
+        seeds = [b"product_owner", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_account: Option<Account<'info, ProductOwner>>,
+}
+
+/// Closes a deactivated product owner's suffix account, returning its rent
+/// to the admin, who paid for it in `register_product_owner`. Aliases under
+/// the suffix are left as they are.
+pub fn handler(ctx: Context<RemoveProductOwner>, suffix: String) -> Result<()> {
+    if let Some(product_owner_account) = ctx.accounts.product_owner_account.as_mut() {
+        product_owner_account.detach_suffix();
+    }
+
+    emit!(ProductOwnerRemoved {
+        suffix,
+        owner: ctx.accounts.suffix_account.owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, registrar: Pubkey)]
+pub struct RemoveRegistrar<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = product_owner,
+        seeds = [b"registrar", suffix.as_bytes(), registrar.as_ref()],
+        bump
+    )]
+    pub registrar_account: Account<'info, Registrar>,
+}
+
+pub fn handler(ctx: Context<RemoveRegistrar>, suffix: String, registrar: Pubkey) -> Result<()> {
+    emit!(RegistrarUpdated {
+        suffix,
+        registrar,
+        quota: ctx.accounts.registrar_account.quota,
+        expires_at: ctx.accounts.registrar_account.expires_at,
+        removed: true,
+    });
+
+    Ok(())
+}
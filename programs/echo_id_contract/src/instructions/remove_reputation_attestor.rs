@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, attestor: Pubkey)]
+pub struct RemoveReputationAttestor<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = product_owner,
+        seeds = [b"reputation_attestor", suffix.as_bytes(), attestor.as_ref()],
+        bump
+    )]
+    pub reputation_attestor: Account<'info, ReputationAttestor>,
+}
+
+/// Stops `attestor` granting new boosts; boosts it already granted run until
+/// they expire.
+pub fn handler(_ctx: Context<RemoveReputationAttestor>, suffix: String, attestor: Pubkey) -> Result<()> {
+    emit!(ReputationAttestorUpdated {
+        suffix,
+        attestor,
+        max_boost: 0,
+        removed: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, authority: Pubkey)]
+pub struct RemoveReputationAuthority<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = product_owner,
+        seeds = [b"reputation_authority", suffix.as_bytes(), authority.as_ref()],
+        bump
+    )]
+    pub reputation_authority: Account<'info, ReputationAuthority>,
+    /// CHECK: Receives the authority's bond back.
+    #[account(mut, address = authority @ ErrorCode::Unauthorized)]
+    pub authority_wallet: UncheckedAccount<'info>,
+}
+
+/// Removes the authority, returning what is left of its bond to it and the
+/// account's rent to the product owner.
+pub fn handler(ctx: Context<RemoveReputationAuthority>, suffix: String, authority: Pubkey) -> Result<()> {
+    let bond = ctx.accounts.reputation_authority.bond;
+    **ctx.accounts.reputation_authority.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **ctx.accounts.authority_wallet.to_account_info().try_borrow_mut_lamports()? += bond;
+
+    emit!(ReputationAuthorityUpdated {
+        suffix,
+        authority,
+        weight_bps: ctx.accounts.reputation_authority.weight_bps,
+        removed: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, slasher: Pubkey)]
+pub struct RemoveReputationSlasher<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        close = product_owner,
+        seeds = [b"reputation_slasher", suffix.as_bytes(), slasher.as_ref()],
+        bump
+    )]
+    pub reputation_slasher: Account<'info, ReputationSlasher>,
+}
+
+pub fn handler(_ctx: Context<RemoveReputationSlasher>, suffix: String, slasher: Pubkey) -> Result<()> {
+    emit!(ReputationSlasherUpdated {
+        suffix,
+        slasher,
+        removed: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_primary_alias::repoint_reverse_record;
+use crate::instructions::reserve_name::reserved_name_hash;
+use crate::instructions::close_alias::write_tombstone;
+use crate::validation::validate_suffix_username;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, new_username: String)]
+pub struct RenameAlias<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = AliasAccount::space(&new_username, &project_suffix, &alias_account.chain_info, &alias_account.metadata),
+        seeds = [new_username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub new_alias_account: Account<'info, AliasAccount>,
+    /// CHECK: Must be empty; an initialized account here means the name is reserved.
+    #[account(
+        seeds = [b"reserved", project_suffix.as_bytes(), reserved_name_hash(&new_username).as_ref()],
+        bump,
+        constraint = reserved_name.data_is_empty() @ ErrorCode::NameReserved
+    )]
+    pub reserved_name: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: Primary alias record of the owner, moved to the new PDA if it points at the old one.
+    #[account(
+        mut,
+        seeds = [b"reverse", owner.key().as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// Redirect from the old address to the renamed alias.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Tombstone::SPACE,
+        seeds = [b"tombstone", alias_account.key().as_ref()],
+        bump
+    )]
+    pub tombstone: Option<Account<'info, Tombstone>>,
+    /// CHECK: The old alias's `ChainMappingTree`, moved to the new alias if
+    /// it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: UncheckedAccount<'info>,
+    /// CHECK: The `ChainMappingTree` address of the new alias; must be free.
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", new_alias_account.key().as_ref()],
+        bump
+    )]
+    pub new_chain_mapping_tree: UncheckedAccount<'info>,
+    /// CHECK: The old alias's `ChainMappingRegistry`, moved to the new alias
+    /// if it exists.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: UncheckedAccount<'info>,
+    /// CHECK: The `ChainMappingRegistry` address of the new alias; must be free.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", new_alias_account.key().as_ref()],
+        bump
+    )]
+    pub new_chain_mapping_registry: UncheckedAccount<'info>,
+    /// CHECK: The old alias's `AliasRecords`, moved to the new alias if they
+    /// exist.
+    #[account(
+        mut,
+        seeds = [b"records", alias_account.key().as_ref()],
+        bump
+    )]
+    pub records: UncheckedAccount<'info>,
+    /// CHECK: The `AliasRecords` address of the new alias; must be free.
+    #[account(
+        mut,
+        seeds = [b"records", new_alias_account.key().as_ref()],
+        bump
+    )]
+    pub new_records: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves `from`, a PDA of this program seeded by `[prefix, old alias]`, to
+/// `to`, its address under `new_alias`, if it exists. Both the
+/// `ChainMappingTree` and the `ChainMappingRegistry` lead with the alias
+/// they belong to, which is rewritten.
+pub(crate) fn move_alias_pda<'info>(from: &AccountInfo<'info>, to: &AccountInfo<'info>, prefix: &[u8], new_alias: Pubkey, bump: u8, system_program: &AccountInfo<'info>) -> Result<()> {
+    if *from.owner != crate::ID {
+        return Ok(());
+    }
+    require!(to.data_is_empty(), ErrorCode::InvalidDerivedAccount);
+
+    let data = from.try_borrow_data()?.to_vec();
+    let lamports = from.lamports();
+    from.sub_lamports(lamports)?;
+    to.add_lamports(lamports)?;
+    from.assign(&system_program::ID);
+    from.realloc(0, false)?;
+
+    let seeds: &[&[u8]] = &[prefix, new_alias.as_ref(), &[bump]];
+    system_program::allocate(
+        CpiContext::new_with_signer(system_program.clone(), system_program::Allocate { account_to_allocate: to.clone() }, &[seeds]),
+        data.len() as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(system_program.clone(), system_program::Assign { account_to_assign: to.clone() }, &[seeds]),
+        &crate::ID,
+    )?;
+
+    let mut moved = to.try_borrow_mut_data()?;
+    moved.copy_from_slice(&data);
+    moved[8..40].copy_from_slice(new_alias.as_ref());
+    Ok(())
+}
+
+/// Carries an alias's chain mappings tree and registry over to the PDA it
+/// was renamed or migrated to, so the copied `chain_mappings_root` keeps
+/// the tree it commits to.
+pub(crate) fn move_chain_mappings<'info>(
+    tree: (&AccountInfo<'info>, &AccountInfo<'info>, u8),
+    registry: (&AccountInfo<'info>, &AccountInfo<'info>, u8),
+    new_alias: Pubkey,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    move_alias_pda(tree.0, tree.1, b"mapping_tree", new_alias, tree.2, system_program)?;
+    move_alias_pda(registry.0, registry.1, b"mapping_registry", new_alias, registry.2, system_program)
+}
+
+pub fn handler(ctx: Context<RenameAlias>, username: String, project_suffix: String, chain_name: String, new_username: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        Some(&ctx.accounts.owner),
+    )?;
+    validate_suffix_username(&new_username, &SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?)?;
+
+    let old_key = ctx.accounts.alias_account.key();
+    let new_key = ctx.accounts.new_alias_account.key();
+    repoint_reverse_record(&ctx.accounts.reverse_record, old_key, Some(new_key))?;
+    write_tombstone(&mut ctx.accounts.tombstone, old_key, Some(new_key))?;
+    move_chain_mappings(
+        (&ctx.accounts.chain_mapping_tree, &ctx.accounts.new_chain_mapping_tree, ctx.bumps.new_chain_mapping_tree),
+        (&ctx.accounts.chain_mapping_registry, &ctx.accounts.new_chain_mapping_registry, ctx.bumps.new_chain_mapping_registry),
+        new_key,
+        &ctx.accounts.system_program,
+    )?;
+    move_alias_pda(&ctx.accounts.records, &ctx.accounts.new_records, b"records", new_key, ctx.bumps.new_records, &ctx.accounts.system_program)?;
+
+    let old_alias = &ctx.accounts.alias_account;
+    old_alias.require_usable(Clock::get()?.unix_timestamp)?;
+
+    ctx.accounts.new_alias_account.set_inner(AliasAccount {
+        username: new_username,
+        // The old display name no longer normalizes to the new username.
+        display_name: String::new(),
+        pending_owner: None,
+        ..(**old_alias).clone()
+    });
+    let new_alias = &ctx.accounts.new_alias_account;
+
+    emit!(AliasRenamed {
+        old_username: old_alias.username.clone(),
+        new_username: new_alias.username.clone(),
+        suffix: new_alias.product_suffix.clone(),
+        chain_info: new_alias.chain_info.clone(),
+        owner: new_alias.owner,
+    });
+
+    Ok(())
+}
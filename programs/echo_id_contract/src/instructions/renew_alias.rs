@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::fee_token::FeeTokenPayer;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct RenewAlias<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.is_active @ ErrorCode::InvalidProjectSuffix
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// CHECK: Receives the renewal fee; must be the suffix owner.
+    #[account(
+        mut,
+        constraint = product_owner.key() == suffix_account.owner @ ErrorCode::InvalidOwner
+    )]
+    pub product_owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `SuffixConfig`, read through
+    /// `SuffixConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"suffix_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `fee_mint`, when fees are charged in a token;
+    /// checked against the suffix config.
+    pub fee_mint: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token program owning `fee_mint`; checked against the mint.
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token account the payer pays token fees from; validated by the
+    /// token program.
+    #[account(mut)]
+    pub payer_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: The product owner's token account for `fee_mint`; checked
+    /// when paid.
+    #[account(mut)]
+    pub owner_token_account: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RenewAlias>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(!ctx.accounts.alias_account.is_reclaimable(now), ErrorCode::AliasExpired);
+
+    let fee = ctx.accounts.suffix_account.renewal_fee;
+    let config = SuffixConfig::load_or_default(&ctx.accounts.suffix_config)?;
+    if fee > 0 {
+        match config.fee_mint {
+            Some(fee_mint) => {
+                let accounts = &ctx.accounts;
+                let payer_info = accounts.payer.to_account_info();
+                FeeTokenPayer::new(
+                    &fee_mint,
+                    accounts.fee_mint.as_deref(),
+                    accounts.token_program.as_deref(),
+                    accounts.payer_token_account.as_deref(),
+                    &payer_info,
+                )?
+                .pay(accounts.owner_token_account.as_deref(), &accounts.suffix_account.owner, fee)?;
+            }
+            None => system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.product_owner.to_account_info(),
+                    },
+                ),
+                fee,
+            )?,
+        }
+    }
+
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.expires_at = alias_account.expires_at.saturating_add(ALIAS_TERM_DURATION);
+
+    emit!(AliasRenewed {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        expires_at: alias_account.expires_at,
+        fee_paid: fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct RenewSuffix<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    /// CHECK: Receives the renewal fee; must be the configured admin.
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == admin_config.admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_treasury: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Extends the suffix lease by `SUFFIX_LEASE_DURATION` for the protocol's
+/// renewal fee. Renewing inside the grace period extends from the lapsed
+/// expiry, so the lease never gains the time it spent lapsed.
+pub fn handler(ctx: Context<RenewSuffix>, _suffix: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(!ctx.accounts.suffix_account.is_reclaimable(now), ErrorCode::SuffixLeaseLapsed);
+
+    let fee = ctx.accounts.admin_config.suffix_renewal_fee;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.product_owner.to_account_info(),
+                    to: ctx.accounts.protocol_treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    suffix_account.expires_at = suffix_account.expires_at.saturating_add(SUFFIX_LEASE_DURATION);
+
+    emit!(SuffixLeaseUpdated {
+        suffix: suffix_account.suffix.clone(),
+        owner: suffix_account.owner,
+        expires_at: suffix_account.expires_at,
+        fee_paid: fee,
+    });
+
+    Ok(())
+}
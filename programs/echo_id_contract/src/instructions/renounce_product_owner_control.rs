@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct RenounceProductOwnerControl<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+}
+
+/// Permanently removes the product owner's administrative powers over the alias.
+pub fn handler(ctx: Context<RenounceProductOwnerControl>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+    alias_account.owner_managed = false;
+
+    emit!(ProductOwnerControlRenounced {
+        alias: alias_account.key(),
+        owner: alias_account.owner,
+    });
+
+    Ok(())
+}
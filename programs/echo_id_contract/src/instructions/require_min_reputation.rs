@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct RequireMinReputation<'info> {
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+}
+
+/// Fails unless the alias is usable and its decayed general reputation is at
+/// least `min`, so other programs can gate their own instructions on it over
+/// CPI.
+pub fn handler(ctx: Context<RequireMinReputation>, _username: String, _project_suffix: String, _chain_name: String, min: i64) -> Result<()> {
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    ctx.accounts.alias_account.require_min_reputation(&config, min, Clock::get()?.unix_timestamp)
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, name_hash: [u8; 32])]
+pub struct ReserveName<'info> {
+    /// Either the admin or the product owner of the suffix. It only signs.
+    #[account(
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    /// Pays rent for the `ReservedName`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 4 + suffix.len() + 32 + 32,
+        seeds = [b"reserved", suffix.as_bytes(), name_hash.as_ref()],
+        bump
+    )]
+    pub reserved_name: Account<'info, ReservedName>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Reservations are keyed by the hash of the canonical username.
+pub fn reserved_name_hash(username: &str) -> [u8; 32] {
+    hash(username.as_bytes()).to_bytes()
+}
+
+pub fn handler(ctx: Context<ReserveName>, suffix: String, name_hash: [u8; 32]) -> Result<()> {
+    let reserved_name = &mut ctx.accounts.reserved_name;
+    reserved_name.suffix = suffix;
+    reserved_name.name_hash = name_hash;
+    reserved_name.reserved_by = ctx.accounts.authority.key();
+
+    emit!(NameReservationUpdated {
+        suffix: reserved_name.suffix.clone(),
+        name_hash,
+        authority: reserved_name.reserved_by,
+        reserved: true,
+    });
+
+    Ok(())
+}
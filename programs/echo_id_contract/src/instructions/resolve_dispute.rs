@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::update_reputation::emit_reputation_changed;
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// The admin or the suffix's dispute arbiter.
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(mut, address = dispute.alias @ ErrorCode::InvalidDispute)]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationCategories`, read through
+    /// `ReputationCategories::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_categories", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_categories: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"reputation_history", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_history: Box<Account<'info, ReputationHistory>>,
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    /// The authority behind a challenged entry, whose bond is slashed if the
+    /// challenge succeeds. Required when reverting a challenge.
+    #[account(
+        mut,
+        constraint = reputation_authority.authority == dispute.entry.authority @ ErrorCode::InvalidDispute,
+        constraint = reputation_authority.suffix == alias_account.product_suffix @ ErrorCode::InvalidDispute
+    )]
+    pub reputation_authority: Option<Account<'info, ReputationAuthority>>,
+    /// CHECK: Receives the bond back if the change is reverted.
+    #[account(mut, address = dispute.opener @ ErrorCode::InvalidDispute)]
+    pub opener: UncheckedAccount<'info>,
+}
+
+/// Settles an open dispute. Reverting undoes the disputed change, clamped to
+/// the suffix's band, and refunds the bond to the opener, who for a
+/// challenge also receives part of the authority's bond; upholding it pays
+/// the bond to the arbiter.
+pub fn handler(ctx: Context<ResolveDispute>, revert: bool) -> Result<()> {
+    let accounts = ctx.accounts;
+    require!(accounts.dispute.status == DisputeStatus::Open, ErrorCode::InvalidDispute);
+    let config = ReputationConfig::load_or_default(&accounts.reputation_config)?;
+    let arbiter = accounts.arbiter.key();
+    require!(arbiter == accounts.admin_config.admin || (config.dispute_arbiter != Pubkey::default() && arbiter == config.dispute_arbiter), ErrorCode::Unauthorized);
+
+    let dispute = &mut accounts.dispute;
+    let recipient = if revert {
+        let now = Clock::get()?.unix_timestamp;
+        let categories = ReputationCategories::load_or_default(&accounts.reputation_categories)?;
+        let alias_account = &mut accounts.alias_account;
+        let category = dispute.entry.category;
+        let half_life = categories.half_life(category, &config)?;
+        let (stored, updated_at) = alias_account.category_reputation(category)?;
+        let reputation = decay_reputation(stored, updated_at, now, half_life);
+        let updated = reputation.saturating_sub(dispute.entry.delta).clamp(config.min_reputation, config.max_reputation);
+        alias_account.set_category_reputation(category, updated, now);
+        emit_reputation_changed(alias_account.key(), category, stored, reputation, updated, arbiter, DISPUTE_REVERT_REASON);
+
+        accounts.reputation_history.push(ReputationEntry {
+            timestamp: now,
+            delta: updated - reputation,
+            authority: arbiter,
+            reason: DISPUTE_REVERT_REASON,
+            category,
+        });
+        dispute.status = DisputeStatus::Reverted;
+
+        if dispute.challenge {
+            let reputation_authority = accounts.reputation_authority.as_mut().ok_or(ErrorCode::InvalidDispute)?;
+            let slashed = (reputation_authority.bond as u128 * REPUTATION_BOND_SLASH_BPS as u128 / 10_000) as u64;
+            reputation_authority.bond -= slashed;
+            **reputation_authority.to_account_info().try_borrow_mut_lamports()? -= slashed;
+            **accounts.opener.to_account_info().try_borrow_mut_lamports()? += slashed;
+            emit!(ReputationBondUpdated {
+                suffix: reputation_authority.suffix.clone(),
+                authority: reputation_authority.authority,
+                bond: reputation_authority.bond,
+                slashed,
+            });
+        }
+        accounts.opener.to_account_info()
+    } else {
+        dispute.status = DisputeStatus::Upheld;
+        accounts.arbiter.to_account_info()
+    };
+
+    **dispute.to_account_info().try_borrow_mut_lamports()? -= dispute.bond;
+    **recipient.try_borrow_mut_lamports()? += dispute.bond;
+    dispute.bond = 0;
+
+    emit!(DisputeResolved {
+        alias: dispute.alias,
+        arbiter,
+        history_index: dispute.history_index,
+        reverted: revert,
+    });
+
+    Ok(())
+}
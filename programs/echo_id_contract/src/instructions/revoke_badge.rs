@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::claim_badge::effective_reputation;
+
+#[derive(Accounts)]
+pub struct RevokeBadge<'info> {
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", alias_account.product_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The alias's `ReputationVault`, read through
+    /// `ReputationVault::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_vault", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"badge", alias_account.key().as_ref()],
+        bump
+    )]
+    pub badge: Account<'info, Badge>,
+}
+
+/// Lowers the alias's badge to the tier its effective reputation now
+/// reaches, once it has fallen below the hysteresis bound of the tier it
+/// holds. Anyone may call this.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, RevokeBadge<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    let reputation = effective_reputation(&ctx.accounts.alias_account, &config, &ctx.accounts.reputation_vault, ctx.remaining_accounts, now)?;
+
+    let badge = &mut ctx.accounts.badge;
+    require!(!config.keeps_tier(badge.tier, reputation), ErrorCode::BadgeUnchanged);
+
+    let previous_tier = badge.tier;
+    badge.tier = config.tier_for(reputation);
+    badge.updated_at = now;
+
+    emit!(BadgeUpdated {
+        alias: badge.alias,
+        tier: badge.tier,
+        previous_tier,
+    });
+
+    Ok(())
+}
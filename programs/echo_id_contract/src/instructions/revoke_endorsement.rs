@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct RevokeEndorsement<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        constraint = endorser.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub endorser: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"endorsement_quota", endorser.key().as_ref()],
+        bump
+    )]
+    pub endorsement_quota: Account<'info, EndorsementQuota>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"endorsement", endorser.key().as_ref(), endorsement.endorsee.as_ref()],
+        bump
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+}
+
+/// Withdraws an endorsement, expired or not, freeing its quota slot.
+pub fn handler(ctx: Context<RevokeEndorsement>) -> Result<()> {
+    let quota = &mut ctx.accounts.endorsement_quota;
+    quota.given = quota.given.saturating_sub(1);
+
+    let endorsement = &ctx.accounts.endorsement;
+    emit!(EndorsementUpdated {
+        endorser: endorsement.endorser,
+        endorsee: endorsement.endorsee,
+        weight: endorsement.weight,
+        expires_at: endorsement.expires_at,
+        revoked: true,
+    });
+
+    Ok(())
+}
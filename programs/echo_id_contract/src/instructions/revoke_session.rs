@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, session_key: Pubkey)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"session", alias_account.key().as_ref(), session_key.as_ref()],
+        bump
+    )]
+    pub session: Account<'info, SessionKey>,
+}
+
+pub fn handler(ctx: Context<RevokeSession>, _username: String, _project_suffix: String, _chain_name: String, session_key: Pubkey) -> Result<()> {
+    emit!(SessionUpdated {
+        alias: ctx.accounts.alias_account.key(),
+        key: session_key,
+        scope: ctx.accounts.session.scope,
+        expires_at: ctx.accounts.session.expires_at,
+        revoked: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_primary_alias::clear_reverse_record;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct RotateOwnerKey<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub new_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = KeyHistory::SPACE,
+        seeds = [b"key_history", alias_account.key().as_ref()],
+        bump
+    )]
+    pub key_history: Account<'info, KeyHistory>,
+    /// CHECK: Primary alias record of the current owner, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rotates the owner key of an alias. Unlike a transfer, both the retiring
+/// and the new key must sign, and the retired key is kept in `KeyHistory`.
+pub fn handler(ctx: Context<RotateOwnerKey>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        None,
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+    let alias_key = ctx.accounts.alias_account.key();
+    let old_key = ctx.accounts.owner.key();
+    let new_key = ctx.accounts.new_owner.key();
+    require!(new_key != old_key, ErrorCode::InvalidOwner);
+
+    ctx.accounts.alias_account.require_usable(now)?;
+    clear_reverse_record(&ctx.accounts.reverse_record, alias_key)?;
+
+    let key_history = &mut ctx.accounts.key_history;
+    key_history.alias = alias_key;
+    key_history.push(RetiredKey { key: old_key, retired_at: now });
+
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.owner = new_key;
+    if alias_account.controller == old_key {
+        alias_account.controller = new_key;
+    }
+    alias_account.pending_owner = None;
+
+    emit!(OwnerKeyRotated {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        old_key,
+        new_key,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::merkle::MerkleHash;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct SetChainMappingHash<'info> {
+    /// The alias owner or its controller.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ChainMappingTree::SPACE,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Chooses the hash function of the alias's chain mapping tree. Every leaf
+/// and root depends on it, so it is fixed once the first mapping is added.
+pub fn handler(ctx: Context<SetChainMappingHash>, _username: String, _project_suffix: String, _chain_name: String, hash: MerkleHash) -> Result<()> {
+    let tree = &mut ctx.accounts.chain_mapping_tree;
+    require!(tree.tree.next_index == 0, ErrorCode::ChainMappingHashLocked);
+    tree.alias = ctx.accounts.alias_account.key();
+    tree.tree.hash = hash;
+    Ok(())
+}
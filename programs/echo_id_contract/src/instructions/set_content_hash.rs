@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct SetContentHash<'info> {
+    /// The alias owner or its controller.
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+}
+
+pub fn handler(ctx: Context<SetContentHash>, _username: String, _project_suffix: String, _chain_name: String, content_hash: [u8; 34]) -> Result<()> {
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+
+    alias_account.content_hash = content_hash;
+
+    emit!(ContentHashUpdated {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        content_hash,
+    });
+
+    Ok(())
+}
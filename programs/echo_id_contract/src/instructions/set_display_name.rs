@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::validation::validate_display_name;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, display_name: String)]
+pub struct SetDisplayName<'info> {
+    /// The alias owner or its controller.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized,
+        realloc = AliasAccount::space(&alias_account.username, &alias_account.product_suffix, &alias_account.chain_info, &alias_account.metadata) + display_name.len(),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets how the alias is displayed; an empty `display_name` clears it.
+pub fn handler(ctx: Context<SetDisplayName>, _username: String, _project_suffix: String, _chain_name: String, display_name: String) -> Result<()> {
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+    if !display_name.is_empty() {
+        validate_display_name(&display_name, &alias_account.username)?;
+    }
+
+    alias_account.display_name = display_name;
+
+    emit!(DisplayNameUpdated {
+        alias: alias_account.key(),
+        username: alias_account.username.clone(),
+        display_name: alias_account.display_name.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct SetGuardians<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = GuardianSet::SPACE,
+        seeds = [b"guardians", alias_account.key().as_ref()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the guardian set. Any in-flight recovery is discarded.
+pub fn handler(ctx: Context<SetGuardians>, _username: String, _project_suffix: String, _chain_name: String, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    require!(!guardians.is_empty() && guardians.len() <= MAX_GUARDIANS, ErrorCode::InvalidGuardianSet);
+    require!(threshold > 0 && threshold as usize <= guardians.len(), ErrorCode::InvalidGuardianSet);
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(!guardians[..i].contains(guardian), ErrorCode::InvalidGuardianSet);
+    }
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.alias = ctx.accounts.alias_account.key();
+    guardian_set.guardians = guardians;
+    guardian_set.threshold = threshold;
+    guardian_set.recovery = None;
+    guardian_set.owner = ctx.accounts.owner.key();
+
+    emit!(GuardiansUpdated {
+        alias: guardian_set.alias,
+        guardians: guardian_set.guardians.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct SetPrimaryAlias<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + 32 + 1 + 32,
+        seeds = [b"reverse", owner.key().as_ref()],
+        bump
+    )]
+    pub reverse_record: Account<'info, ReverseRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Points the `ReverseRecord` at `reverse_record`, which callers constrain
+/// to the owner's reverse address, at `successor` if it is initialized and
+/// still points at `alias`.
+pub(crate) fn repoint_reverse_record(reverse_record: &AccountInfo, alias: Pubkey, successor: Option<Pubkey>) -> Result<()> {
+    if reverse_record.data_is_empty() || *reverse_record.owner != crate::ID {
+        return Ok(());
+    }
+    let mut record = ReverseRecord::try_deserialize(&mut &reverse_record.data.borrow()[..])?;
+    if record.alias != Some(alias) {
+        return Ok(());
+    }
+    record.alias = successor;
+    record.try_serialize(&mut &mut reverse_record.try_borrow_mut_data()?[..])?;
+
+    emit!(PrimaryAliasUpdated {
+        owner: record.owner,
+        alias: successor,
+    });
+    Ok(())
+}
+
+/// Clears the `ReverseRecord` at `reverse_record` if it points at `alias`.
+pub(crate) fn clear_reverse_record(reverse_record: &AccountInfo, alias: Pubkey) -> Result<()> {
+    repoint_reverse_record(reverse_record, alias, None)
+}
+
+pub fn handler(ctx: Context<SetPrimaryAlias>, _username: String, _project_suffix: String, _chain_name: String) -> Result<()> {
+    ctx.accounts.alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+
+    let reverse_record = &mut ctx.accounts.reverse_record;
+    reverse_record.owner = ctx.accounts.owner.key();
+    reverse_record.alias = Some(ctx.accounts.alias_account.key());
+
+    emit!(PrimaryAliasUpdated {
+        owner: reverse_record.owner,
+        alias: reverse_record.alias,
+    });
+
+    Ok(())
+}
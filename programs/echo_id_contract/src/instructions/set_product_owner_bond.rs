@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+pub struct SetProductOwnerBond<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Sets the bond product owners post from their next registration or
+/// takeover on; existing bonds are not topped up until then.
+pub fn handler(ctx: Context<SetProductOwnerBond>, amount: u64) -> Result<()> {
+    ctx.accounts.admin_config.product_owner_bond = amount;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::validation::validate_product_owner_branding;
+
+#[derive(Accounts)]
+pub struct SetProductOwnerProfile<'info> {
+    /// Product owner the profile belongs to; signs without paying.
+    pub owner: Signer<'info>,
+    /// Pays for the `ProductOwnerProfile` the first time it is published.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"product_owner", owner.key().as_ref()],
+        bump
+    )]
+    pub product_owner_account: Account<'info, ProductOwner>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ProductOwnerProfile::SPACE,
+        seeds = [b"product_owner_profile", owner.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, ProductOwnerProfile>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Publishes or replaces the branding `AliasRegistered` carries for every
+/// suffix the product owner operates.
+pub fn handler(ctx: Context<SetProductOwnerProfile>, branding: ProductOwnerBranding) -> Result<()> {
+    validate_product_owner_branding(&branding)?;
+
+    let profile = &mut ctx.accounts.profile;
+    profile.owner = ctx.accounts.owner.key();
+    profile.branding = branding.clone();
+    profile.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProductOwnerProfileUpdated {
+        owner: profile.owner,
+        branding,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct SetRecord<'info> {
+    /// The alias owner, its controller, or a session key scoped for records.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// Session key acting for the alias, when `authority` is not its controller.
+    #[account(
+        constraint = session.alias == alias_account.key() @ ErrorCode::Unauthorized
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AliasRecords::space(&[]),
+        seeds = [b"records", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_records: Account<'info, AliasRecords>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Resizes `account` to `new_len`, topping up rent from `payer` when growing
+/// and refunding the excess to `payer` when shrinking.
+pub(crate) fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let current = account.lamports();
+
+    if required > current {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    } else if current > required {
+        **account.try_borrow_mut_lamports()? -= current - required;
+        **payer.try_borrow_mut_lamports()? += current - required;
+    }
+
+    account.realloc(new_len, false)?;
+    Ok(())
+}
+
+pub fn handler(ctx: Context<SetRecord>, _username: String, _project_suffix: String, _chain_name: String, key: String, value: String) -> Result<()> {
+    require!(!key.is_empty() && key.len() <= MAX_RECORD_KEY_LENGTH, ErrorCode::InvalidRecordKey);
+    require!(value.len() <= MAX_RECORD_VALUE_LENGTH, ErrorCode::RecordValueTooLong);
+    let now = Clock::get()?.unix_timestamp;
+    let alias_account = &ctx.accounts.alias_account;
+    alias_account.require_authority(&ctx.accounts.authority.key(), ctx.accounts.session.as_deref(), SESSION_SCOPE_SET_RECORD, now)?;
+    alias_account.require_usable(now)?;
+
+    let alias = ctx.accounts.alias_account.key();
+    let alias_records = &mut ctx.accounts.alias_records;
+    alias_records.alias = alias;
+
+    match alias_records.records.iter_mut().find(|r| r.key == key) {
+        Some(record) => record.value = value.clone(),
+        None => {
+            require!(alias_records.records.len() < MAX_TEXT_RECORDS, ErrorCode::MaxRecordsReached);
+            alias_records.records.push(TextRecord { key: key.clone(), value: value.clone() });
+        }
+    }
+
+    let new_len = AliasRecords::space(&alias_records.records);
+    resize_account(
+        &alias_records.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    emit!(AliasRecordUpdated {
+        alias,
+        key,
+        value: Some(value),
+    });
+
+    Ok(())
+}
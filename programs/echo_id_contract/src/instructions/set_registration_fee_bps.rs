@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+pub struct SetRegistrationFeeBps<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Sets the protocol's cut of every suffix registration fee.
+pub fn handler(ctx: Context<SetRegistrationFeeBps>, bps: u16) -> Result<()> {
+    require!(bps <= 10_000, ErrorCode::InvalidFeeBps);
+    ctx.accounts.admin_config.registration_fee_bps = bps;
+    Ok(())
+}
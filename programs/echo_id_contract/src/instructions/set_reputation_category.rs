@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetReputationCategory<'info> {
+    /// Suffix owner; signs only, so it may be a PDA.
+    pub product_owner: Signer<'info>,
+    /// Pays for `ReputationCategories` when the suffix adds its first category.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReputationCategories::SPACE,
+        seeds = [b"reputation_categories", suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_categories: Account<'info, ReputationCategories>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Renames or re-tunes reputation category `category`, or adds it when it
+/// is the next unused ID.
+pub fn handler(ctx: Context<SetReputationCategory>, suffix: String, category: u8, name: String, half_life: i64) -> Result<()> {
+    require!(!name.is_empty() && name.len() <= MAX_REPUTATION_CATEGORY_NAME_LENGTH, ErrorCode::InvalidReputationCategory);
+    require!(half_life >= 0, ErrorCode::InvalidReputationCategory);
+
+    let categories = &mut ctx.accounts.reputation_categories.categories;
+    let entry = ReputationCategory { name: name.clone(), half_life };
+    match category as usize {
+        id if id >= 1 && id <= categories.len() => categories[id - 1] = entry,
+        id if id == categories.len() + 1 && id <= MAX_REPUTATION_CATEGORIES => categories.push(entry),
+        _ => return err!(ErrorCode::InvalidReputationCategory),
+    }
+
+    emit!(ReputationCategoryUpdated {
+        suffix,
+        category,
+        name,
+        half_life,
+    });
+
+    Ok(())
+}
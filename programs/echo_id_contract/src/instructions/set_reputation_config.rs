@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReputationConfigParams {
+    pub initial_reputation: i64,
+    pub reputation_half_life: i64,
+    pub min_reputation: i64,
+    pub max_reputation: i64,
+    pub max_reputation_change: i64,
+    pub reputation_window: i64,
+    pub max_reputation_per_window: u64,
+    pub dispute_arbiter: Pubkey,
+    pub tier_thresholds: [i64; MAX_REPUTATION_TIERS],
+    pub tier_hysteresis_bps: u16,
+    pub reputation_transfer_policy: ReputationTransferPolicy,
+    pub verification_reward: i64,
+    pub verification_reward_cooldown: i64,
+    pub quarantine_floor: i64,
+    pub rehabilitation_period: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetReputationConfig<'info> {
+    /// Suffix owner. A vault signing through CPI is fine here.
+    pub product_owner: Signer<'info>,
+    /// Pays for the `ReputationConfig` if the suffix has none yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized,
+        constraint = !suffix_account.is_frozen @ ErrorCode::SuffixFrozen
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReputationConfig::SPACE,
+        seeds = [b"reputation_config", suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: Account<'info, ReputationConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetReputationConfig>, _suffix: String, params: ReputationConfigParams) -> Result<()> {
+    require!(params.reputation_half_life >= 0, ErrorCode::InvalidReputationConfig);
+    require!(MIN_REPUTATION <= params.min_reputation && params.min_reputation <= params.max_reputation && params.max_reputation <= MAX_REPUTATION, ErrorCode::InvalidReputationConfig);
+    require!(params.min_reputation <= params.initial_reputation && params.initial_reputation <= params.max_reputation, ErrorCode::InvalidReputationConfig);
+    require!(params.max_reputation_change > 0 && params.max_reputation_change <= MAX_REPUTATION_CHANGE, ErrorCode::InvalidReputationConfig);
+    require!(params.reputation_window > 0 && params.max_reputation_per_window > 0, ErrorCode::InvalidReputationConfig);
+    let tiers = params.tier_thresholds.iter().take_while(|&&threshold| threshold != 0).count();
+    let (used, unused) = params.tier_thresholds.split_at(tiers);
+    require!(used.iter().all(|&threshold| threshold > 0) && used.windows(2).all(|pair| pair[0] < pair[1]), ErrorCode::InvalidReputationConfig);
+    require!(unused.iter().all(|&threshold| threshold == 0), ErrorCode::InvalidReputationConfig);
+    require!(params.tier_hysteresis_bps <= 10_000, ErrorCode::InvalidReputationConfig);
+    require!(params.verification_reward >= 0 && params.verification_reward <= params.max_reputation_change, ErrorCode::InvalidReputationConfig);
+    require!(params.verification_reward == 0 || params.verification_reward_cooldown > 0, ErrorCode::InvalidReputationConfig);
+    require!(params.quarantine_floor >= MIN_REPUTATION && params.rehabilitation_period >= 0, ErrorCode::InvalidReputationConfig);
+
+    let reputation_config = &mut ctx.accounts.reputation_config;
+    reputation_config.initial_reputation = params.initial_reputation;
+    reputation_config.reputation_half_life = params.reputation_half_life;
+    reputation_config.min_reputation = params.min_reputation;
+    reputation_config.max_reputation = params.max_reputation;
+    reputation_config.max_reputation_change = params.max_reputation_change;
+    reputation_config.reputation_window = params.reputation_window;
+    reputation_config.max_reputation_per_window = params.max_reputation_per_window;
+    reputation_config.dispute_arbiter = params.dispute_arbiter;
+    reputation_config.tier_thresholds = params.tier_thresholds;
+    reputation_config.tier_hysteresis_bps = params.tier_hysteresis_bps;
+    reputation_config.reputation_transfer_policy = params.reputation_transfer_policy;
+    reputation_config.verification_reward = params.verification_reward;
+    reputation_config.verification_reward_cooldown = params.verification_reward_cooldown;
+    reputation_config.quarantine_floor = params.quarantine_floor;
+    reputation_config.rehabilitation_period = params.rehabilitation_period;
+
+    emit!(SuffixUpdated {
+        suffix: ctx.accounts.suffix_account.suffix.clone(),
+        owner: ctx.accounts.suffix_account.owner,
+    });
+
+    Ok(())
+}
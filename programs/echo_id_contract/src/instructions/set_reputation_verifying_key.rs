@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+use crate::zkp::Groth16VerifyingKey;
+
+#[derive(Accounts)]
+pub struct SetReputationVerifyingKey<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ReputationVerifyingKey::SPACE,
+        seeds = [b"reputation_verifying_key"],
+        bump
+    )]
+    pub reputation_verifying_key: Box<Account<'info, ReputationVerifyingKey>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Installs the verifying key of the reputation threshold circuit, replacing
+/// any earlier one when the circuit changes.
+pub fn handler(ctx: Context<SetReputationVerifyingKey>, verifying_key: Groth16VerifyingKey) -> Result<()> {
+    ctx.accounts.reputation_verifying_key.verifying_key = verifying_key;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+pub struct SetRootHistoryDepth<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Sets how many recent chain mapping roots `verify_chain_mapping` accepts.
+/// A depth of 1 accepts only the current root.
+pub fn handler(ctx: Context<SetRootHistoryDepth>, depth: u8) -> Result<()> {
+    require!((1..=MAX_ROOT_HISTORY).contains(&(depth as usize)), ErrorCode::InvalidRootHistoryDepth);
+    ctx.accounts.admin_config.root_history_depth = depth;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::validation::validate_username_policy;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SuffixConfigParams {
+    pub max_chain_mappings: u8,
+    pub allow_multiple_per_chain: bool,
+    pub min_username_length: u8,
+    pub max_username_length: u8,
+    pub username_charset: u8,
+    pub registration_fee: u64,
+    pub premium_fees: [u64; PREMIUM_LENGTH_BUCKETS],
+    pub allowlist_mode: bool,
+    pub max_registrations_per_day: u32,
+    pub max_total_registrations: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub registration_proof_required: bool,
+    pub registration_verifying_key: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetSuffixConfig<'info> {
+    /// Suffix owner, possibly a PDA signing through CPI.
+    pub product_owner: Signer<'info>,
+    /// Pays for the `SuffixConfig` if the suffix has none yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized,
+        constraint = !suffix_account.is_frozen @ ErrorCode::SuffixFrozen
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SuffixConfig::SPACE,
+        seeds = [b"suffix_config", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_config: Account<'info, SuffixConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetSuffixConfig>, _suffix: String, params: SuffixConfigParams) -> Result<()> {
+    require!(params.max_chain_mappings as usize <= MAX_CHAIN_MAPPINGS, ErrorCode::InvalidSuffixConfig);
+    require!(!params.registration_proof_required || params.registration_verifying_key.is_some(), ErrorCode::InvalidSuffixConfig);
+
+    let suffix_config = &mut ctx.accounts.suffix_config;
+    suffix_config.max_chain_mappings = params.max_chain_mappings;
+    suffix_config.allow_multiple_per_chain = params.allow_multiple_per_chain;
+    suffix_config.min_username_length = params.min_username_length;
+    suffix_config.max_username_length = params.max_username_length;
+    suffix_config.username_charset = params.username_charset;
+    suffix_config.registration_fee = params.registration_fee;
+    suffix_config.premium_fees = params.premium_fees;
+    suffix_config.allowlist_mode = params.allowlist_mode;
+    suffix_config.max_registrations_per_day = params.max_registrations_per_day;
+    suffix_config.max_total_registrations = params.max_total_registrations;
+    suffix_config.fee_mint = params.fee_mint;
+    suffix_config.registration_proof_required = params.registration_proof_required;
+    suffix_config.registration_verifying_key = params.registration_verifying_key;
+    validate_username_policy(suffix_config)?;
+
+    emit!(SuffixUpdated {
+        suffix: ctx.accounts.suffix_account.suffix.clone(),
+        owner: ctx.accounts.suffix_account.owner,
+    });
+
+    Ok(())
+}
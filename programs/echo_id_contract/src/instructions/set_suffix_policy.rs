@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SuffixPolicyParams {
+    pub transfer_requires_cosign: bool,
+    pub renewal_fee: u64,
+    pub require_commitment: bool,
+    pub require_registration_approval: bool,
+    pub transferable: bool,
+    pub royalty_bps: u16,
+    pub transfer_lock_duration: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetSuffixPolicy<'info> {
+    pub product_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized,
+        constraint = !suffix_account.is_frozen @ ErrorCode::SuffixFrozen
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+}
+
+pub fn handler(ctx: Context<SetSuffixPolicy>, _suffix: String, params: SuffixPolicyParams) -> Result<()> {
+    require!(params.royalty_bps <= 10_000 - PROTOCOL_FEE_BPS, ErrorCode::InvalidRoyalty);
+
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    suffix_account.transfer_requires_cosign = params.transfer_requires_cosign;
+    suffix_account.renewal_fee = params.renewal_fee;
+    suffix_account.require_commitment = params.require_commitment;
+    suffix_account.require_registration_approval = params.require_registration_approval;
+    suffix_account.transferable = params.transferable;
+    suffix_account.royalty_bps = params.royalty_bps;
+    suffix_account.transfer_lock_duration = params.transfer_lock_duration;
+
+    emit!(SuffixUpdated {
+        suffix: suffix_account.suffix.clone(),
+        owner: suffix_account.owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+pub struct SetSuffixRenewalFee<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+pub fn handler(ctx: Context<SetSuffixRenewalFee>, amount: u64) -> Result<()> {
+    ctx.accounts.admin_config.suffix_renewal_fee = amount;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct SetSuffixWeight<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = SuffixWeight::space(&suffix),
+        seeds = [b"suffix_weight", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_weight: Account<'info, SuffixWeight>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets how much the suffix's scores count in aggregate reputation; zero
+/// leaves them out.
+pub fn handler(ctx: Context<SetSuffixWeight>, suffix: String, weight_bps: u16) -> Result<()> {
+    require!(weight_bps <= 10_000, ErrorCode::InvalidReputationWeight);
+
+    let suffix_weight = &mut ctx.accounts.suffix_weight;
+    suffix_weight.suffix = suffix;
+    suffix_weight.weight_bps = weight_bps;
+
+    emit!(SuffixWeightUpdated {
+        suffix: suffix_weight.suffix.clone(),
+        weight_bps,
+    });
+
+    Ok(())
+}
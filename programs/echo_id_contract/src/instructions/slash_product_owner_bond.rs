@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, error::EchoIDError as ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct SlashProductOwnerBond<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [b"product_owner", owner.as_ref()],
+        bump
+    )]
+    pub product_owner_account: Account<'info, ProductOwner>,
+}
+
+/// Takes up to `amount` of the owner's bond for a policy violation, paying
+/// it to the admin. The owner must top the bond back up before it can add or
+/// take over suffixes again.
+pub fn handler(ctx: Context<SlashProductOwnerBond>, owner: Pubkey, amount: u64) -> Result<()> {
+    let product_owner_account = &mut ctx.accounts.product_owner_account;
+    let slashed = amount.min(product_owner_account.bond);
+    product_owner_account.bond -= slashed;
+    **product_owner_account.to_account_info().try_borrow_mut_lamports()? -= slashed;
+    **ctx.accounts.admin.to_account_info().try_borrow_mut_lamports()? += slashed;
+
+    emit!(ProductOwnerBondUpdated {
+        owner,
+        bond: product_owner_account.bond,
+        slashed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::update_reputation::emit_reputation_changed;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct SlashReputation<'info> {
+    #[account(mut)]
+    pub slasher: Signer<'info>,
+    #[account(
+        seeds = [b"reputation_slasher", project_suffix.as_bytes(), slasher.key().as_ref()],
+        bump
+    )]
+    pub reputation_slasher: Account<'info, ReputationSlasher>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    /// CHECK: Receives slashed stake; checked against `admin_config.admin`.
+    #[account(mut, address = admin_config.admin @ ErrorCode::Unauthorized)]
+    pub admin: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = slasher,
+        space = ReputationHistory::SPACE,
+        seeds = [b"reputation_history", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_history: Box<Account<'info, ReputationHistory>>,
+    /// CHECK: The alias's `ReputationVault`, slashed whenever it exists.
+    /// Read through `ReputationVault::load_or_default` so a caller cannot
+    /// spare the stake by leaving it out.
+    #[account(
+        mut,
+        seeds = [b"reputation_vault", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_vault: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Cuts `slash_bps` of the alias's general reputation, if positive, and of
+/// any stake behind it, which goes to the admin. Slashes bypass the suffix's
+/// rate limit and are recorded in history under `SLASH_REASON`.
+pub fn handler(ctx: Context<SlashReputation>, _username: String, _project_suffix: String, _chain_name: String, slash_bps: u16, evidence_hash: [u8; 32]) -> Result<()> {
+    require!(slash_bps > 0 && slash_bps <= 10_000, ErrorCode::InvalidSlash);
+    let accounts = ctx.accounts;
+    let alias_account = &mut accounts.alias_account;
+    require!(alias_account.owner_managed, ErrorCode::ProductOwnerControlRenounced);
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = ReputationConfig::load_or_default(&accounts.reputation_config)?;
+    let stored = alias_account.reputation;
+    let reputation = decay_reputation(stored, alias_account.reputation_updated_at, now, config.reputation_half_life);
+    let reputation_cut = (reputation.max(0) as i128 * slash_bps as i128 / 10_000) as i64;
+    alias_account.set_category_reputation(0, reputation - reputation_cut, now);
+    emit_reputation_changed(alias_account.key(), 0, stored, reputation, reputation - reputation_cut, accounts.slasher.key(), SLASH_REASON);
+
+    let history = &mut accounts.reputation_history;
+    history.alias = alias_account.key();
+    history.push(ReputationEntry {
+        timestamp: now,
+        delta: -reputation_cut,
+        authority: accounts.slasher.key(),
+        reason: SLASH_REASON,
+        category: 0,
+    });
+
+    let mut stake_cut = 0;
+    let vault_info = accounts.reputation_vault.to_account_info();
+    if !vault_info.data_is_empty() {
+        let mut vault = ReputationVault::load_or_default(&vault_info)?;
+        stake_cut = vault.slash(slash_bps);
+        vault.try_serialize(&mut &mut vault_info.try_borrow_mut_data()?[..])?;
+        vault_info.sub_lamports(stake_cut)?;
+        accounts.admin.add_lamports(stake_cut)?;
+    }
+
+    emit!(Slashed {
+        alias: alias_account.key(),
+        slasher: accounts.slasher.key(),
+        slash_bps,
+        reputation_cut,
+        stake_cut,
+        evidence_hash,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct StakeForReputation<'info> {
+    /// The alias owner or anyone else willing to back the alias.
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = ReputationVault::SPACE,
+        seeds = [b"reputation_vault", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_vault: Account<'info, ReputationVault>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = ReputationStake::SPACE,
+        seeds = [b"reputation_stake", alias_account.key().as_ref(), staker.key().as_ref()],
+        bump
+    )]
+    pub reputation_stake: Account<'info, ReputationStake>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Bonds `amount` lamports behind the alias, boosting its effective
+/// reputation until withdrawn and exposing them to slashing.
+pub fn handler(ctx: Context<StakeForReputation>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidStake);
+    require!(ctx.accounts.reputation_stake.unlocks_at == 0, ErrorCode::InvalidStake);
+    ctx.accounts.alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+
+    let shares = ctx.accounts.reputation_vault.shares_for(amount)?;
+    require!(shares > 0, ErrorCode::InvalidStake);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.staker.to_account_info(),
+                to: ctx.accounts.reputation_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let alias = ctx.accounts.alias_account.key();
+    let vault = &mut ctx.accounts.reputation_vault;
+    vault.alias = alias;
+    vault.total_staked = vault.total_staked.checked_add(amount).ok_or(ErrorCode::InvalidStake)?;
+    vault.total_shares = vault.total_shares.checked_add(shares).ok_or(ErrorCode::InvalidStake)?;
+
+    let stake = &mut ctx.accounts.reputation_stake;
+    stake.alias = alias;
+    stake.staker = ctx.accounts.staker.key();
+    stake.shares += shares;
+
+    emit!(ReputationStaked {
+        alias,
+        staker: stake.staker,
+        amount,
+        shares,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::mint_alias_token::{retire_alias_token, TOKEN_2022_PROGRAM_ID};
+use crate::instructions::set_primary_alias::clear_reverse_record;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct TransferAlias<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// Only required when the suffix enforces product owner co-signature and
+    /// the alias owner has not renounced product owner control.
+    #[account(
+        constraint = product_owner.key() == suffix_account.owner @ ErrorCode::Unauthorized
+    )]
+    pub product_owner: Option<Signer<'info>>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: Primary alias record of the current owner, cleared if it points at this alias.
+    #[account(
+        mut,
+        seeds = [b"reverse", alias_account.owner.as_ref()],
+        bump,
+    )]
+    pub reverse_record: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The alias token mint; its token is burned if one was minted.
+    #[account(
+        mut,
+        seeds = [b"alias_mint", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_mint: UncheckedAccount<'info>,
+    /// CHECK: Token account holding the alias token, required while one is
+    /// outstanding; validated by the token program.
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Token-2022 program, required once the alias has a mint.
+    #[account(address = TOKEN_2022_PROGRAM_ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+}
+
+/// Applies the suffix's `ReputationTransferPolicy` to an alias changing
+/// hands and records the scores before and after.
+pub(crate) fn transfer_reputation(alias_account: &mut Account<AliasAccount>, reputation_config: &AccountInfo, now: i64) -> Result<()> {
+    let policy = ReputationConfig::load_or_default(reputation_config)?.reputation_transfer_policy;
+    let (previous_reputation, reputation) = alias_account.transfer_reputation(policy, now);
+    emit!(AliasReputationTransferred {
+        alias: alias_account.key(),
+        policy,
+        previous_reputation,
+        reputation,
+        escrowed_reputation: alias_account.escrowed_reputation,
+    });
+    Ok(())
+}
+
+pub fn handler(ctx: Context<TransferAlias>, username: String, project_suffix: String, chain_name: String, new_owner: Pubkey) -> Result<()> {
+    retire_alias_token(
+        &ctx.accounts.alias_account.to_account_info(),
+        &[username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes(), &[ctx.bumps.alias_account]],
+        &ctx.accounts.alias_mint,
+        ctx.accounts.token_account.as_deref(),
+        ctx.accounts.token_program.as_deref(),
+        None,
+    )?;
+    if ctx.accounts.suffix_account.transfer_requires_cosign && ctx.accounts.alias_account.owner_managed {
+        require!(ctx.accounts.product_owner.is_some(), ErrorCode::ProductOwnerSignatureRequired);
+    }
+
+    let alias_key = ctx.accounts.alias_account.key();
+    clear_reverse_record(&ctx.accounts.reverse_record, alias_key)?;
+
+    let alias_account = &mut ctx.accounts.alias_account;
+    require!(new_owner != alias_account.owner, ErrorCode::InvalidOwner);
+    let now = Clock::get()?.unix_timestamp;
+    alias_account.require_usable(now)?;
+    alias_account.require_transfer_unlocked(&ctx.accounts.suffix_account, now)?;
+
+    let previous_owner = alias_account.owner;
+    alias_account.owner = new_owner;
+    alias_account.controller = new_owner;
+    alias_account.pending_owner = None;
+    transfer_reputation(alias_account, &ctx.accounts.reputation_config, now)?;
+
+    emit!(AliasTransferred {
+        username: alias_account.username.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        previous_owner,
+        new_owner,
+    });
+
+    Ok(())
+}
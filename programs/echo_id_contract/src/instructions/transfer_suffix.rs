@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::register_product_owner::post_product_owner_bond;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct TransferSuffix<'info> {
+    /// The current product owner, or the admin arbitrating the transfer.
+    #[account(
+        constraint = authority.key() == suffix_account.owner || authority.key() == admin_config.admin @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    /// The product owner taking over; pays for its `ProductOwner` record if
+    /// it has none yet, and tops up its bond.
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        mut,
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner != new_owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// The current owner's record, when it has one; owners that came in
+    /// through a key rotation may not.
+    #[account(
+        mut,
+        seeds = [b"product_owner", suffix_account.owner.as_ref()],
+        bump
+    )]
+    pub product_owner_account: Option<Account<'info, ProductOwner>>,
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = ProductOwner::SPACE,
+        seeds = [b"product_owner", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_product_owner_account: Account<'info, ProductOwner>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Hands the suffix to `new_owner`. Alias PDAs are derived from the suffix
+/// name alone, so every existing alias stays valid. A frozen suffix can only
+/// be moved by the admin.
+pub fn handler(ctx: Context<TransferSuffix>, _suffix: String) -> Result<()> {
+    let by_admin = ctx.accounts.authority.key() != ctx.accounts.suffix_account.owner;
+    require!(by_admin || !ctx.accounts.suffix_account.is_frozen, ErrorCode::SuffixFrozen);
+
+    let new_owner = ctx.accounts.new_owner.key();
+    post_product_owner_bond(
+        &mut ctx.accounts.new_product_owner_account,
+        &ctx.accounts.new_owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin_config.product_owner_bond,
+    )?;
+    ctx.accounts.new_product_owner_account.attach_suffix(new_owner, Clock::get()?.unix_timestamp)?;
+    if let Some(product_owner_account) = ctx.accounts.product_owner_account.as_mut() {
+        product_owner_account.detach_suffix();
+    }
+
+    let suffix_account = &mut ctx.accounts.suffix_account;
+    let previous_owner = suffix_account.owner;
+    suffix_account.owner = new_owner;
+    suffix_account.pending_owner = None;
+
+    emit!(SuffixTransferred {
+        suffix: suffix_account.suffix.clone(),
+        previous_owner,
+        owner: new_owner,
+        by_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct Undelegate<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        constraint = alias_account.owner == owner.key() @ ErrorCode::InvalidOwner
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"reputation_delegation", alias_account.key().as_ref()],
+        bump,
+        constraint = delegation.delegated > 0 @ ErrorCode::InvalidDelegation
+    )]
+    pub delegation: Account<'info, ReputationDelegation>,
+    #[account(
+        mut,
+        seeds = [b"reputation_delegation", delegation.delegate.as_ref()],
+        bump
+    )]
+    pub target_delegation: Account<'info, ReputationDelegation>,
+}
+
+/// Withdraws the alias's delegation, returning the reputation to the alias.
+pub fn handler(ctx: Context<Undelegate>) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let target_delegation = &mut ctx.accounts.target_delegation;
+    target_delegation.received = target_delegation.received.saturating_sub(delegation.delegated);
+    delegation.delegated = 0;
+    delegation.delegate = Pubkey::default();
+
+    emit!(ReputationDelegated {
+        alias: delegation.alias,
+        delegate: target_delegation.alias,
+        amount: 0,
+    });
+    Ok(())
+}
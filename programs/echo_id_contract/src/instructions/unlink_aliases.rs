@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct UnlinkAliases<'info> {
+    /// The owner of either linked alias.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub alias_a: Account<'info, AliasAccount>,
+    pub alias_b: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"link", alias_a.key().as_ref(), alias_b.key().as_ref()],
+        bump,
+        constraint = alias_a.owner == authority.key() || alias_b.owner == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub alias_link: Account<'info, AliasLink>,
+}
+
+pub fn handler(ctx: Context<UnlinkAliases>) -> Result<()> {
+    emit!(AliasesUnlinked {
+        alias_a: ctx.accounts.alias_link.alias_a,
+        alias_b: ctx.accounts.alias_link.alias_b,
+        unlinked_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub staker: Signer<'info>,
+    /// CHECK: Only used to derive the stake address, so stakes stay
+    /// withdrawable after the alias is closed.
+    pub alias: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"reputation_stake", alias.key().as_ref(), staker.key().as_ref()],
+        bump
+    )]
+    pub reputation_stake: Account<'info, ReputationStake>,
+}
+
+/// Starts the cooldown after which `withdraw_stake` releases the stake.
+pub fn handler(ctx: Context<Unstake>) -> Result<()> {
+    let stake = &mut ctx.accounts.reputation_stake;
+    require!(stake.unlocks_at == 0, ErrorCode::InvalidStake);
+    stake.unlocks_at = Clock::get()?.unix_timestamp.saturating_add(REPUTATION_UNSTAKE_COOLDOWN);
+
+    emit!(ReputationUnstakeRequested {
+        alias: stake.alias,
+        staker: stake.staker,
+        shares: stake.shares,
+        unlocks_at: stake.unlocks_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String, metadata: AliasMetadata)]
+pub struct UpdateAliasMetadata<'info> {
+    /// The alias owner or its controller.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized,
+        realloc = AliasAccount::space(&alias_account.username, &alias_account.product_suffix, &alias_account.chain_info, &metadata) + alias_account.display_name.len(),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateAliasMetadata>, _username: String, _project_suffix: String, _chain_name: String, metadata: AliasMetadata) -> Result<()> {
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_usable(Clock::get()?.unix_timestamp)?;
+
+    alias_account.metadata = metadata;
+
+    emit!(AliasMetadataUpdated {
+        username: alias_account.username.clone(),
+        display_name: alias_account.display_name.clone(),
+        suffix: alias_account.product_suffix.clone(),
+        chain_info: alias_account.chain_info.clone(),
+        metadata: alias_account.metadata.clone(),
+    });
+
+    Ok(())
+}
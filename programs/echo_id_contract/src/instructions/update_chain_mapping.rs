@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::merkle::{hash_chain_mapping, LeafCodec};
+use crate::validation::validate_chain_address;
+use super::add_chain_mapping::require_unproven_allowed;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct UpdateChainMapping<'info> {
+    /// The alias owner or its controller.
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+        constraint = alias_account.is_controller(&authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        mut,
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    /// Required when the alias keeps a registry.
+    #[account(
+        mut,
+        seeds = [b"mapping_registry", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_registry: Option<Account<'info, ChainMappingRegistry>>,
+}
+
+/// Rotates the address in the slot `proof` points at from `old` to
+/// `new_address`. The new address counts as freshly attested.
+pub fn handler(ctx: Context<UpdateChainMapping>, _username: String, _project_suffix: String, _chain_name: String, old: AttestedChainMapping, new_address: Vec<u8>, proof: ChainMappingProof) -> Result<()> {
+    LeafCodec::require_version(proof.leaf_version)?;
+    let now = Clock::get()?.unix_timestamp;
+    let alias_account = &mut ctx.accounts.alias_account;
+    alias_account.require_usable(now)?;
+    require!(old.mapping.address != new_address, ErrorCode::InvalidChainMapping);
+    validate_chain_address(old.mapping.chain_type, &new_address)?;
+    require_unproven_allowed(&old.mapping)?;
+    ctx.accounts.chain_mapping_tree.require_registry(ctx.accounts.chain_mapping_registry.as_deref())?;
+
+    let new = AttestedChainMapping::new(ChainMapping { address: new_address, ..old.mapping.clone() }, now);
+    let hash = ctx.accounts.chain_mapping_tree.tree.hash;
+    let leaf = hash_chain_mapping(hash, &new);
+    let root = ctx.accounts.chain_mapping_tree.tree
+        .replace(&alias_account.chain_mappings_root, proof.index, &proof.siblings, hash_chain_mapping(hash, &old), leaf)
+        .ok_or(ErrorCode::InvalidChainMapping)?;
+    alias_account.set_chain_mappings_root(root);
+    ctx.accounts.chain_mapping_tree.root_history.push(root);
+    if let Some(registry) = ctx.accounts.chain_mapping_registry.as_deref_mut() {
+        registry.replace(proof.index, new.clone());
+    }
+
+    emit!(ChainMappingRotated {
+        alias: alias_account.key(),
+        index: proof.index,
+        leaf,
+        chain_id: new.mapping.chain_id,
+        old_address: old.mapping.address,
+        new_address: new.mapping.address,
+        attested_at: new.attested_at,
+        expires_at: new.expires_at,
+    });
+    emit!(RootUpdated {
+        alias: alias_account.key(),
+        chain_mappings_root: alias_account.chain_mappings_root,
+        chain_mapping_count: alias_account.chain_mapping_count,
+        chain_mappings_version: alias_account.chain_mappings_version,
+    });
+
+    Ok(())
+}
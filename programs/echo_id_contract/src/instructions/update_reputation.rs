@@ -10,11 +10,28 @@ pub struct UpdateReputation<'info> {
     #[account(
         seeds = [b"admin"],
         bump,
-        constraint = admin_config.admin == admin.key() @ ErrorCode::Unauthorized
     )]
     pub admin_config: Account<'info, AdminConfig>,
-    #[account(mut)]
-    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// The admin, the product owner of the suffix, or one of its reputation
+    /// authorities.
+    #[account(
+        mut,
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner || reputation_authority.is_some() @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    /// Required when `authority` acts as one of the suffix's reputation
+    /// authorities.
+    #[account(
+        seeds = [b"reputation_authority", project_suffix.as_bytes(), authority.key().as_ref()],
+        bump
+    )]
+    pub reputation_authority: Option<Account<'info, ReputationAuthority>>,
     #[account(
         mut,
         seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(),chain_name.as_bytes()],
@@ -24,27 +41,143 @@ pub struct UpdateReputation<'info> {
         constraint = alias_account.chain_info.name == chain_name @ ErrorCode::InvalidChainName
     )]
     pub alias_account: Account<'info, AliasAccount>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationCategories`, read through
+    /// `ReputationCategories::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_categories", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_categories: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReputationHistory::SPACE,
+        seeds = [b"reputation_history", alias_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_history: Box<Account<'info, ReputationHistory>>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<UpdateReputation>, username: String, project_suffix: String, chain_name: String, reputation_change: i64) -> Result<()> {
-    let alias_account = &mut ctx.accounts.alias_account;
-    
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReputationUpdate {
+    pub alias: Pubkey,
+    pub change: i64,
+    pub reason: u16,
+    /// 0 for the general score, or one of the suffix's categories.
+    pub category: u8,
+}
+
+/// Emits `ReputationChanged` for a score stored as `stored` that decayed to
+/// `decayed` and was then set to `new` by `authority` for `reason`.
+pub(crate) fn emit_reputation_changed(alias: Pubkey, category: u8, stored: i64, decayed: i64, new: i64, authority: Pubkey, reason: u16) {
+    if decayed != stored {
+        emit!(ReputationChanged {
+            alias,
+            category,
+            old: stored,
+            new: decayed,
+            delta: decayed - stored,
+            authority: Pubkey::default(),
+            reason: DECAY_REASON,
+        });
+    }
+    emit!(ReputationChanged {
+        alias,
+        category,
+        old: decayed,
+        new,
+        delta: new - decayed,
+        authority,
+        reason,
+    });
+}
+
+/// Who is changing reputation: the admin, the suffix's product owner, or one
+/// of its reputation authorities, whose changes are weighted.
+pub(crate) struct ReputationUpdater<'a> {
+    pub key: Pubkey,
+    pub is_admin: bool,
+    pub oracle: Option<&'a ReputationAuthority>,
+}
+
+impl<'a> ReputationUpdater<'a> {
+    pub(crate) fn new(key: Pubkey, admin_config: &AdminConfig, suffix_account: &SuffixAccount, reputation_authority: Option<&'a ReputationAuthority>) -> Result<Self> {
+        let is_admin = key == admin_config.admin;
+        let oracle = reputation_authority.filter(|_| !is_admin && key != suffix_account.owner);
+        if let Some(oracle) = oracle {
+            require!(oracle.bond >= REPUTATION_AUTHORITY_BOND, ErrorCode::ReputationAuthorityUnbonded);
+        }
+        Ok(Self { key, is_admin, oracle })
+    }
+
+    /// Decays the alias's score in `update.category`, applies the change
+    /// within the suffix's limits, and records it in `history`. A decrease
+    /// that leaves the general score below the suffix's quarantine floor
+    /// quarantines the alias.
+    pub(crate) fn apply(&self, alias_account: &mut AliasAccount, history: &mut ReputationHistory, config: &ReputationConfig, categories: &ReputationCategories, update: &ReputationUpdate, now: i64) -> Result<()> {
+        require!(self.is_admin || alias_account.owner_managed, ErrorCode::ProductOwnerControlRenounced);
+        alias_account.require_usable(now)?;
+
+        // Decay what was earned so far before applying the change
+        let half_life = categories.half_life(update.category, config)?;
+        let (stored, updated_at) = alias_account.category_reputation(update.category)?;
+        let reputation = decay_reputation(stored, updated_at, now, half_life);
+        let change = self.oracle.map_or(update.change, |oracle| oracle.weigh(update.change));
+        alias_account.record_reputation_change(change, config, now)?;
+        let updated = config.apply_reputation_change(reputation, change)?;
+        alias_account.set_category_reputation(update.category, updated, now);
+
+        history.push(ReputationEntry {
+            timestamp: now,
+            delta: updated - reputation,
+            authority: self.key,
+            reason: update.reason,
+            category: update.category,
+        });
+        emit_reputation_changed(update.alias, update.category, stored, reputation, updated, self.key, update.reason);
+
+        if update.category == 0 && change < 0 && alias_account.quarantine_below_floor(config, now) {
+            emit!(AliasQuarantineUpdated {
+                alias: update.alias,
+                is_quarantined: true,
+                reputation: updated,
+                authority: self.key,
+            });
+        }
+
+        emit!(AliasReputationUpdated {
+            username: alias_account.username.clone(),
+            suffix: alias_account.product_suffix.clone(),
+            chain_info: alias_account.chain_info.clone(),
+            metadata: alias_account.metadata.clone(),
+            user_address: alias_account.owner,
+        });
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<UpdateReputation>, username: String, project_suffix: String, chain_name: String, reputation_change: i64, reason: u16, category: u8) -> Result<()> {
+    let accounts = ctx.accounts;
+    let alias_account = &mut accounts.alias_account;
+
     // Verify that the provided username and project_suffix match the account
     require!(alias_account.username == username, ErrorCode::InvalidAlias);
     require!(alias_account.product_suffix == project_suffix, ErrorCode::InvalidProjectSuffix);
     require!(alias_account.chain_info.name == chain_name, ErrorCode::InvalidChainName);
-    // Update reputation
-    alias_account.reputation = alias_account.reputation.saturating_add(reputation_change);
-    alias_account.reputation_updated_at = Clock::get()?.unix_timestamp;
-
-    
-    emit!(AliasReputationUpdated {
-        username: alias_account.username.clone(),
-        suffix: alias_account.product_suffix.clone(),
-        chain_info: alias_account.chain_info.clone(),
-        metadata: alias_account.metadata.clone(),
-        user_address: alias_account.owner,
-    });
-    Ok(())
-}
\ No newline at end of file
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = ReputationConfig::load_or_default(&accounts.reputation_config)?;
+    let categories = ReputationCategories::load_or_default(&accounts.reputation_categories)?;
+    let updater = ReputationUpdater::new(accounts.authority.key(), &accounts.admin_config, &accounts.suffix_account, accounts.reputation_authority.as_deref())?;
+    accounts.reputation_history.alias = alias_account.key();
+    let update = ReputationUpdate { alias: alias_account.key(), change: reputation_change, reason, category };
+    updater.apply(alias_account, &mut accounts.reputation_history, &config, &categories, &update, now)
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use super::update_reputation::{ReputationUpdate, ReputationUpdater};
+
+#[derive(Accounts)]
+#[instruction(project_suffix: String)]
+pub struct UpdateReputationBatch<'info> {
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    #[account(
+        seeds = [b"suffix", project_suffix.as_bytes()],
+        bump,
+        constraint = !suffix_account.is_paused @ ErrorCode::SuffixPaused
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    /// The admin, the product owner of the suffix, or one of its reputation
+    /// authorities.
+    #[account(
+        mut,
+        constraint = authority.key() == admin_config.admin || authority.key() == suffix_account.owner || reputation_authority.is_some() @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    /// Required when `authority` acts as one of the suffix's reputation
+    /// authorities.
+    #[account(
+        seeds = [b"reputation_authority", project_suffix.as_bytes(), authority.key().as_ref()],
+        bump
+    )]
+    pub reputation_authority: Option<Account<'info, ReputationAuthority>>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: The suffix's `ReputationCategories`, read through
+    /// `ReputationCategories::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_categories", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_categories: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Applies each of `updates` as `update_reputation` would. Every update
+/// takes two remaining accounts, in order: the alias, which must belong to
+/// `project_suffix`, and its reputation history, created if missing.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, UpdateReputationBatch<'info>>, project_suffix: String, updates: Vec<ReputationUpdate>) -> Result<()> {
+    require!(!updates.is_empty(), ErrorCode::InvalidInstruction);
+    require!(updates.len() * 2 == ctx.remaining_accounts.len(), ErrorCode::InvalidInstruction);
+
+    let accounts = &ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+    let config = ReputationConfig::load_or_default(&accounts.reputation_config)?;
+    let categories = ReputationCategories::load_or_default(&accounts.reputation_categories)?;
+    let updater = ReputationUpdater::new(accounts.authority.key(), &accounts.admin_config, &accounts.suffix_account, accounts.reputation_authority.as_deref())?;
+
+    for (update, pair) in updates.iter().zip(ctx.remaining_accounts.chunks_exact(2)) {
+        let (alias_info, history_info) = (&pair[0], &pair[1]);
+        require_keys_eq!(alias_info.key(), update.alias, ErrorCode::InvalidDerivedAccount);
+        require_keys_eq!(*alias_info.owner, crate::ID, ErrorCode::InvalidDerivedAccount);
+        let mut alias_account = AliasAccount::try_deserialize(&mut &alias_info.try_borrow_data()?[..])?;
+        require!(alias_account.product_suffix == project_suffix, ErrorCode::InvalidProjectSuffix);
+
+        let mut history = load_or_create_history(accounts, alias_info.key, history_info)?;
+        updater.apply(&mut alias_account, &mut history, &config, &categories, update, now)?;
+
+        alias_account.try_serialize(&mut &mut alias_info.try_borrow_mut_data()?[..])?;
+        history.try_serialize(&mut &mut history_info.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
+
+fn load_or_create_history<'info>(accounts: &UpdateReputationBatch<'info>, alias: &Pubkey, history_info: &AccountInfo<'info>) -> Result<ReputationHistory> {
+    let (expected, bump) = Pubkey::find_program_address(&[b"reputation_history", alias.as_ref()], &crate::ID);
+    require_keys_eq!(history_info.key(), expected, ErrorCode::InvalidDerivedAccount);
+    if !history_info.data_is_empty() {
+        return ReputationHistory::try_deserialize(&mut &history_info.try_borrow_data()?[..]);
+    }
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: accounts.authority.to_account_info(),
+                to: history_info.clone(),
+            },
+            &[&[b"reputation_history", alias.as_ref(), &[bump]]],
+        ),
+        Rent::get()?.minimum_balance(ReputationHistory::SPACE),
+        ReputationHistory::SPACE as u64,
+        &crate::ID,
+    )?;
+    Ok(ReputationHistory {
+        alias: *alias,
+        entries: Default::default(),
+        head: 0,
+        len: 0,
+    })
+}
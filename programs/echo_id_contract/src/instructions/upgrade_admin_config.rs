@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_record::resize_account;
+
+#[derive(Accounts)]
+pub struct UpgradeAdminConfig<'info> {
+    /// Anyone may upgrade the config; the payer covers any additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Deserialized manually since older layouts don't match `AdminConfig`.
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump,
+        owner = crate::ID @ ErrorCode::InvalidDerivedAccount
+    )]
+    pub admin_config: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Widths of the `AdminConfig` fields in declaration order. Every layout so
+/// far only appended fields, so an older config is the current one cut off
+/// after its first few fields and is told apart by its length.
+const ADMIN_CONFIG_FIELDS: [usize; 5] = [32, 1, 2, 8, 8];
+
+/// Rewrites an `AdminConfig` created before its newer fields existed,
+/// filling them with the defaults `initialize` uses.
+pub fn handler(ctx: Context<UpgradeAdminConfig>) -> Result<()> {
+    let config_info = ctx.accounts.admin_config.to_account_info();
+    let from_len = config_info.data_len();
+
+    let upgraded = {
+        let data = config_info.try_borrow_data()?;
+        require!(data.len() >= 8 && data[..8] == AdminConfig::DISCRIMINATOR, ErrorCode::UnsupportedAccountVersion);
+        require!(data.len() < AdminConfig::SPACE, ErrorCode::AccountLayoutUpToDate);
+        let fields = (1..ADMIN_CONFIG_FIELDS.len())
+            .find(|&fields| 8 + ADMIN_CONFIG_FIELDS[..fields].iter().sum::<usize>() == data.len())
+            .ok_or(ErrorCode::UnsupportedAccountVersion)?;
+
+        let mut config = AdminConfig {
+            admin: Pubkey::default(),
+            root_history_depth: MAX_ROOT_HISTORY as u8,
+            registration_fee_bps: PROTOCOL_FEE_BPS,
+            product_owner_bond: PRODUCT_OWNER_BOND,
+            suffix_renewal_fee: SUFFIX_RENEWAL_FEE,
+        };
+        let cur = &mut &data[8..];
+        config.admin = Pubkey::deserialize(cur)?;
+        if fields > 1 {
+            config.root_history_depth = u8::deserialize(cur)?;
+        }
+        if fields > 2 {
+            config.registration_fee_bps = u16::deserialize(cur)?;
+        }
+        if fields > 3 {
+            config.product_owner_bond = u64::deserialize(cur)?;
+        }
+        config
+    };
+
+    resize_account(
+        &config_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        AdminConfig::SPACE,
+    )?;
+    upgraded.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountLayoutUpgraded {
+        account: config_info.key(),
+        from_len: from_len as u32,
+        to_len: AdminConfig::SPACE as u32,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,550 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_record::resize_account;
+
+#[derive(Accounts)]
+pub struct UpgradeAliasAccount<'info> {
+    /// Anyone may upgrade an alias; the payer covers any additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Deserialized manually since older layouts don't match `AliasAccount`.
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::InvalidDerivedAccount
+    )]
+    pub alias_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpgradeAliasAccount>) -> Result<()> {
+    let alias_info = ctx.accounts.alias_account.to_account_info();
+
+    let (upgraded, from_version) = {
+        let data = alias_info.try_borrow_data()?;
+        require!(data.len() >= 8 && data[..8] == AliasAccount::DISCRIMINATOR, ErrorCode::UnsupportedAccountVersion);
+
+        if let Ok(current) = AliasAccount::try_deserialize(&mut &data[..]) {
+            require!(current.version < ALIAS_ACCOUNT_VERSION, ErrorCode::AliasAccountUpToDate);
+            return err!(ErrorCode::UnsupportedAccountVersion);
+        }
+
+        // Borsh ignores trailing bytes, so try the newest layout first.
+        if let Ok(v13) = AliasAccountV13::deserialize(&mut &data[8..]) {
+            require!(v13.version == 13, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v13(v13), 13)
+        } else if let Ok(v12) = AliasAccountV12::deserialize(&mut &data[8..]) {
+            require!(v12.version == 12, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v12(v12), 12)
+        } else if let Ok(v11) = AliasAccountV11::deserialize(&mut &data[8..]) {
+            require!(v11.version == 11, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v11(v11), 11)
+        } else if let Ok(v10) = AliasAccountV10::deserialize(&mut &data[8..]) {
+            require!(v10.version == 10, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v10(v10), 10)
+        } else if let Ok(v9) = AliasAccountV9::deserialize(&mut &data[8..]) {
+            require!(v9.version == 9, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v9(v9), 9)
+        } else if let Ok(v8) = AliasAccountV8::deserialize(&mut &data[8..]) {
+            require!(v8.version == 8, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v8(v8), 8)
+        } else if let Ok(v7) = AliasAccountV7::deserialize(&mut &data[8..]) {
+            require!(v7.version == 7, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v7(v7), 7)
+        } else if let Ok(v6) = AliasAccountV6::deserialize(&mut &data[8..]) {
+            require!(v6.version == 6, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v6(v6), 6)
+        } else if let Ok(v5) = AliasAccountV5::deserialize(&mut &data[8..]) {
+            require!(v5.version == 5, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v5(v5), 5)
+        } else if let Ok(v4) = AliasAccountV4::deserialize(&mut &data[8..]) {
+            require!(v4.version == 4, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v4(v4), 4)
+        } else if let Ok(v3) = AliasAccountV3::deserialize(&mut &data[8..]) {
+            require!(v3.version == 3, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v3(v3), 3)
+        } else if let Ok(v2) = AliasAccountV2::deserialize(&mut &data[8..]) {
+            require!(v2.version == 2, ErrorCode::UnsupportedAccountVersion);
+            (migrate_v2(v2), 2)
+        } else {
+            let v1 = AliasAccountV1::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::UnsupportedAccountVersion)?;
+            (migrate_v1(v1, Clock::get()?.unix_timestamp), 1)
+        }
+    };
+
+    let new_len = AliasAccount::space(&upgraded.username, &upgraded.product_suffix, &upgraded.chain_info, &upgraded.metadata);
+    resize_account(
+        &alias_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+    upgraded.try_serialize(&mut &mut alias_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AliasAccountUpgraded {
+        alias: alias_info.key(),
+        from_version,
+        to_version: ALIAS_ACCOUNT_VERSION,
+    });
+
+    Ok(())
+}
+
+/// Unversioned aliases predate leases and controllers, so they start a fresh
+/// term with the owner as controller.
+fn migrate_v1(v1: AliasAccountV1, now: i64) -> AliasAccount {
+    AliasAccount {
+        owner: v1.owner,
+        controller: v1.owner,
+        username: v1.username,
+        product_suffix: v1.product_suffix,
+        chain_info: v1.chain_info,
+        reputation: v1.reputation,
+        reputation_updated_at: v1.reputation_updated_at,
+        metadata: v1.metadata,
+        pending_owner: None,
+        expires_at: now + ALIAS_TERM_DURATION,
+        content_hash: [0; 34],
+        is_frozen: false,
+        nonce: 0,
+        owner_managed: true,
+        referrer: None,
+        created_at: 0,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v2(v2: AliasAccountV2) -> AliasAccount {
+    AliasAccount {
+        owner: v2.owner,
+        controller: v2.controller,
+        username: v2.username,
+        product_suffix: v2.product_suffix,
+        chain_info: v2.chain_info,
+        reputation: v2.reputation,
+        reputation_updated_at: v2.reputation_updated_at,
+        metadata: v2.metadata,
+        pending_owner: v2.pending_owner,
+        expires_at: v2.expires_at,
+        content_hash: v2.content_hash,
+        is_frozen: v2.is_frozen,
+        nonce: 0,
+        owner_managed: true,
+        referrer: None,
+        created_at: 0,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+/// Existing aliases keep the product owner control they were registered under.
+fn migrate_v3(v3: AliasAccountV3) -> AliasAccount {
+    AliasAccount {
+        owner: v3.owner,
+        controller: v3.controller,
+        username: v3.username,
+        product_suffix: v3.product_suffix,
+        chain_info: v3.chain_info,
+        reputation: v3.reputation,
+        reputation_updated_at: v3.reputation_updated_at,
+        metadata: v3.metadata,
+        pending_owner: v3.pending_owner,
+        expires_at: v3.expires_at,
+        content_hash: v3.content_hash,
+        is_frozen: v3.is_frozen,
+        nonce: v3.nonce,
+        owner_managed: true,
+        referrer: None,
+        created_at: 0,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v4(v4: AliasAccountV4) -> AliasAccount {
+    AliasAccount {
+        owner: v4.owner,
+        controller: v4.controller,
+        username: v4.username,
+        product_suffix: v4.product_suffix,
+        chain_info: v4.chain_info,
+        reputation: v4.reputation,
+        reputation_updated_at: v4.reputation_updated_at,
+        metadata: v4.metadata,
+        pending_owner: v4.pending_owner,
+        expires_at: v4.expires_at,
+        content_hash: v4.content_hash,
+        is_frozen: v4.is_frozen,
+        nonce: v4.nonce,
+        owner_managed: v4.owner_managed,
+        referrer: None,
+        created_at: 0,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v5(v5: AliasAccountV5) -> AliasAccount {
+    AliasAccount {
+        owner: v5.owner,
+        controller: v5.controller,
+        username: v5.username,
+        product_suffix: v5.product_suffix,
+        chain_info: v5.chain_info,
+        reputation: v5.reputation,
+        reputation_updated_at: v5.reputation_updated_at,
+        metadata: v5.metadata,
+        pending_owner: v5.pending_owner,
+        expires_at: v5.expires_at,
+        content_hash: v5.content_hash,
+        is_frozen: v5.is_frozen,
+        nonce: v5.nonce,
+        owner_managed: v5.owner_managed,
+        referrer: v5.referrer,
+        created_at: 0,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v6(v6: AliasAccountV6) -> AliasAccount {
+    AliasAccount {
+        owner: v6.owner,
+        controller: v6.controller,
+        username: v6.username,
+        product_suffix: v6.product_suffix,
+        chain_info: v6.chain_info,
+        reputation: v6.reputation,
+        reputation_updated_at: v6.reputation_updated_at,
+        metadata: v6.metadata,
+        pending_owner: v6.pending_owner,
+        expires_at: v6.expires_at,
+        content_hash: v6.content_hash,
+        is_frozen: v6.is_frozen,
+        nonce: v6.nonce,
+        owner_managed: v6.owner_managed,
+        referrer: v6.referrer,
+        created_at: v6.created_at,
+        display_name: String::new(),
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v7(v7: AliasAccountV7) -> AliasAccount {
+    AliasAccount {
+        owner: v7.owner,
+        controller: v7.controller,
+        username: v7.username,
+        product_suffix: v7.product_suffix,
+        chain_info: v7.chain_info,
+        reputation: v7.reputation,
+        reputation_updated_at: v7.reputation_updated_at,
+        metadata: v7.metadata,
+        pending_owner: v7.pending_owner,
+        expires_at: v7.expires_at,
+        content_hash: v7.content_hash,
+        is_frozen: v7.is_frozen,
+        nonce: v7.nonce,
+        owner_managed: v7.owner_managed,
+        referrer: v7.referrer,
+        created_at: v7.created_at,
+        display_name: v7.display_name,
+        chain_mappings_root: [0; 32],
+        chain_mapping_count: 0,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v8(v8: AliasAccountV8) -> AliasAccount {
+    AliasAccount {
+        owner: v8.owner,
+        controller: v8.controller,
+        username: v8.username,
+        product_suffix: v8.product_suffix,
+        chain_info: v8.chain_info,
+        reputation: v8.reputation,
+        reputation_updated_at: v8.reputation_updated_at,
+        metadata: v8.metadata,
+        pending_owner: v8.pending_owner,
+        expires_at: v8.expires_at,
+        content_hash: v8.content_hash,
+        is_frozen: v8.is_frozen,
+        nonce: v8.nonce,
+        owner_managed: v8.owner_managed,
+        referrer: v8.referrer,
+        created_at: v8.created_at,
+        display_name: v8.display_name,
+        chain_mappings_root: v8.chain_mappings_root,
+        chain_mapping_count: v8.chain_mapping_count,
+        chain_mappings_version: 0,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v9(v9: AliasAccountV9) -> AliasAccount {
+    AliasAccount {
+        owner: v9.owner,
+        controller: v9.controller,
+        username: v9.username,
+        product_suffix: v9.product_suffix,
+        chain_info: v9.chain_info,
+        reputation: v9.reputation,
+        reputation_updated_at: v9.reputation_updated_at,
+        metadata: v9.metadata,
+        pending_owner: v9.pending_owner,
+        expires_at: v9.expires_at,
+        content_hash: v9.content_hash,
+        is_frozen: v9.is_frozen,
+        nonce: v9.nonce,
+        owner_managed: v9.owner_managed,
+        referrer: v9.referrer,
+        created_at: v9.created_at,
+        display_name: v9.display_name,
+        chain_mappings_root: v9.chain_mappings_root,
+        chain_mapping_count: v9.chain_mapping_count,
+        chain_mappings_version: v9.chain_mappings_version,
+        reputation_window_start: 0,
+        reputation_window_change: 0,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v10(v10: AliasAccountV10) -> AliasAccount {
+    AliasAccount {
+        owner: v10.owner,
+        controller: v10.controller,
+        username: v10.username,
+        product_suffix: v10.product_suffix,
+        chain_info: v10.chain_info,
+        reputation: v10.reputation,
+        reputation_updated_at: v10.reputation_updated_at,
+        metadata: v10.metadata,
+        pending_owner: v10.pending_owner,
+        expires_at: v10.expires_at,
+        content_hash: v10.content_hash,
+        is_frozen: v10.is_frozen,
+        nonce: v10.nonce,
+        owner_managed: v10.owner_managed,
+        referrer: v10.referrer,
+        created_at: v10.created_at,
+        display_name: v10.display_name,
+        chain_mappings_root: v10.chain_mappings_root,
+        chain_mapping_count: v10.chain_mapping_count,
+        chain_mappings_version: v10.chain_mappings_version,
+        reputation_window_start: v10.reputation_window_start,
+        reputation_window_change: v10.reputation_window_change,
+        category_reputation: [0; MAX_REPUTATION_CATEGORIES],
+        category_reputation_updated_at: [0; MAX_REPUTATION_CATEGORIES],
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v11(v11: AliasAccountV11) -> AliasAccount {
+    AliasAccount {
+        owner: v11.owner,
+        controller: v11.controller,
+        username: v11.username,
+        product_suffix: v11.product_suffix,
+        chain_info: v11.chain_info,
+        reputation: v11.reputation,
+        reputation_updated_at: v11.reputation_updated_at,
+        metadata: v11.metadata,
+        pending_owner: v11.pending_owner,
+        expires_at: v11.expires_at,
+        content_hash: v11.content_hash,
+        is_frozen: v11.is_frozen,
+        nonce: v11.nonce,
+        owner_managed: v11.owner_managed,
+        referrer: v11.referrer,
+        created_at: v11.created_at,
+        display_name: v11.display_name,
+        chain_mappings_root: v11.chain_mappings_root,
+        chain_mapping_count: v11.chain_mapping_count,
+        chain_mappings_version: v11.chain_mappings_version,
+        reputation_window_start: v11.reputation_window_start,
+        reputation_window_change: v11.reputation_window_change,
+        category_reputation: v11.category_reputation,
+        category_reputation_updated_at: v11.category_reputation_updated_at,
+        escrowed_reputation: 0,
+        escrowed_at: 0,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v12(v12: AliasAccountV12) -> AliasAccount {
+    AliasAccount {
+        owner: v12.owner,
+        controller: v12.controller,
+        username: v12.username,
+        product_suffix: v12.product_suffix,
+        chain_info: v12.chain_info,
+        reputation: v12.reputation,
+        reputation_updated_at: v12.reputation_updated_at,
+        metadata: v12.metadata,
+        pending_owner: v12.pending_owner,
+        expires_at: v12.expires_at,
+        content_hash: v12.content_hash,
+        is_frozen: v12.is_frozen,
+        nonce: v12.nonce,
+        owner_managed: v12.owner_managed,
+        referrer: v12.referrer,
+        created_at: v12.created_at,
+        display_name: v12.display_name,
+        chain_mappings_root: v12.chain_mappings_root,
+        chain_mapping_count: v12.chain_mapping_count,
+        chain_mappings_version: v12.chain_mappings_version,
+        reputation_window_start: v12.reputation_window_start,
+        reputation_window_change: v12.reputation_window_change,
+        category_reputation: v12.category_reputation,
+        category_reputation_updated_at: v12.category_reputation_updated_at,
+        escrowed_reputation: v12.escrowed_reputation,
+        escrowed_at: v12.escrowed_at,
+        verification_rewarded_at: 0,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
+
+fn migrate_v13(v13: AliasAccountV13) -> AliasAccount {
+    AliasAccount {
+        owner: v13.owner,
+        controller: v13.controller,
+        username: v13.username,
+        product_suffix: v13.product_suffix,
+        chain_info: v13.chain_info,
+        reputation: v13.reputation,
+        reputation_updated_at: v13.reputation_updated_at,
+        metadata: v13.metadata,
+        pending_owner: v13.pending_owner,
+        expires_at: v13.expires_at,
+        content_hash: v13.content_hash,
+        is_frozen: v13.is_frozen,
+        nonce: v13.nonce,
+        owner_managed: v13.owner_managed,
+        referrer: v13.referrer,
+        created_at: v13.created_at,
+        display_name: v13.display_name,
+        chain_mappings_root: v13.chain_mappings_root,
+        chain_mapping_count: v13.chain_mapping_count,
+        chain_mappings_version: v13.chain_mappings_version,
+        reputation_window_start: v13.reputation_window_start,
+        reputation_window_change: v13.reputation_window_change,
+        category_reputation: v13.category_reputation,
+        category_reputation_updated_at: v13.category_reputation_updated_at,
+        escrowed_reputation: v13.escrowed_reputation,
+        escrowed_at: v13.escrowed_at,
+        verification_rewarded_at: v13.verification_rewarded_at,
+        is_quarantined: false,
+        quarantined_at: 0,
+        version: ALIAS_ACCOUNT_VERSION,
+    }
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::instructions::set_record::resize_account;
+
+#[derive(Accounts)]
+pub struct UpgradeSuffixAccount<'info> {
+    /// Anyone may upgrade a suffix; the payer covers any additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Deserialized manually since older layouts don't match `SuffixAccount`.
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::InvalidDerivedAccount
+    )]
+    pub suffix_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Space of the `SuffixAccount` fields before `suffix`, in declaration
+/// order. Each layout so far inserted its fields just before `suffix`, so
+/// an older account is the current one cut off after its first few fields,
+/// followed by the suffix.
+const SUFFIX_ACCOUNT_FIELDS: [usize; 15] = [32, 1, 1, 8, 1, 1, 1, 2, 8, 1, 8, 33, 1, 8, 33];
+
+/// Field counts of the layouts that shipped before the current one, newest
+/// first.
+const LEGACY_SUFFIX_LAYOUTS: [usize; 11] = [14, 13, 12, 11, 9, 8, 6, 5, 4, 3, 2];
+
+/// Reads a `SuffixAccount` written with only its first `fields` fields,
+/// if `data` is exactly the size such an account was created with. Missing
+/// fields take the defaults of a newly registered suffix, starting a fresh
+/// lease.
+fn read_legacy(data: &[u8], fields: usize, now: i64) -> Option<SuffixAccount> {
+    let mut account = SuffixAccount {
+        owner: Pubkey::default(),
+        is_active: true,
+        transfer_requires_cosign: false,
+        renewal_fee: 0,
+        require_commitment: false,
+        require_registration_approval: false,
+        transferable: false,
+        royalty_bps: 0,
+        transfer_lock_duration: 0,
+        is_frozen: false,
+        deactivated_at: 0,
+        pending_owner: None,
+        is_paused: false,
+        expires_at: now + SUFFIX_LEASE_DURATION,
+        parent: None,
+        suffix: String::new(),
+    };
+    let cur = &mut &data[8..];
+    account.owner = Pubkey::deserialize(cur).ok()?;
+    account.is_active = bool::deserialize(cur).ok()?;
+    if fields > 2 {
+        account.transfer_requires_cosign = bool::deserialize(cur).ok()?;
+    }
+    if fields > 3 {
+        account.renewal_fee = u64::deserialize(cur).ok()?;
+    }
+    if fields > 4 {
+        account.require_commitment = bool::deserialize(cur).ok()?;
+    }
+    if fields > 5 {
+        account.require_registration_approval = bool::deserialize(cur).ok()?;
+    }
+    if fields > 6 {
+        account.transferable = bool::deserialize(cur).ok()?;
+        account.royalty_bps = u16::deserialize(cur).ok()?;
+    }
+    if fields > 8 {
+        account.transfer_lock_duration = i64::deserialize(cur).ok()?;
+    }
+    if fields > 9 {
+        account.is_frozen = bool::deserialize(cur).ok()?;
+        account.deactivated_at = i64::deserialize(cur).ok()?;
+    }
+    if fields > 11 {
+        account.pending_owner = Option::<Pubkey>::deserialize(cur).ok()?;
+    }
+    if fields > 12 {
+        account.is_paused = bool::deserialize(cur).ok()?;
+    }
+    if fields > 13 {
+        account.expires_at = i64::deserialize(cur).ok()?;
+    }
+    account.suffix = String::deserialize(cur).ok()?;
+
+    let size = 8 + SUFFIX_ACCOUNT_FIELDS[..fields].iter().sum::<usize>() + 4 + account.suffix.len();
+    (size == data.len()).then_some(account)
+}
+
+/// Rewrites a `SuffixAccount` created before its newer fields existed.
+pub fn handler(ctx: Context<UpgradeSuffixAccount>) -> Result<()> {
+    let suffix_info = ctx.accounts.suffix_account.to_account_info();
+    let from_len = suffix_info.data_len();
+
+    let upgraded = {
+        let data = suffix_info.try_borrow_data()?;
+        require!(data.len() >= 8 && data[..8] == SuffixAccount::DISCRIMINATOR, ErrorCode::UnsupportedAccountVersion);
+
+        if let Ok(current) = SuffixAccount::try_deserialize(&mut &data[..]) {
+            require!(SuffixAccount::space(&current.suffix) != data.len(), ErrorCode::AccountLayoutUpToDate);
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        LEGACY_SUFFIX_LAYOUTS
+            .iter()
+            .find_map(|&fields| read_legacy(&data, fields, now))
+            .ok_or(ErrorCode::UnsupportedAccountVersion)?
+    };
+
+    let new_len = SuffixAccount::space(&upgraded.suffix);
+    resize_account(
+        &suffix_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+    upgraded.try_serialize(&mut &mut suffix_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountLayoutUpgraded {
+        account: suffix_info.key(),
+        from_len: from_len as u32,
+        to_len: new_len as u32,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use crate::{ed25519::verify_ed25519_signature, error::EchoIDError as ErrorCode, instructions::update_reputation::emit_reputation_changed, state::*};
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct VerifyAliasOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"keys", alias_account.key().as_ref()],
+        bump
+    )]
+    pub alias_keys: Option<Account<'info, AliasKeys>>,
+    /// A session key scoped for verification may also sign the proof.
+    #[account(
+        constraint = session.alias == alias_account.key() @ ErrorCode::Unauthorized
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    /// CHECK: The suffix's `ReputationConfig`, read through
+    /// `ReputationConfig::load_or_default` since it may not exist.
+    #[account(
+        seeds = [b"reputation_config", project_suffix.as_bytes()],
+        bump
+    )]
+    pub reputation_config: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar, used to find the ed25519 proof.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Succeeds if the transaction carries an ed25519 signature over the alias
+/// authorization message for `challenge` by the alias owner, any of its keys
+/// registered for `purpose`, or an unexpired verification session key, and
+/// returns the key that signed. The proof consumes the alias nonce, so it
+/// cannot be submitted twice. A successful proof may also earn the suffix's
+/// verification reward, subject to its cooldown.
+pub fn handler(ctx: Context<VerifyAliasOwnership>, _username: String, _project_suffix: String, _chain_name: String, purpose: KeyPurpose, challenge: [u8; 32]) -> Result<Pubkey> {
+    let alias_key = ctx.accounts.alias_account.key();
+    let alias_account = &mut ctx.accounts.alias_account;
+    let now = Clock::get()?.unix_timestamp;
+    alias_account.require_usable(now)?;
+    alias_account.require_not_quarantined()?;
+
+    let message = alias_account.authorization_message(&alias_key, &challenge);
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let delegated = ctx.accounts.alias_keys.iter().flat_map(|k| k.owner_keys_for(&alias_account.owner, purpose));
+    let session = ctx.accounts.session.iter().filter(|s| s.allows(&alias_account.owner, SESSION_SCOPE_VERIFY, now)).map(|s| &s.key);
+    let signer = std::iter::once(&alias_account.owner)
+        .chain(delegated)
+        .chain(session)
+        .find(|key| verify_ed25519_signature(&instructions, key, &message).is_ok())
+        .copied()
+        .ok_or(ErrorCode::SignatureVerificationFailed)?;
+
+    alias_account.advance_nonce();
+
+    let config = ReputationConfig::load_or_default(&ctx.accounts.reputation_config)?;
+    let stored = alias_account.reputation;
+    let change = alias_account.reward_verification(&config, now);
+    if change != 0 {
+        let reputation = alias_account.reputation;
+        emit_reputation_changed(alias_key, 0, stored, reputation - change, reputation, alias_key, VERIFICATION_REWARD_REASON);
+        emit!(VerificationRewarded {
+            alias: alias_key,
+            change,
+            reputation: alias_account.reputation,
+        });
+    }
+    Ok(signer)
+}
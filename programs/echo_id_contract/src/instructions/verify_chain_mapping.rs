@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::merkle::LeafCodec;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct VerifyChainMapping<'info> {
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Returns, as return data, whether `proof` shows `mapping` is in a usable
+/// alias's tree under its current root or one of the admin-configured number
+/// of recent roots, and whether its attestation has lapsed. Only fails for a
+/// proof built against another leaf encoding, so callers can simulate it.
+pub fn handler(ctx: Context<VerifyChainMapping>, _username: String, _project_suffix: String, _chain_name: String, mapping: AttestedChainMapping, proof: ChainMappingProof) -> Result<ChainMappingVerification> {
+    LeafCodec::require_version(proof.leaf_version)?;
+    let now = Clock::get()?.unix_timestamp;
+    let depth = ctx.accounts.admin_config.root_history_depth as usize;
+    Ok(ctx.accounts.chain_mapping_tree.verify(&ctx.accounts.alias_account, &mapping, &proof, depth, now))
+}
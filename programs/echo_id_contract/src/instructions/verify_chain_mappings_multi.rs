@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::merkle::LeafCodec;
+
+#[derive(Accounts)]
+#[instruction(username: String, project_suffix: String, chain_name: String)]
+pub struct VerifyChainMappingsMulti<'info> {
+    #[account(
+        seeds = [username.as_bytes(), b"@", project_suffix.as_bytes(), chain_name.as_bytes()],
+        bump,
+    )]
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"mapping_tree", alias_account.key().as_ref()],
+        bump
+    )]
+    pub chain_mapping_tree: Account<'info, ChainMappingTree>,
+    #[account(
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Like `verify_chain_mapping`, for several `mappings` proven together by
+/// one multiproof, in the order of `proof.indices`. Valid only if every
+/// mapping is.
+pub fn handler(ctx: Context<VerifyChainMappingsMulti>, _username: String, _project_suffix: String, _chain_name: String, mappings: Vec<AttestedChainMapping>, proof: ChainMappingMultiProof) -> Result<ChainMappingVerification> {
+    LeafCodec::require_version(proof.leaf_version)?;
+    let now = Clock::get()?.unix_timestamp;
+    let depth = ctx.accounts.admin_config.root_history_depth as usize;
+    Ok(ctx.accounts.chain_mapping_tree.verify_multi(&ctx.accounts.alias_account, &mappings, &proof, depth, now))
+}
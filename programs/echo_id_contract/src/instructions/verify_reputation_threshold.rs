@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::zkp::{threshold_public_inputs, verify_groth16, Groth16Proof};
+
+#[derive(Accounts)]
+#[instruction(epoch: u64, threshold: u64)]
+pub struct VerifyReputationThreshold<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub alias_account: Account<'info, AliasAccount>,
+    #[account(
+        seeds = [b"reputation_snapshot", alias_account.product_suffix.as_bytes(), &epoch.to_le_bytes()],
+        bump
+    )]
+    pub reputation_snapshot: Account<'info, ReputationSnapshot>,
+    #[account(
+        seeds = [b"reputation_verifying_key"],
+        bump
+    )]
+    pub reputation_verifying_key: Box<Account<'info, ReputationVerifyingKey>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReputationThresholdReceipt::SPACE,
+        seeds = [b"threshold_receipt", alias_account.key().as_ref(), &threshold.to_le_bytes()],
+        bump
+    )]
+    pub receipt: Account<'info, ReputationThresholdReceipt>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Checks a zero-knowledge proof that the alias scored at least `threshold`
+/// in its suffix's snapshot for `epoch`, without the score itself, and
+/// records it in the alias's receipt for that threshold. A receipt only
+/// moves forward to later epochs.
+pub fn handler(ctx: Context<VerifyReputationThreshold>, epoch: u64, threshold: u64, proof: Groth16Proof) -> Result<()> {
+    let alias = ctx.accounts.alias_account.key();
+    let inputs = threshold_public_inputs(&alias, &ctx.accounts.reputation_snapshot.root, threshold);
+    require!(
+        verify_groth16(&ctx.accounts.reputation_verifying_key.verifying_key, &proof, &inputs, ErrorCode::InvalidThresholdProof)?,
+        ErrorCode::InvalidThresholdProof
+    );
+
+    let receipt = &mut ctx.accounts.receipt;
+    require!(receipt.alias == Pubkey::default() || epoch >= receipt.epoch, ErrorCode::InvalidThresholdProof);
+    receipt.alias = alias;
+    receipt.threshold = threshold;
+    receipt.epoch = epoch;
+    receipt.verified_at = Clock::get()?.unix_timestamp;
+
+    emit!(ReputationThresholdVerified {
+        alias,
+        threshold,
+        epoch,
+    });
+
+    Ok(())
+}
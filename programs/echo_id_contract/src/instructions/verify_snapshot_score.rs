@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::merkle::verify_proof;
+
+#[derive(Accounts)]
+#[instruction(suffix: String, epoch: u64)]
+pub struct VerifySnapshotScore<'info> {
+    #[account(
+        seeds = [b"reputation_snapshot", suffix.as_bytes(), &epoch.to_le_bytes()],
+        bump
+    )]
+    pub reputation_snapshot: Account<'info, ReputationSnapshot>,
+}
+
+/// Returns, as return data, whether `proof` shows `alias` scored `score` in
+/// the suffix's snapshot for `epoch`.
+pub fn handler(ctx: Context<VerifySnapshotScore>, _suffix: String, _epoch: u64, alias: Pubkey, score: i64, proof: Vec<[u8; 32]>) -> Result<bool> {
+    Ok(verify_proof(&proof, &ctx.accounts.reputation_snapshot.root, ReputationSnapshot::leaf(&alias, score)))
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    /// CHECK: Only used to derive the vault and stake addresses.
+    pub alias: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"reputation_vault", alias.key().as_ref()],
+        bump
+    )]
+    pub reputation_vault: Account<'info, ReputationVault>,
+    #[account(
+        mut,
+        close = staker,
+        seeds = [b"reputation_stake", alias.key().as_ref(), staker.key().as_ref()],
+        bump
+    )]
+    pub reputation_stake: Account<'info, ReputationStake>,
+}
+
+/// Pays out what the stake's shares are worth after any slashing, once its
+/// cooldown has passed.
+pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
+    let stake = &ctx.accounts.reputation_stake;
+    let now = Clock::get()?.unix_timestamp;
+    require!(stake.unlocks_at != 0 && now >= stake.unlocks_at, ErrorCode::StakeLocked);
+
+    let vault = &mut ctx.accounts.reputation_vault;
+    let amount = vault.value_of(stake.shares);
+    vault.total_staked -= amount;
+    vault.total_shares -= stake.shares;
+
+    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.staker.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(ReputationStakeWithdrawn {
+        alias: stake.alias,
+        staker: stake.staker,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::EchoIDError as ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(suffix: String)]
+pub struct WithdrawSuffixTreasury<'info> {
+    #[account(mut)]
+    pub product_owner: Signer<'info>,
+    #[account(
+        seeds = [b"suffix", suffix.as_bytes()],
+        bump,
+        constraint = suffix_account.owner == product_owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub suffix_account: Account<'info, SuffixAccount>,
+    #[account(
+        mut,
+        seeds = [b"suffix_treasury", suffix.as_bytes()],
+        bump
+    )]
+    pub suffix_treasury: Account<'info, SuffixTreasury>,
+}
+
+/// Pays `amount` of the suffix's collected registration fees to the product
+/// owner, leaving the treasury rent-exempt.
+pub fn handler(ctx: Context<WithdrawSuffixTreasury>, suffix: String, amount: u64) -> Result<()> {
+    let treasury = ctx.accounts.suffix_treasury.to_account_info();
+    let available = treasury.lamports().saturating_sub(Rent::get()?.minimum_balance(treasury.data_len()));
+    require!(amount <= available, ErrorCode::InsufficientTreasuryBalance);
+
+    **treasury.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.product_owner.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(SuffixTreasuryWithdrawn {
+        suffix,
+        owner: ctx.accounts.product_owner.key(),
+        amount,
+    });
+
+    Ok(())
+}
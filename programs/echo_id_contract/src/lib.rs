@@ -3,8 +3,19 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod error;
 pub mod instructions;
+pub mod validation;
+pub mod ed25519;
+pub mod secp256k1;
+pub mod fee_token;
+pub mod merkle;
+pub mod zkp;
+#[cfg(feature = "cpi")]
+pub mod echo_id_cpi;
 
 use instructions::*;
+use merkle::MerkleHash;
+use zkp::{Groth16Proof, Groth16VerifyingKey};
+use state::{AliasMetadata, AttestedChainMapping, ChainMapping, ChainMappingMultiProof, ChainMappingProof, ChainMappingVerification, KeyPurpose, ProductOwnerBranding};
 
 declare_id!("5zFfHUucGgrkRuCAvEE2QSr5aoXrZmeARULQZ5k3YKy");
 
@@ -25,8 +36,500 @@ pub mod echo_id_contract {
     }
 
 
-    pub fn update_reputation(ctx: Context<UpdateReputation>, username: String, project_suffix: String, chain_name:String,  change: i64) -> Result<()> {
-        instructions::update_reputation::handler(ctx, username, project_suffix, chain_name, change)
+    pub fn update_reputation(ctx: Context<UpdateReputation>, username: String, project_suffix: String, chain_name:String,  change: i64, reason: u16, category: u8) -> Result<()> {
+        instructions::update_reputation::handler(ctx, username, project_suffix, chain_name, change, reason, category)
+    }
+
+    pub fn transfer_alias(ctx: Context<TransferAlias>, username: String, project_suffix: String, chain_name: String, new_owner: Pubkey) -> Result<()> {
+        instructions::transfer_alias::handler(ctx, username, project_suffix, chain_name, new_owner)
+    }
+
+    pub fn set_suffix_policy(ctx: Context<SetSuffixPolicy>, suffix: String, params: set_suffix_policy::SuffixPolicyParams) -> Result<()> {
+        instructions::set_suffix_policy::handler(ctx, suffix, params)
+    }
+
+    pub fn propose_transfer(ctx: Context<ProposeTransfer>, username: String, project_suffix: String, chain_name: String, new_owner: Pubkey) -> Result<()> {
+        instructions::propose_transfer::handler(ctx, username, project_suffix, chain_name, new_owner)
+    }
+
+    pub fn accept_transfer(ctx: Context<AcceptTransfer>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::accept_transfer::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn cancel_transfer(ctx: Context<CancelTransfer>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::cancel_transfer::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn close_alias(ctx: Context<CloseAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::close_alias::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn renew_alias(ctx: Context<RenewAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::renew_alias::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn reclaim_expired_alias(ctx: Context<ReclaimExpiredAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::reclaim_expired_alias::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn rename_alias(ctx: Context<RenameAlias>, username: String, project_suffix: String, chain_name: String, new_username: String) -> Result<()> {
+        instructions::rename_alias::handler(ctx, username, project_suffix, chain_name, new_username)
+    }
+
+    pub fn register_aliases_batch<'info>(ctx: Context<'_, '_, '_, 'info, RegisterAliasesBatch<'info>>, suffix: String, params: Vec<register_alias::RegisterAliasParams>) -> Result<()> {
+        instructions::register_aliases_batch::handler(ctx, suffix, params)
+    }
+
+    pub fn commit_alias(ctx: Context<CommitAlias>, commitment: [u8; 32]) -> Result<()> {
+        instructions::commit_alias::handler(ctx, commitment)
+    }
+
+    pub fn reserve_name(ctx: Context<ReserveName>, suffix: String, name_hash: [u8; 32]) -> Result<()> {
+        instructions::reserve_name::handler(ctx, suffix, name_hash)
+    }
+
+    pub fn release_name(ctx: Context<ReleaseName>, suffix: String, name_hash: [u8; 32]) -> Result<()> {
+        instructions::release_name::handler(ctx, suffix, name_hash)
+    }
+
+    pub fn set_primary_alias(ctx: Context<SetPrimaryAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::set_primary_alias::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn set_controller(ctx: Context<SetController>, username: String, project_suffix: String, chain_name: String, controller: Pubkey) -> Result<()> {
+        instructions::set_controller::handler(ctx, username, project_suffix, chain_name, controller)
+    }
+
+    pub fn update_alias_metadata(ctx: Context<UpdateAliasMetadata>, username: String, project_suffix: String, chain_name: String, metadata: AliasMetadata) -> Result<()> {
+        instructions::update_alias_metadata::handler(ctx, username, project_suffix, chain_name, metadata)
+    }
+
+    pub fn set_record(ctx: Context<SetRecord>, username: String, project_suffix: String, chain_name: String, key: String, value: String) -> Result<()> {
+        instructions::set_record::handler(ctx, username, project_suffix, chain_name, key, value)
+    }
+
+    pub fn delete_record(ctx: Context<DeleteRecord>, username: String, project_suffix: String, chain_name: String, key: String) -> Result<()> {
+        instructions::delete_record::handler(ctx, username, project_suffix, chain_name, key)
+    }
+
+    pub fn set_content_hash(ctx: Context<SetContentHash>, username: String, project_suffix: String, chain_name: String, content_hash: [u8; 34]) -> Result<()> {
+        instructions::set_content_hash::handler(ctx, username, project_suffix, chain_name, content_hash)
+    }
+
+    pub fn register_alias_with_voucher(ctx: Context<RegisterAliasWithVoucher>, params: register_alias::RegisterAliasParams, voucher: register_alias_with_voucher::RegistrationVoucher) -> Result<()> {
+        instructions::register_alias_with_voucher::handler(ctx, params, voucher)
+    }
+
+    pub fn freeze_alias(ctx: Context<FreezeAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::freeze_alias::freeze_handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn unfreeze_alias(ctx: Context<FreezeAlias>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::freeze_alias::unfreeze_handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn migrate_alias_suffix(ctx: Context<MigrateAliasSuffix>, username: String, project_suffix: String, chain_name: String, new_suffix: String) -> Result<()> {
+        instructions::migrate_alias_suffix::handler(ctx, username, project_suffix, chain_name, new_suffix)
+    }
+
+    pub fn upgrade_alias_account(ctx: Context<UpgradeAliasAccount>) -> Result<()> {
+        instructions::upgrade_alias_account::handler(ctx)
+    }
+
+    pub fn rotate_owner_key(ctx: Context<RotateOwnerKey>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::rotate_owner_key::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn set_guardians(ctx: Context<SetGuardians>, username: String, project_suffix: String, chain_name: String, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::set_guardians::handler(ctx, username, project_suffix, chain_name, guardians, threshold)
+    }
+
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>, username: String, project_suffix: String, chain_name: String, new_owner: Pubkey) -> Result<()> {
+        instructions::initiate_recovery::handler(ctx, username, project_suffix, chain_name, new_owner)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::approve_recovery::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::execute_recovery::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn register_sub_alias(ctx: Context<RegisterSubAlias>, username: String, project_suffix: String, chain_name: String, params: register_sub_alias::RegisterSubAliasParams) -> Result<()> {
+        instructions::register_sub_alias::handler(ctx, username, project_suffix, chain_name, params)
+    }
+
+    pub fn close_sub_alias(ctx: Context<CloseSubAlias>, username: String, project_suffix: String, chain_name: String, label: String) -> Result<()> {
+        instructions::close_sub_alias::handler(ctx, username, project_suffix, chain_name, label)
+    }
+
+    pub fn check_availability(ctx: Context<CheckAvailability>, username: String, suffix: String, chain_name: String) -> Result<check_availability::Availability> {
+        instructions::check_availability::handler(ctx, username, suffix, chain_name)
+    }
+
+    pub fn mint_alias_token(ctx: Context<MintAliasToken>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::mint_alias_token::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn list_alias(ctx: Context<ListAlias>, username: String, project_suffix: String, chain_name: String, price: u64) -> Result<()> {
+        instructions::list_alias::handler(ctx, username, project_suffix, chain_name, price)
+    }
+
+    pub fn buy_alias(ctx: Context<BuyAlias>, username: String, project_suffix: String, chain_name: String, price: u64) -> Result<()> {
+        instructions::buy_alias::handler(ctx, username, project_suffix, chain_name, price)
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::cancel_listing::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn add_key(ctx: Context<AddKey>, username: String, project_suffix: String, chain_name: String, purpose: KeyPurpose, key: Pubkey) -> Result<()> {
+        instructions::add_key::handler(ctx, username, project_suffix, chain_name, purpose, key)
+    }
+
+    pub fn remove_key(ctx: Context<RemoveKey>, username: String, project_suffix: String, chain_name: String, purpose: KeyPurpose, key: Pubkey) -> Result<()> {
+        instructions::remove_key::handler(ctx, username, project_suffix, chain_name, purpose, key)
+    }
+
+    pub fn verify_alias_ownership(ctx: Context<VerifyAliasOwnership>, username: String, project_suffix: String, chain_name: String, purpose: KeyPurpose, challenge: [u8; 32]) -> Result<Pubkey> {
+        instructions::verify_alias_ownership::handler(ctx, username, project_suffix, chain_name, purpose, challenge)
+    }
+
+    pub fn increment_nonce(ctx: Context<IncrementNonce>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::increment_nonce::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn create_session(ctx: Context<CreateSession>, username: String, project_suffix: String, chain_name: String, session_key: Pubkey, scope: u8, expires_at: i64) -> Result<()> {
+        instructions::create_session::handler(ctx, username, project_suffix, chain_name, session_key, scope, expires_at)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>, username: String, project_suffix: String, chain_name: String, session_key: Pubkey) -> Result<()> {
+        instructions::revoke_session::handler(ctx, username, project_suffix, chain_name, session_key)
+    }
+
+    pub fn link_aliases(ctx: Context<LinkAliases>) -> Result<()> {
+        instructions::link_aliases::handler(ctx)
+    }
+
+    pub fn unlink_aliases(ctx: Context<UnlinkAliases>) -> Result<()> {
+        instructions::unlink_aliases::handler(ctx)
+    }
+
+    pub fn renounce_product_owner_control(ctx: Context<RenounceProductOwnerControl>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::renounce_product_owner_control::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn commit_import_root(ctx: Context<CommitImportRoot>, suffix: String, root: [u8; 32]) -> Result<()> {
+        instructions::commit_import_root::handler(ctx, suffix, root)
+    }
+
+    pub fn claim_imported_alias(ctx: Context<ClaimImportedAlias>, suffix: String, leaf: claim_imported_alias::ImportedAlias, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_imported_alias::handler(ctx, suffix, leaf, proof)
+    }
+
+    pub fn set_display_name(ctx: Context<SetDisplayName>, username: String, project_suffix: String, chain_name: String, display_name: String) -> Result<()> {
+        instructions::set_display_name::handler(ctx, username, project_suffix, chain_name, display_name)
+    }
+
+    pub fn add_chain_mapping(ctx: Context<AddChainMapping>, username: String, project_suffix: String, chain_name: String, mapping: ChainMapping) -> Result<()> {
+        instructions::add_chain_mapping::handler(ctx, username, project_suffix, chain_name, mapping)
+    }
+
+    pub fn add_chain_mappings_batch(ctx: Context<AddChainMapping>, username: String, project_suffix: String, chain_name: String, added: Vec<ChainMapping>) -> Result<()> {
+        instructions::add_chain_mapping::batch_handler(ctx, username, project_suffix, chain_name, added)
+    }
+
+    pub fn add_chain_mapping_with_proof(ctx: Context<AddChainMappingWithProof>, username: String, project_suffix: String, chain_name: String, mapping: ChainMapping) -> Result<()> {
+        instructions::add_chain_mapping_with_proof::handler(ctx, username, project_suffix, chain_name, mapping)
+    }
+
+    pub fn remove_chain_mapping(ctx: Context<RemoveChainMapping>, username: String, project_suffix: String, chain_name: String, mapping: AttestedChainMapping, proof: ChainMappingProof) -> Result<()> {
+        instructions::remove_chain_mapping::handler(ctx, username, project_suffix, chain_name, mapping, proof)
+    }
+
+    pub fn update_chain_mapping(ctx: Context<UpdateChainMapping>, username: String, project_suffix: String, chain_name: String, old: AttestedChainMapping, new_address: Vec<u8>, proof: ChainMappingProof) -> Result<()> {
+        instructions::update_chain_mapping::handler(ctx, username, project_suffix, chain_name, old, new_address, proof)
+    }
+
+    pub fn verify_chain_mapping(ctx: Context<VerifyChainMapping>, username: String, project_suffix: String, chain_name: String, mapping: AttestedChainMapping, proof: ChainMappingProof) -> Result<ChainMappingVerification> {
+        instructions::verify_chain_mapping::handler(ctx, username, project_suffix, chain_name, mapping, proof)
+    }
+
+    pub fn set_root_history_depth(ctx: Context<SetRootHistoryDepth>, depth: u8) -> Result<()> {
+        instructions::set_root_history_depth::handler(ctx, depth)
+    }
+
+    pub fn create_chain_mapping_registry(ctx: Context<CreateChainMappingRegistry>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::create_chain_mapping_registry::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn reattest_chain_mapping(ctx: Context<ReattestChainMapping>, username: String, project_suffix: String, chain_name: String, mapping: AttestedChainMapping, proof: ChainMappingProof) -> Result<()> {
+        instructions::reattest_chain_mapping::handler(ctx, username, project_suffix, chain_name, mapping, proof)
+    }
+
+    pub fn set_chain_mapping_hash(ctx: Context<SetChainMappingHash>, username: String, project_suffix: String, chain_name: String, hash: MerkleHash) -> Result<()> {
+        instructions::set_chain_mapping_hash::handler(ctx, username, project_suffix, chain_name, hash)
+    }
+
+    pub fn set_suffix_config(ctx: Context<SetSuffixConfig>, suffix: String, params: set_suffix_config::SuffixConfigParams) -> Result<()> {
+        instructions::set_suffix_config::handler(ctx, suffix, params)
+    }
+
+    pub fn verify_chain_mappings_multi(ctx: Context<VerifyChainMappingsMulti>, username: String, project_suffix: String, chain_name: String, mappings: Vec<AttestedChainMapping>, proof: ChainMappingMultiProof) -> Result<ChainMappingVerification> {
+        instructions::verify_chain_mappings_multi::handler(ctx, username, project_suffix, chain_name, mappings, proof)
+    }
+
+    pub fn get_effective_reputation<'info>(ctx: Context<'_, '_, '_, 'info, GetEffectiveReputation<'info>>, username: String, project_suffix: String, chain_name: String, category: u8) -> Result<i64> {
+        instructions::get_effective_reputation::handler(ctx, username, project_suffix, chain_name, category)
+    }
+
+    pub fn add_reputation_authority(ctx: Context<AddReputationAuthority>, suffix: String, authority: Pubkey, weight_bps: u16) -> Result<()> {
+        instructions::add_reputation_authority::handler(ctx, suffix, authority, weight_bps)
+    }
+
+    pub fn remove_reputation_authority(ctx: Context<RemoveReputationAuthority>, suffix: String, authority: Pubkey) -> Result<()> {
+        instructions::remove_reputation_authority::handler(ctx, suffix, authority)
+    }
+
+    pub fn update_reputation_batch<'info>(ctx: Context<'_, '_, '_, 'info, UpdateReputationBatch<'info>>, project_suffix: String, updates: Vec<update_reputation::ReputationUpdate>) -> Result<()> {
+        instructions::update_reputation_batch::handler(ctx, project_suffix, updates)
+    }
+
+    pub fn set_reputation_category(ctx: Context<SetReputationCategory>, suffix: String, category: u8, name: String, half_life: i64) -> Result<()> {
+        instructions::set_reputation_category::handler(ctx, suffix, category, name, half_life)
+    }
+
+    pub fn endorse_alias(ctx: Context<EndorseAlias>, weight: u16, expires_at: i64) -> Result<()> {
+        instructions::endorse_alias::handler(ctx, weight, expires_at)
+    }
+
+    pub fn revoke_endorsement(ctx: Context<RevokeEndorsement>) -> Result<()> {
+        instructions::revoke_endorsement::handler(ctx)
+    }
+
+    pub fn stake_for_reputation(ctx: Context<StakeForReputation>, amount: u64) -> Result<()> {
+        instructions::stake_for_reputation::handler(ctx, amount)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
+        instructions::unstake::handler(ctx)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::withdraw_stake::handler(ctx)
+    }
+
+    pub fn add_reputation_slasher(ctx: Context<AddReputationSlasher>, suffix: String, slasher: Pubkey) -> Result<()> {
+        instructions::add_reputation_slasher::handler(ctx, suffix, slasher)
+    }
+
+    pub fn remove_reputation_slasher(ctx: Context<RemoveReputationSlasher>, suffix: String, slasher: Pubkey) -> Result<()> {
+        instructions::remove_reputation_slasher::handler(ctx, suffix, slasher)
+    }
+
+    pub fn slash_reputation(ctx: Context<SlashReputation>, username: String, project_suffix: String, chain_name: String, slash_bps: u16, evidence_hash: [u8; 32]) -> Result<()> {
+        instructions::slash_reputation::handler(ctx, username, project_suffix, chain_name, slash_bps, evidence_hash)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>, history_index: u8) -> Result<()> {
+        instructions::open_dispute::handler(ctx, history_index)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, revert: bool) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, revert)
+    }
+
+    pub fn claim_badge<'info>(ctx: Context<'_, '_, '_, 'info, ClaimBadge<'info>>) -> Result<()> {
+        instructions::claim_badge::handler(ctx)
+    }
+
+    pub fn revoke_badge<'info>(ctx: Context<'_, '_, '_, 'info, RevokeBadge<'info>>) -> Result<()> {
+        instructions::revoke_badge::handler(ctx)
+    }
+
+    pub fn create_reputation_snapshot(ctx: Context<CreateReputationSnapshot>, suffix: String, epoch: u64, root: [u8; 32]) -> Result<()> {
+        instructions::create_reputation_snapshot::handler(ctx, suffix, epoch, root)
+    }
+
+    pub fn verify_snapshot_score(ctx: Context<VerifySnapshotScore>, suffix: String, epoch: u64, alias: Pubkey, score: i64, proof: Vec<[u8; 32]>) -> Result<bool> {
+        instructions::verify_snapshot_score::handler(ctx, suffix, epoch, alias, score, proof)
+    }
+
+    pub fn set_reputation_verifying_key(ctx: Context<SetReputationVerifyingKey>, verifying_key: Groth16VerifyingKey) -> Result<()> {
+        instructions::set_reputation_verifying_key::handler(ctx, verifying_key)
+    }
+
+    pub fn verify_reputation_threshold(ctx: Context<VerifyReputationThreshold>, epoch: u64, threshold: u64, proof: Groth16Proof) -> Result<()> {
+        instructions::verify_reputation_threshold::handler(ctx, epoch, threshold, proof)
+    }
+
+    pub fn post_reputation_bond(ctx: Context<PostReputationBond>, suffix: String) -> Result<()> {
+        instructions::post_reputation_bond::handler(ctx, suffix)
+    }
+
+    pub fn challenge_update(ctx: Context<ChallengeUpdate>, history_index: u8) -> Result<()> {
+        instructions::challenge_update::handler(ctx, history_index)
+    }
+
+    pub fn set_suffix_weight(ctx: Context<SetSuffixWeight>, suffix: String, weight_bps: u16) -> Result<()> {
+        instructions::set_suffix_weight::handler(ctx, suffix, weight_bps)
+    }
+
+    pub fn aggregate_reputation<'info>(ctx: Context<'_, '_, '_, 'info, AggregateReputationView<'info>>, username: String, project_suffix: String, chain_name: String) -> Result<i64> {
+        instructions::aggregate_reputation::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn cache_aggregate_reputation<'info>(ctx: Context<'_, '_, '_, 'info, CacheAggregateReputation<'info>>) -> Result<()> {
+        instructions::cache_aggregate_reputation::handler(ctx)
+    }
+
+    pub fn require_min_reputation(ctx: Context<RequireMinReputation>, username: String, project_suffix: String, chain_name: String, min: i64) -> Result<()> {
+        instructions::require_min_reputation::handler(ctx, username, project_suffix, chain_name, min)
+    }
+
+    pub fn lift_quarantine(ctx: Context<LiftQuarantine>, username: String, project_suffix: String, chain_name: String) -> Result<()> {
+        instructions::lift_quarantine::handler(ctx, username, project_suffix, chain_name)
+    }
+
+    pub fn set_reputation_config(ctx: Context<SetReputationConfig>, suffix: String, params: set_reputation_config::ReputationConfigParams) -> Result<()> {
+        instructions::set_reputation_config::handler(ctx, suffix, params)
+    }
+
+    pub fn delegate_reputation(ctx: Context<DelegateReputation>, amount: i64) -> Result<()> {
+        instructions::delegate_reputation::handler(ctx, amount)
+    }
+
+    pub fn undelegate(ctx: Context<Undelegate>) -> Result<()> {
+        instructions::undelegate::handler(ctx)
+    }
+
+    pub fn add_reputation_attestor(ctx: Context<AddReputationAttestor>, suffix: String, attestor: Pubkey, max_boost: i64) -> Result<()> {
+        instructions::add_reputation_attestor::handler(ctx, suffix, attestor, max_boost)
+    }
+
+    pub fn remove_reputation_attestor(ctx: Context<RemoveReputationAttestor>, suffix: String, attestor: Pubkey) -> Result<()> {
+        instructions::remove_reputation_attestor::handler(ctx, suffix, attestor)
+    }
+
+    pub fn grant_reputation_boost(ctx: Context<GrantReputationBoost>, amount: i64, expires_at: i64) -> Result<()> {
+        instructions::grant_reputation_boost::handler(ctx, amount, expires_at)
+    }
+
+    pub fn close_reputation_boost(ctx: Context<CloseReputationBoost>) -> Result<()> {
+        instructions::close_reputation_boost::handler(ctx)
+    }
+
+    pub fn deactivate_product_owner(ctx: Context<SetProductOwnerStatus>, suffix: String, freeze: bool) -> Result<()> {
+        instructions::deactivate_product_owner::deactivate_handler(ctx, suffix, freeze)
+    }
+
+    pub fn reactivate_product_owner(ctx: Context<SetProductOwnerStatus>, suffix: String) -> Result<()> {
+        instructions::deactivate_product_owner::reactivate_handler(ctx, suffix)
+    }
+
+    pub fn remove_product_owner(ctx: Context<RemoveProductOwner>, suffix: String) -> Result<()> {
+        instructions::remove_product_owner::handler(ctx, suffix)
+    }
+
+    pub fn propose_owner_key_rotation(ctx: Context<ProposeOwnerKeyRotation>, suffix: String, new_key: Pubkey) -> Result<()> {
+        instructions::propose_owner_key_rotation::handler(ctx, suffix, new_key)
+    }
+
+    pub fn accept_owner_key_rotation(ctx: Context<AcceptOwnerKeyRotation>, suffix: String) -> Result<()> {
+        instructions::accept_owner_key_rotation::handler(ctx, suffix)
+    }
+
+    pub fn create_suffix(ctx: Context<CreateSuffix>, suffix: String) -> Result<()> {
+        instructions::create_suffix::handler(ctx, suffix)
+    }
+
+    pub fn pause_suffix(ctx: Context<SetSuffixPause>, suffix: String) -> Result<()> {
+        instructions::pause_suffix::pause_handler(ctx, suffix)
+    }
+
+    pub fn unpause_suffix(ctx: Context<SetSuffixPause>, suffix: String) -> Result<()> {
+        instructions::pause_suffix::unpause_handler(ctx, suffix)
+    }
+
+    pub fn transfer_suffix(ctx: Context<TransferSuffix>, suffix: String) -> Result<()> {
+        instructions::transfer_suffix::handler(ctx, suffix)
+    }
+
+    pub fn add_registrar(ctx: Context<AddRegistrar>, suffix: String, registrar: Pubkey, quota: u32, expires_at: i64) -> Result<()> {
+        instructions::add_registrar::handler(ctx, suffix, registrar, quota, expires_at)
+    }
+
+    pub fn remove_registrar(ctx: Context<RemoveRegistrar>, suffix: String, registrar: Pubkey) -> Result<()> {
+        instructions::remove_registrar::handler(ctx, suffix, registrar)
+    }
+
+    pub fn withdraw_suffix_treasury(ctx: Context<WithdrawSuffixTreasury>, suffix: String, amount: u64) -> Result<()> {
+        instructions::withdraw_suffix_treasury::handler(ctx, suffix, amount)
+    }
+
+    pub fn set_registration_fee_bps(ctx: Context<SetRegistrationFeeBps>, bps: u16) -> Result<()> {
+        instructions::set_registration_fee_bps::handler(ctx, bps)
+    }
+
+    pub fn close_product_owner(ctx: Context<CloseProductOwner>) -> Result<()> {
+        instructions::close_product_owner::handler(ctx)
+    }
+
+    pub fn slash_product_owner_bond(ctx: Context<SlashProductOwnerBond>, owner: Pubkey, amount: u64) -> Result<()> {
+        instructions::slash_product_owner_bond::handler(ctx, owner, amount)
+    }
+
+    pub fn set_product_owner_bond(ctx: Context<SetProductOwnerBond>, amount: u64) -> Result<()> {
+        instructions::set_product_owner_bond::handler(ctx, amount)
+    }
+
+    pub fn commit_allowlist_root(ctx: Context<CommitAllowlistRoot>, suffix: String, root: [u8; 32]) -> Result<()> {
+        instructions::commit_allowlist_root::handler(ctx, suffix, root)
+    }
+
+    pub fn add_allowlist_entry(ctx: Context<AddAllowlistEntry>, suffix: String, user: Pubkey) -> Result<()> {
+        instructions::add_allowlist_entry::handler(ctx, suffix, user)
+    }
+
+    pub fn remove_allowlist_entry(ctx: Context<RemoveAllowlistEntry>, suffix: String, user: Pubkey) -> Result<()> {
+        instructions::remove_allowlist_entry::handler(ctx, suffix, user)
+    }
+
+    pub fn set_product_owner_profile(ctx: Context<SetProductOwnerProfile>, branding: ProductOwnerBranding) -> Result<()> {
+        instructions::set_product_owner_profile::handler(ctx, branding)
+    }
+
+    pub fn renew_suffix(ctx: Context<RenewSuffix>, suffix: String) -> Result<()> {
+        instructions::renew_suffix::handler(ctx, suffix)
+    }
+
+    pub fn reclaim_suffix(ctx: Context<ReclaimSuffix>, suffix: String) -> Result<()> {
+        instructions::reclaim_suffix::handler(ctx, suffix)
+    }
+
+    pub fn set_suffix_renewal_fee(ctx: Context<SetSuffixRenewalFee>, amount: u64) -> Result<()> {
+        instructions::set_suffix_renewal_fee::handler(ctx, amount)
+    }
+
+    pub fn create_sub_suffix(ctx: Context<CreateSubSuffix>, parent: String, label: String) -> Result<()> {
+        instructions::create_sub_suffix::handler(ctx, parent, label)
+    }
+
+    pub fn create_registration_verifying_key(ctx: Context<CreateRegistrationVerifyingKey>, id: u64, verifying_key: Groth16VerifyingKey, predicate: [u8; 16]) -> Result<()> {
+        instructions::create_registration_verifying_key::handler(ctx, id, verifying_key, predicate)
+    }
+
+    pub fn upgrade_admin_config(ctx: Context<UpgradeAdminConfig>) -> Result<()> {
+        instructions::upgrade_admin_config::handler(ctx)
+    }
+
+    pub fn upgrade_suffix_account(ctx: Context<UpgradeSuffixAccount>) -> Result<()> {
+        instructions::upgrade_suffix_account::handler(ctx)
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash, hash::hashv, keccak};
+use crate::error::EchoIDError as ErrorCode;
+use crate::state::AttestedChainMapping;
+
+/// Depth of the per-alias chain mapping tree, giving `1 << depth` leaf slots.
+pub const CHAIN_MAPPING_TREE_DEPTH: usize = 8;
+
+/// Leaf value of an empty or removed slot.
+pub const EMPTY_LEAF: [u8; 32] = [0; 32];
+
+/// Hashes a pair of nodes in sorted order, so proofs need no position bits.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[a, b]).to_bytes()
+    } else {
+        hashv(&[b, a]).to_bytes()
+    }
+}
+
+/// Returns true if the sorted-pair `proof` connects `leaf` to `root`.
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
+
+/// Hash function of a chain mapping tree. Keccak-256 trees can be checked by
+/// EVM contracts without re-hashing.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleHash {
+    Sha256,
+    Keccak256,
+}
+
+impl MerkleHash {
+    pub fn hashv(self, values: &[&[u8]]) -> [u8; 32] {
+        match self {
+            MerkleHash::Sha256 => hash::hashv(values).to_bytes(),
+            MerkleHash::Keccak256 => keccak::hashv(values).to_bytes(),
+        }
+    }
+}
+
+/// Canonical byte layout of a chain mapping leaf, so any client can rebuild
+/// leaves and proofs. Version 2, in order:
+///
+/// | field         | encoding                 |
+/// |---------------|--------------------------|
+/// | domain        | `b"chain_mapping"`       |
+/// | version       | u8, `LeafCodec::VERSION` |
+/// | chain type    | u8, `ChainType` variant  |
+/// | chain id      | u32 LE                   |
+/// | address       | u16 LE length, bytes     |
+/// | label         | u16 LE length, UTF-8     |
+/// | visible       | u8, 0 or 1               |
+/// | attested at   | i64 LE                   |
+/// | expires at    | i64 LE                   |
+///
+/// The leaf is the tree's hash of these bytes. Any layout change bumps the
+/// version.
+pub struct LeafCodec;
+
+impl LeafCodec {
+    pub const VERSION: u8 = 2;
+    const DOMAIN: &'static [u8] = b"chain_mapping";
+
+    pub fn encode(attested: &AttestedChainMapping) -> Vec<u8> {
+        let mapping = &attested.mapping;
+        let mut bytes = Vec::with_capacity(Self::DOMAIN.len() + 1 + 1 + 4 + 2 + mapping.address.len() + 2 + mapping.label.len() + 1 + 8 + 8);
+        bytes.extend_from_slice(Self::DOMAIN);
+        bytes.push(Self::VERSION);
+        bytes.push(mapping.chain_type as u8);
+        bytes.extend_from_slice(&mapping.chain_id.to_le_bytes());
+        bytes.extend_from_slice(&(mapping.address.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&mapping.address);
+        bytes.extend_from_slice(&(mapping.label.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(mapping.label.as_bytes());
+        bytes.push(mapping.visible as u8);
+        bytes.extend_from_slice(&attested.attested_at.to_le_bytes());
+        bytes.extend_from_slice(&attested.expires_at.to_le_bytes());
+        bytes
+    }
+
+    /// Fails for proofs built against another leaf layout, which could never
+    /// verify.
+    pub fn require_version(version: u8) -> Result<()> {
+        require!(version == Self::VERSION, ErrorCode::LeafVersionMismatch);
+        Ok(())
+    }
+}
+
+pub fn hash_chain_mapping(hash: MerkleHash, attested: &AttestedChainMapping) -> [u8; 32] {
+    hash.hashv(&[&LeafCodec::encode(attested)])
+}
+
+/// Roots of empty subtrees, by height.
+fn zero_hashes(hash: MerkleHash) -> [[u8; 32]; CHAIN_MAPPING_TREE_DEPTH] {
+    let mut zeros = [EMPTY_LEAF; CHAIN_MAPPING_TREE_DEPTH];
+    for level in 1..CHAIN_MAPPING_TREE_DEPTH {
+        zeros[level] = hash.hashv(&[&zeros[level - 1], &zeros[level - 1]]);
+    }
+    zeros
+}
+
+/// Hashes `leaf` up the positional `proof` for slot `index`. Fails on a
+/// proof of the wrong length.
+fn root_from_path(hash: MerkleHash, proof: &[[u8; 32]], index: u32, leaf: [u8; 32]) -> Option<[u8; 32]> {
+    if proof.len() != CHAIN_MAPPING_TREE_DEPTH {
+        return None;
+    }
+    Some(proof.iter().enumerate().fold(leaf, |node, (level, sibling)| {
+        if index >> level & 1 == 0 {
+            hash.hashv(&[&node, sibling])
+        } else {
+            hash.hashv(&[sibling, &node])
+        }
+    }))
+}
+
+/// Returns true if `proof` shows `leaf` sits in slot `index` under `root`.
+pub fn verify_path(hash: MerkleHash, proof: &[[u8; 32]], root: &[u8; 32], index: u32, leaf: [u8; 32]) -> bool {
+    root_from_path(hash, proof, index, leaf).as_ref() == Some(root)
+}
+
+/// Computes the root over `leaves`, given as `(index, leaf)` pairs with
+/// strictly increasing indices, taking each sibling that is not itself on a
+/// proven path from `siblings` in order. Fails unless every sibling is used.
+pub fn root_from_multiproof(hash: MerkleHash, leaves: &[(u32, [u8; 32])], siblings: &[[u8; 32]]) -> Option<[u8; 32]> {
+    let sorted = leaves.windows(2).all(|pair| pair[0].0 < pair[1].0);
+    if leaves.is_empty() || !sorted || leaves.iter().any(|&(index, _)| index >= IncrementalTree::CAPACITY) {
+        return None;
+    }
+    let mut siblings = siblings.iter();
+    let mut level = leaves.to_vec();
+    for _ in 0..CHAIN_MAPPING_TREE_DEPTH {
+        let mut parents = Vec::with_capacity(level.len());
+        let mut nodes = level.iter().peekable();
+        while let Some(&(index, node)) = nodes.next() {
+            let parent = if index & 1 == 0 {
+                match nodes.next_if(|&&(next, _)| next == index + 1) {
+                    Some((_, right)) => hash.hashv(&[&node, right]),
+                    None => hash.hashv(&[&node, siblings.next()?]),
+                }
+            } else {
+                hash.hashv(&[siblings.next()?, &node])
+            };
+            parents.push((index >> 1, parent));
+        }
+        level = parents;
+    }
+    match (siblings.next(), level.as_slice()) {
+        (None, [(_, root)]) => Some(*root),
+        _ => None,
+    }
+}
+
+/// Append-only Merkle tree that keeps only the rightmost left node at each
+/// level (`frontier`), so appends and proven updates are O(depth) and never
+/// need the full leaf set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IncrementalTree {
+    pub next_index: u32,
+    pub frontier: [[u8; 32]; CHAIN_MAPPING_TREE_DEPTH],
+    /// Only changeable while the tree is empty.
+    pub hash: MerkleHash,
+}
+
+impl IncrementalTree {
+    pub const SPACE: usize = 4 + 32 * CHAIN_MAPPING_TREE_DEPTH + 1;
+    pub const CAPACITY: u32 = 1 << CHAIN_MAPPING_TREE_DEPTH;
+
+    /// Writes `leaf` to the next free slot, returning the slot and new root.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Option<(u32, [u8; 32])> {
+        let index = self.next_index;
+        if index >= Self::CAPACITY {
+            return None;
+        }
+        let zeros = zero_hashes(self.hash);
+        let mut node = leaf;
+        for (level, zero) in zeros.iter().enumerate() {
+            node = if index >> level & 1 == 0 {
+                self.frontier[level] = node;
+                self.hash.hashv(&[&node, zero])
+            } else {
+                self.hash.hashv(&[&self.frontier[level], &node])
+            };
+        }
+        self.next_index += 1;
+        Some((index, node))
+    }
+
+    /// Replaces the leaf in slot `index` with `new_leaf`, given a `proof`
+    /// that `old_leaf` is there under `root`. Returns the new root.
+    pub fn replace(&mut self, root: &[u8; 32], index: u32, proof: &[[u8; 32]], old_leaf: [u8; 32], new_leaf: [u8; 32]) -> Option<[u8; 32]> {
+        if index >= self.next_index || !verify_path(self.hash, proof, root, index, old_leaf) {
+            return None;
+        }
+        // The frontier holds, at each level, the left node of the pair on the
+        // path of the last appended leaf; refresh any of those we pass through.
+        let last = self.next_index - 1;
+        let mut node = new_leaf;
+        for (level, sibling) in proof.iter().enumerate() {
+            if index >> level == (last >> level) & !1 {
+                self.frontier[level] = node;
+            }
+            node = if index >> level & 1 == 0 {
+                self.hash.hashv(&[&node, sibling])
+            } else {
+                self.hash.hashv(&[sibling, &node])
+            };
+        }
+        Some(node)
+    }
+}
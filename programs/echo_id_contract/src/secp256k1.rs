@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{secp256k1_program, sysvar::instructions as sysvar_instructions};
+use crate::error::EchoIDError as ErrorCode;
+
+const SIGNATURE_OFFSETS_START: usize = 1;
+const SIGNATURE_OFFSETS_SIZE: usize = 11;
+const ETH_ADDRESS_SIZE: usize = 20;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<usize> {
+    data.get(offset..offset + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize)
+}
+
+/// Returns true if `data`, the payload of the secp256k1 instruction at
+/// `ix_index`, includes a signature recovering to `eth_address` over exactly
+/// `message`, with all offsets pointing into that same instruction.
+fn contains_signature(data: &[u8], ix_index: usize, eth_address: &[u8], message: &[u8]) -> bool {
+    let Some(&count) = data.first() else {
+        return false;
+    };
+
+    (0..count as usize).any(|i| {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+        let byte = |n: usize| data.get(start + n).map(|&b| b as usize);
+        let (Some(sig_ix), Some(address_offset), Some(address_ix), Some(msg_offset), Some(msg_size), Some(msg_ix)) =
+            (byte(2), read_u16(data, start + 3), byte(5), read_u16(data, start + 6), read_u16(data, start + 8), byte(10))
+        else {
+            return false;
+        };
+        if sig_ix != ix_index || address_ix != ix_index || msg_ix != ix_index {
+            return false;
+        }
+
+        data.get(address_offset..address_offset + ETH_ADDRESS_SIZE) == Some(eth_address)
+            && data.get(msg_offset..msg_offset + msg_size) == Some(message)
+    })
+}
+
+/// Checks that the transaction carries a secp256k1 program instruction in
+/// which the key behind `eth_address` signed `message`. As with ed25519, the
+/// precompile rejects the transaction if recovery fails, so finding the
+/// instruction is sufficient proof.
+pub fn verify_secp256k1_signature(instructions: &AccountInfo, eth_address: &[u8], message: &[u8]) -> Result<()> {
+    require_keys_eq!(instructions.key(), sysvar_instructions::ID, ErrorCode::InvalidSignature);
+
+    let current = sysvar_instructions::load_current_index_checked(instructions)? as usize;
+    let mut index = 0;
+    while let Ok(ix) = sysvar_instructions::load_instruction_at_checked(index, instructions) {
+        if index != current && ix.program_id == secp256k1_program::ID && contains_signature(&ix.data, index, eth_address, message) {
+            return Ok(());
+        }
+        index += 1;
+    }
+
+    err!(ErrorCode::SignatureVerificationFailed)
+}
@@ -1,19 +1,255 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::error::EchoIDError as ErrorCode;
+use crate::validation::{MIN_USERNAME_LENGTH, MAX_USERNAME_LENGTH, USERNAME_CHARSET_ALL};
+use crate::merkle::{hash_chain_mapping, root_from_multiproof, verify_path, IncrementalTree};
+use crate::zkp::Groth16VerifyingKey;
 
 
 pub const MAX_CHAIN_MAPPINGS: usize = 32; 
+pub const ALIAS_TERM_DURATION: i64 = 365 * 24 * 60 * 60;
+pub const ALIAS_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60;
+pub const SUFFIX_LEASE_DURATION: i64 = 365 * 24 * 60 * 60;
+/// Time after a suffix lease lapses during which its owner may still renew
+/// it, before the admin may reclaim it.
+pub const SUFFIX_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60;
+/// Default `AdminConfig::suffix_renewal_fee`.
+pub const SUFFIX_RENEWAL_FEE: u64 = 1_000_000_000;
+/// Default general score of a newly registered alias.
+pub const INITIAL_REPUTATION: i64 = 10;
+/// Protocol-wide reputation band and per-update limit; suffixes may only
+/// narrow them.
+pub const MIN_REPUTATION: i64 = -1_000_000;
+pub const MAX_REPUTATION: i64 = 1_000_000;
+pub const MAX_REPUTATION_CHANGE: i64 = 10_000;
+/// Default reputation rate limit: total absolute change per window.
+pub const REPUTATION_RATE_WINDOW: i64 = 24 * 60 * 60;
+pub const MAX_REPUTATION_PER_WINDOW: u64 = 50_000;
+/// Default effective reputation needed for each badge tier (Bronze, Silver,
+/// Gold, Platinum), and how far below its threshold, in basis points, a
+/// score may fall before a badge can be revoked.
+pub const MAX_REPUTATION_TIERS: usize = 4;
+pub const REPUTATION_TIER_THRESHOLDS: [i64; MAX_REPUTATION_TIERS] = [100, 1_000, 10_000, 100_000];
+pub const REPUTATION_TIER_HYSTERESIS_BPS: u16 = 1_000;
+/// Half-life of reputation escrowed when an alias changes hands.
+pub const REPUTATION_ESCROW_HALF_LIFE: i64 = 30 * 24 * 60 * 60;
+/// Default time between ownership verifications that earn reputation.
+pub const VERIFICATION_REWARD_COOLDOWN: i64 = 24 * 60 * 60;
+/// Default time a quarantined alias waits before anyone may lift it.
+pub const REHABILITATION_PERIOD: i64 = 30 * 24 * 60 * 60;
+pub const MAX_REPUTATION_HISTORY: usize = 32;
+/// Named reputation categories a suffix may define on top of the general
+/// score, which is category 0.
+pub const MAX_REPUTATION_CATEGORIES: usize = 4;
+pub const MAX_REPUTATION_CATEGORY_NAME_LENGTH: usize = 16;
+/// Live endorsements an alias may have given at once.
+pub const MAX_ENDORSEMENTS_PER_ALIAS: u8 = 5;
+pub const MAX_ENDORSEMENT_WEIGHT: u16 = 100;
+/// Most reputation an alias can gain from endorsements combined.
+pub const MAX_ENDORSEMENT_BONUS: i64 = 250;
+/// Staked lamports per point of reputation boost, and the most staking can add.
+pub const LAMPORTS_PER_STAKED_POINT: u64 = 10_000_000;
+pub const MAX_STAKE_BONUS: i64 = 500;
+/// Most reputation an alias can gain from attestor boosts combined, and the
+/// longest a single boost may last.
+pub const MAX_BOOST_BONUS: i64 = 500;
+pub const MAX_BOOST_DURATION: i64 = 365 * 24 * 60 * 60;
+/// Delay between `unstake` and `withdraw_stake`, during which the stake can
+/// still be slashed.
+pub const REPUTATION_UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+/// Reason code recorded in reputation history for slashes.
+pub const SLASH_REASON: u16 = u16::MAX;
+/// Lamports posted to open a dispute, refunded if the change is reverted.
+pub const DISPUTE_BOND: u64 = 100_000_000;
+/// Reason code recorded in reputation history when a dispute reverts a change.
+pub const DISPUTE_REVERT_REASON: u16 = u16::MAX - 1;
+/// Reason codes in `ReputationChanged` for decay applied before a change and
+/// for ownership verification rewards.
+pub const DECAY_REASON: u16 = u16::MAX - 2;
+pub const VERIFICATION_REWARD_REASON: u16 = u16::MAX - 3;
+/// Lamports a reputation authority must bond before its updates apply, and
+/// the share of that bond paid to a challenger whose challenge succeeds.
+pub const REPUTATION_AUTHORITY_BOND: u64 = 500_000_000;
+pub const REPUTATION_BOND_SLASH_BPS: u16 = 5_000;
+/// Weight of a suffix's scores in aggregate reputation unless the admin sets
+/// another, and the most linked aliases one aggregate covers.
+pub const DEFAULT_SUFFIX_WEIGHT_BPS: u16 = 10_000;
+pub const MAX_AGGREGATED_ALIASES: usize = 8;
+/// How long a chain mapping stays fresh before it must be re-attested.
+pub const CHAIN_MAPPING_ATTESTATION_PERIOD: i64 = 90 * 24 * 60 * 60;
+pub const COMMITMENT_MIN_AGE: i64 = 60;
+pub const COMMITMENT_MAX_AGE: i64 = 24 * 60 * 60;
+pub const MAX_TEXT_RECORDS: usize = 16;
+pub const MAX_RECORD_KEY_LENGTH: usize = 32;
+pub const MAX_RECORD_VALUE_LENGTH: usize = 256;
+pub const VOUCHER_NONCES_PER_PAGE: u64 = 1024;
+pub const MAX_KEY_HISTORY: usize = 16;
+pub const MAX_GUARDIANS: usize = 8;
+pub const RECOVERY_TIMELOCK: i64 = 2 * 24 * 60 * 60;
+pub const MAX_ALIAS_KEYS: usize = 8;
+pub const MAX_ROOT_HISTORY: usize = 16;
+pub const MAX_REGISTRY_MAPPINGS: usize = 16;
+/// Longest address `validate_chain_address` accepts (a bech32 string).
+pub const MAX_CHAIN_ADDRESS_LENGTH: usize = 90;
+pub const MAX_CHAIN_MAPPING_LABEL_LENGTH: usize = 32;
+pub const SESSION_SCOPE_ADD_MAPPING: u8 = 1 << 0;
+pub const SESSION_SCOPE_SET_RECORD: u8 = 1 << 1;
+pub const SESSION_SCOPE_VERIFY: u8 = 1 << 2;
+pub const SESSION_SCOPE_ALL: u8 = SESSION_SCOPE_ADD_MAPPING | SESSION_SCOPE_SET_RECORD | SESSION_SCOPE_VERIFY;
+/// Layout version written to every `AliasAccount`. Bump it together with a
+/// migration arm in `upgrade_alias_account` whenever the layout changes.
+pub const ALIAS_ACCOUNT_VERSION: u8 = 14;
+/// Window for a suffix's `max_registrations_per_day`.
+pub const REGISTRATION_WINDOW: i64 = 24 * 60 * 60;
+/// Username lengths, starting at `MIN_USERNAME_LENGTH`, a suffix may price
+/// separately from its base registration fee.
+pub const PREMIUM_LENGTH_BUCKETS: usize = 4;
+/// Most suffixes one product owner may attach.
+pub const MAX_SUFFIXES_PER_PRODUCT_OWNER: u16 = 16;
+/// Default `AdminConfig::product_owner_bond`.
+pub const PRODUCT_OWNER_BOND: u64 = 100_000_000;
+pub const MAX_PRODUCT_OWNER_NAME_LENGTH: usize = 32;
+pub const MAX_PRODUCT_OWNER_URI_LENGTH: usize = 128;
+/// Time a deactivated product owner waits before the admin may reactivate it.
+pub const PRODUCT_OWNER_REACTIVATION_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+/// Share of every marketplace sale paid to the protocol admin, in basis points.
+pub const PROTOCOL_FEE_BPS: u16 = 100;
 #[account]
 pub struct AdminConfig {
     pub admin: Pubkey,
+    /// How many of an alias's most recent chain mapping roots verification
+    /// accepts, including the current one.
+    pub root_history_depth: u8,
+    /// Protocol's cut of every suffix registration fee, in basis points.
+    pub registration_fee_bps: u16,
+    /// Lamports a product owner must hold bonded in its `ProductOwner`
+    /// record to register or take over suffixes.
+    pub product_owner_bond: u64,
+    /// Lamports paid to the protocol for each `renew_suffix`.
+    pub suffix_renewal_fee: u64,
+}
+
+impl AdminConfig {
+    pub const SPACE: usize = 8 + 32 + 1 + 2 + 8 + 8;
 }
 
 #[account]
 pub struct SuffixAccount {
+    /// Key that must sign product-owner instructions. It may be a PDA, such
+    /// as a Squads vault, signing through CPI: the program checks the key
+    /// and `is_signer` only, never the seeds behind it. Rent is paid by a
+    /// separate payer, but the bond and suffix renewals are paid from the
+    /// owner, so a PDA owner must be system-owned to post them.
     pub owner: Pubkey,
     pub is_active: bool,
+    pub transfer_requires_cosign: bool,
+    pub renewal_fee: u64,
+    pub require_commitment: bool,
+    pub require_registration_approval: bool,
+    /// Whether aliases under this suffix may be listed and sold.
+    pub transferable: bool,
+    /// Product owner royalty on marketplace sales, in basis points.
+    pub royalty_bps: u16,
+    /// Seconds after registration during which aliases cannot change hands.
+    pub transfer_lock_duration: i64,
+    /// Set by the admin when deactivating the product owner to also stop it
+    /// changing the suffix's policy and configs.
+    pub is_frozen: bool,
+    /// When the product owner was last deactivated; zero if never.
+    pub deactivated_at: i64,
+    /// Key proposed to take over as product owner, until it accepts.
+    pub pending_owner: Option<Pubkey>,
+    /// Emergency stop set by the product owner: no registrations, chain
+    /// mapping additions or reputation updates under the suffix.
+    pub is_paused: bool,
+    /// End of the current lease; registrations stop here, and the admin may
+    /// reclaim the suffix once `SUFFIX_GRACE_PERIOD` has also passed.
+    pub expires_at: i64,
+    /// The namespace this one is a `label.parent` child of, if any.
+    pub parent: Option<Pubkey>,
     pub suffix: String,
 }
 
+impl SuffixAccount {
+    pub fn space(suffix: &str) -> usize {
+        8 + 32 + 1 + 1 + 8 + 1 + 1 + 1 + 2 + 8 + 1 + 8 + 1 + 32 + 1 + 8 + 33 + 4 + suffix.len()
+    }
+
+    pub fn require_leased(&self, now: i64) -> Result<()> {
+        require!(now < self.expires_at, ErrorCode::SuffixExpired);
+        Ok(())
+    }
+
+    pub fn is_reclaimable(&self, now: i64) -> bool {
+        now >= self.expires_at.saturating_add(SUFFIX_GRACE_PERIOD)
+    }
+}
+
+/// A product owner the admin has registered, which may attach up to
+/// `MAX_SUFFIXES_PER_PRODUCT_OWNER` suffixes, each its own `SuffixAccount`
+/// linked back by owner key.
+#[account]
+pub struct ProductOwner {
+    pub owner: Pubkey,
+    pub suffix_count: u16,
+    pub created_at: i64,
+    /// Lamports bonded in this account, refunded by `close_product_owner`
+    /// and slashable by the admin.
+    pub bond: u64,
+}
+
+impl ProductOwner {
+    pub const SPACE: usize = 8 + 32 + 2 + 8 + 8;
+
+    /// Counts one more suffix against the owner's limit, filling in the
+    /// record the first time it is used.
+    pub fn attach_suffix(&mut self, owner: Pubkey, now: i64) -> Result<()> {
+        require!(self.suffix_count < MAX_SUFFIXES_PER_PRODUCT_OWNER, ErrorCode::TooManySuffixes);
+        if self.owner == Pubkey::default() {
+            self.owner = owner;
+            self.created_at = now;
+        }
+        self.suffix_count += 1;
+        Ok(())
+    }
+
+    pub fn detach_suffix(&mut self) {
+        self.suffix_count = self.suffix_count.saturating_sub(1);
+    }
+}
+
+/// How wallets present a product owner as the operator of its suffixes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ProductOwnerBranding {
+    pub display_name: String,
+    pub logo_uri: String,
+    pub website: String,
+    /// Hash of the operator's support contact, so wallets can confirm a
+    /// contact they were given without it being published on chain.
+    pub support_contact_hash: [u8; 32],
+}
+
+#[account]
+pub struct ProductOwnerProfile {
+    pub owner: Pubkey,
+    pub branding: ProductOwnerBranding,
+    pub updated_at: i64,
+}
+
+impl ProductOwnerProfile {
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_PRODUCT_OWNER_NAME_LENGTH + 2 * (4 + MAX_PRODUCT_OWNER_URI_LENGTH) + 32 + 8;
+
+    /// Reads the branding of the profile at `info`, which callers constrain
+    /// to the suffix owner's profile address, if the owner has one.
+    pub fn load_branding(info: &AccountInfo) -> Result<Option<ProductOwnerBranding>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::Unauthorized);
+        Ok(Some(Self::try_deserialize(&mut &info.data.borrow()[..])?.branding))
+    }
+}
+
 #[account]
 pub struct AliasAccount {
     pub owner: Pubkey,
@@ -23,41 +259,2509 @@ pub struct AliasAccount {
     pub reputation: i64,
     pub reputation_updated_at: i64,
     pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    /// Multihash of the off-chain profile document; all zeroes when unset.
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    /// Bound into every off-chain authorization for this alias and advanced
+    /// when one is consumed, so signed messages cannot be replayed.
+    pub nonce: u64,
+    /// Whether the suffix's product owner may freeze, adjust reputation on,
+    /// or co-sign transfers of this alias. Cleared for good by
+    /// `renounce_product_owner_control`.
+    pub owner_managed: bool,
+    /// Alias credited with referring this registration, if any.
+    pub referrer: Option<Pubkey>,
+    /// Registration time; zero for aliases that predate it.
+    pub created_at: i64,
+    /// Presentation form of `username`, e.g. `Alice.Smith` for `alicesmith`.
+    /// Empty means the username is displayed as is.
+    pub display_name: String,
+    /// Merkle root over the alias's additional chain addresses. The leaves
+    /// live off-chain; the append frontier lives in its `ChainMappingTree`.
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    /// Advanced whenever `chain_mappings_root` changes.
+    pub chain_mappings_version: u32,
+    /// Start of the current reputation rate-limit window.
+    pub reputation_window_start: i64,
+    /// Sum of absolute reputation changes applied within that window.
+    pub reputation_window_change: u64,
+    /// Scores in the suffix's named categories, indexed by category ID minus
+    /// one; category 0 is `reputation`.
+    pub category_reputation: [i64; MAX_REPUTATION_CATEGORIES],
+    pub category_reputation_updated_at: [i64; MAX_REPUTATION_CATEGORIES],
+    /// General score set aside by an escrow-and-decay transfer, counted in
+    /// effective reputation as it decays from `escrowed_at`.
+    pub escrowed_reputation: i64,
+    pub escrowed_at: i64,
+    /// When a successful ownership verification last earned reputation.
+    pub verification_rewarded_at: i64,
+    /// Set when a reputation decrease leaves the general score below the
+    /// suffix's quarantine floor; blocks new chain mappings and ownership
+    /// verifications until `lift_quarantine`.
+    pub is_quarantined: bool,
+    pub quarantined_at: i64,
+    /// Kept last so older layouts remain a byte prefix of newer ones.
+    pub version: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub struct ChainInfo {
-    pub name: String,
-    pub address: String, 
-    pub chain_id: u32,
+/// The original, unversioned alias layout.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV1 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub struct AliasMetadata {
-    pub name: String,
-    pub image_url: String,
+/// Version 2 layout, before the alias nonce.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV2 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub version: u8,
 }
 
-#[event]
-pub struct AliasRegistered {
+/// Version 3 layout, before the product owner control toggle.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV3 {
+    pub owner: Pubkey,
     pub username: String,
-    pub suffix: String,
+    pub product_suffix: String,
     pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
     pub metadata: AliasMetadata,
-    pub user_address: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub version: u8,
 }
 
-#[event]
-pub struct AliasReputationUpdated {
+/// Version 4 layout, before referral tracking.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV4 {
+    pub owner: Pubkey,
     pub username: String,
-    pub suffix: String,
+    pub product_suffix: String,
     pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
     pub metadata: AliasMetadata,
-    pub user_address: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub version: u8,
 }
 
-#[event]
-pub struct SuffixUpdated {
+/// Version 5 layout, before the registration timestamp.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV5 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub version: u8,
+}
+
+/// Version 6 layout, before display names.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV6 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub version: u8,
+}
+
+/// Version 7 layout, before chain mappings.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV7 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub version: u8,
+}
+
+/// Version 8 layout, before the chain mappings version counter.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV8 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub version: u8,
+}
+
+/// Version 9 layout, before the reputation rate-limit window.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV9 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub chain_mappings_version: u32,
+    pub version: u8,
+}
+
+/// Version 10 layout, before reputation categories.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV10 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub chain_mappings_version: u32,
+    pub reputation_window_start: i64,
+    pub reputation_window_change: u64,
+    pub version: u8,
+}
+
+/// Version 11 layout, before reputation escrow.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV11 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub chain_mappings_version: u32,
+    pub reputation_window_start: i64,
+    pub reputation_window_change: u64,
+    pub category_reputation: [i64; MAX_REPUTATION_CATEGORIES],
+    pub category_reputation_updated_at: [i64; MAX_REPUTATION_CATEGORIES],
+    pub version: u8,
+}
+
+/// Version 12 layout, before ownership verification rewards.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV12 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub chain_mappings_version: u32,
+    pub reputation_window_start: i64,
+    pub reputation_window_change: u64,
+    pub category_reputation: [i64; MAX_REPUTATION_CATEGORIES],
+    pub category_reputation_updated_at: [i64; MAX_REPUTATION_CATEGORIES],
+    pub escrowed_reputation: i64,
+    pub escrowed_at: i64,
+    pub version: u8,
+}
+
+/// Version 13 layout, before quarantine.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AliasAccountV13 {
+    pub owner: Pubkey,
+    pub username: String,
+    pub product_suffix: String,
+    pub chain_info: ChainInfo,
+    pub reputation: i64,
+    pub reputation_updated_at: i64,
+    pub metadata: AliasMetadata,
+    pub pending_owner: Option<Pubkey>,
+    pub expires_at: i64,
+    pub controller: Pubkey,
+    pub content_hash: [u8; 34],
+    pub is_frozen: bool,
+    pub nonce: u64,
+    pub owner_managed: bool,
+    pub referrer: Option<Pubkey>,
+    pub created_at: i64,
+    pub display_name: String,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub chain_mappings_version: u32,
+    pub reputation_window_start: i64,
+    pub reputation_window_change: u64,
+    pub category_reputation: [i64; MAX_REPUTATION_CATEGORIES],
+    pub category_reputation_updated_at: [i64; MAX_REPUTATION_CATEGORIES],
+    pub escrowed_reputation: i64,
+    pub escrowed_at: i64,
+    pub verification_rewarded_at: i64,
+    pub version: u8,
+}
+
+impl AliasAccount {
+    /// Size of an alias with no display name; add the display name length
+    /// when one is set.
+    pub fn space(username: &str, suffix: &str, chain_info: &ChainInfo, metadata: &AliasMetadata) -> usize {
+        8 + 32 + 4 + username.len() + 4 + suffix.len()
+            + 4 + chain_info.name.len() + 4 + chain_info.address.len() + 4
+            + 8 + 8
+            + 4 + metadata.name.len() + 4 + metadata.image_url.len()
+            + 1 + 32 // pending_owner
+            + 8 // expires_at
+            + 32 // controller
+            + 34 // content_hash
+            + 1 // is_frozen
+            + 8 // nonce
+            + 1 // owner_managed
+            + 1 + 32 // referrer
+            + 8 // created_at
+            + 4 // display_name
+            + 32 + 1 // chain_mappings_root, chain_mapping_count
+            + 4 // chain_mappings_version
+            + 8 + 8 // reputation_window_start, reputation_window_change
+            + 2 * 8 * MAX_REPUTATION_CATEGORIES // category_reputation, category_reputation_updated_at
+            + 8 + 8 // escrowed_reputation, escrowed_at
+            + 8 // verification_rewarded_at
+            + 1 + 8 // is_quarantined, quarantined_at
+            + 1 // version
+    }
+
+    /// The digest an off-chain authorization for this alias must sign:
+    /// `payload` bound to the alias address and its current nonce.
+    pub fn authorization_message(&self, alias: &Pubkey, payload: &[u8]) -> [u8; 32] {
+        hashv(&[alias.as_ref(), &self.nonce.to_le_bytes(), payload]).to_bytes()
+    }
+
+    /// Invalidates every outstanding authorization signed against the current nonce.
+    pub fn advance_nonce(&mut self) {
+        self.nonce = self.nonce.wrapping_add(1);
+    }
+
+    /// The controller may manage day-to-day data; the owner always can.
+    pub fn is_controller(&self, key: &Pubkey) -> bool {
+        self.controller == *key || self.owner == *key
+    }
+
+    /// Like `is_controller`, but also accepts an unexpired session key
+    /// granted `scope` for this alias by its current owner.
+    pub fn require_authority(&self, key: &Pubkey, session: Option<&SessionKey>, scope: u8, now: i64) -> Result<()> {
+        if self.is_controller(key) {
+            return Ok(());
+        }
+        require!(session.is_some_and(|s| s.key == *key && s.allows(&self.owner, scope, now)), ErrorCode::Unauthorized);
+        Ok(())
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Fails unless the alias is within its term and not frozen.
+    pub fn require_usable(&self, now: i64) -> Result<()> {
+        require!(!self.is_expired(now), ErrorCode::AliasExpired);
+        require!(!self.is_frozen, ErrorCode::AliasFrozen);
+        Ok(())
+    }
+
+    pub fn require_not_quarantined(&self) -> Result<()> {
+        require!(!self.is_quarantined, ErrorCode::AliasQuarantined);
+        Ok(())
+    }
+
+    /// Quarantines the alias if its general score is below the suffix's
+    /// floor, returning whether it was newly quarantined.
+    pub fn quarantine_below_floor(&mut self, config: &ReputationConfig, now: i64) -> bool {
+        if self.is_quarantined || self.reputation >= config.quarantine_floor {
+            return false;
+        }
+        self.is_quarantined = true;
+        self.quarantined_at = now;
+        true
+    }
+
+    /// Fails while the alias is inside its suffix's post-registration transfer lock.
+    pub fn require_transfer_unlocked(&self, suffix: &SuffixAccount, now: i64) -> Result<()> {
+        require!(now >= self.created_at.saturating_add(suffix.transfer_lock_duration), ErrorCode::TransferLocked);
+        Ok(())
+    }
+
+    /// Score and last update time in `category`; category 0 is the general
+    /// `reputation`.
+    pub fn category_reputation(&self, category: u8) -> Result<(i64, i64)> {
+        match category as usize {
+            0 => Ok((self.reputation, self.reputation_updated_at)),
+            id if id <= MAX_REPUTATION_CATEGORIES => Ok((self.category_reputation[id - 1], self.category_reputation_updated_at[id - 1])),
+            _ => err!(ErrorCode::InvalidReputationCategory),
+        }
+    }
+
+    pub fn set_category_reputation(&mut self, category: u8, reputation: i64, now: i64) {
+        match category as usize {
+            0 => {
+                self.reputation = reputation;
+                self.reputation_updated_at = now;
+            }
+            id => {
+                self.category_reputation[id - 1] = reputation;
+                self.category_reputation_updated_at[id - 1] = now;
+            }
+        }
+    }
+
+    /// What remains of the escrowed score at `now`.
+    pub fn escrowed_reputation_at(&self, now: i64) -> i64 {
+        decay_reputation(self.escrowed_reputation, self.escrowed_at, now, REPUTATION_ESCROW_HALF_LIFE)
+    }
+
+    /// The general score as it stands at `now` under the suffix's decay,
+    /// including anything escrowed by a transfer, but none of the bonuses
+    /// that need other accounts.
+    pub fn general_reputation_at(&self, config: &ReputationConfig, now: i64) -> i64 {
+        decay_reputation(self.reputation, self.reputation_updated_at, now, config.reputation_half_life)
+            .saturating_add(self.escrowed_reputation_at(now))
+    }
+
+    /// Credits the suffix's `verification_reward` to the general score unless
+    /// one was already earned within the cooldown, and returns the change
+    /// after clamping to the suffix's band.
+    pub fn reward_verification(&mut self, config: &ReputationConfig, now: i64) -> i64 {
+        if config.verification_reward == 0 || now < self.verification_rewarded_at.saturating_add(config.verification_reward_cooldown) {
+            return 0;
+        }
+        let reputation = decay_reputation(self.reputation, self.reputation_updated_at, now, config.reputation_half_life);
+        let updated = reputation.saturating_add(config.verification_reward).clamp(config.min_reputation, config.max_reputation);
+        self.reputation = updated;
+        self.reputation_updated_at = now;
+        self.verification_rewarded_at = now;
+        updated - reputation
+    }
+
+    /// Fails unless the alias is usable and its general score at `now` is at
+    /// least `min`.
+    pub fn require_min_reputation(&self, config: &ReputationConfig, min: i64, now: i64) -> Result<()> {
+        self.require_usable(now)?;
+        require!(self.general_reputation_at(config, now) >= min, ErrorCode::InsufficientReputation);
+        Ok(())
+    }
+
+    /// Applies the suffix's reputation transfer policy as the alias changes
+    /// hands, returning the general score before and after. Category scores
+    /// only carry over under `CarryOver`.
+    pub fn transfer_reputation(&mut self, policy: ReputationTransferPolicy, now: i64) -> (i64, i64) {
+        let previous = self.reputation;
+        if policy == ReputationTransferPolicy::CarryOver {
+            return (previous, previous);
+        }
+        if policy == ReputationTransferPolicy::EscrowAndDecay {
+            self.escrowed_reputation = self.escrowed_reputation_at(now).saturating_add(previous);
+            self.escrowed_at = now;
+        }
+        self.reputation = 0;
+        self.reputation_updated_at = now;
+        self.category_reputation = [0; MAX_REPUTATION_CATEGORIES];
+        self.category_reputation_updated_at = [now; MAX_REPUTATION_CATEGORIES];
+        (previous, self.reputation)
+    }
+
+    /// Counts `change` against the alias's reputation rate limit, starting a
+    /// new window once the current one has passed.
+    pub fn record_reputation_change(&mut self, change: i64, config: &ReputationConfig, now: i64) -> Result<()> {
+        if now >= self.reputation_window_start.saturating_add(config.reputation_window) {
+            self.reputation_window_start = now;
+            self.reputation_window_change = 0;
+        }
+        let total = self.reputation_window_change.saturating_add(change.unsigned_abs());
+        require!(total <= config.max_reputation_per_window, ErrorCode::ReputationRateLimited);
+        self.reputation_window_change = total;
+        Ok(())
+    }
+
+    pub fn set_chain_mappings_root(&mut self, root: [u8; 32]) {
+        self.chain_mappings_root = root;
+        self.chain_mappings_version = self.chain_mappings_version.wrapping_add(1);
+    }
+
+    pub fn is_reclaimable(&self, now: i64) -> bool {
+        now >= self.expires_at.saturating_add(ALIAS_GRACE_PERIOD)
+    }
+}
+
+/// A `label.username@suffix` identity controlled by the parent alias owner.
+#[account]
+pub struct SubAliasAccount {
+    pub parent: Pubkey,
+    pub label: String,
+    pub owner: Pubkey,
+    /// Share of the parent's reputation the sub-alias inherits, in basis points.
+    pub reputation_share_bps: u16,
+    pub created_at: i64,
+}
+
+impl SubAliasAccount {
+    pub fn space(label: &str) -> usize {
+        8 + 32 + 4 + label.len() + 32 + 2 + 8
+    }
+
+    pub fn effective_reputation(&self, parent_reputation: i64) -> i64 {
+        (parent_reputation as i128 * self.reputation_share_bps as i128 / 10_000) as i64
+    }
+}
+
+/// An alias escrowed for sale. While listed, the alias is owned by this PDA.
+#[account]
+pub struct Listing {
+    pub alias: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub created_at: i64,
+    /// `created_at` of the listed alias, so a listing left behind cannot
+    /// act on a later alias registered at the same address.
+    pub alias_created_at: i64,
+}
+
+impl Listing {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 8;
+}
+
+#[account]
+pub struct CommitmentAccount {
+    pub registrant: Pubkey,
+    pub commitment: [u8; 32],
+    pub created_at: i64,
+}
+
+#[account]
+pub struct ReservedName {
+    pub suffix: String,
+    pub name_hash: [u8; 32],
+    pub reserved_by: Pubkey,
+}
+
+#[account]
+pub struct ReverseRecord {
+    pub owner: Pubkey,
+    pub alias: Option<Pubkey>,
+}
+
+#[account]
+pub struct AliasRecords {
+    pub alias: Pubkey,
+    pub records: Vec<TextRecord>,
+}
+
+impl AliasRecords {
+    pub fn space(records: &[TextRecord]) -> usize {
+        8 + 32 + 4 + records.iter().map(|r| 4 + r.key.len() + 4 + r.value.len()).sum::<usize>()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPurpose {
+    Signing,
+    Encryption,
+    Session,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AliasKey {
+    pub purpose: KeyPurpose,
+    pub key: Pubkey,
+    pub added_at: i64,
+}
+
+/// Additional keys the owner has authorized for an alias, by purpose.
+#[account]
+pub struct AliasKeys {
+    pub alias: Pubkey,
+    pub keys: Vec<AliasKey>,
+    /// Owner that authorized `keys`; they lapse once the alias changes hands.
+    pub owner: Pubkey,
+}
+
+impl AliasKeys {
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_ALIAS_KEYS * (1 + 32 + 8) + 32;
+
+    pub fn keys_for(&self, purpose: KeyPurpose) -> impl Iterator<Item = &Pubkey> {
+        self.keys.iter().filter(move |k| k.purpose == purpose).map(|k| &k.key)
+    }
+
+    /// Keys for `purpose`, or none if they were authorized by an owner
+    /// other than `owner`.
+    pub fn owner_keys_for(&self, owner: &Pubkey, purpose: KeyPurpose) -> impl Iterator<Item = &Pubkey> {
+        let current = self.owner == *owner;
+        self.keys_for(purpose).filter(move |_| current)
+    }
+}
+
+/// A short-lived key that may act for an alias within `scope` until `expires_at`.
+#[account]
+pub struct SessionKey {
+    pub alias: Pubkey,
+    pub key: Pubkey,
+    pub scope: u8,
+    pub expires_at: i64,
+    /// Owner that granted the session; it ends if the alias changes hands.
+    pub owner: Pubkey,
+}
+
+impl SessionKey {
+    pub const SPACE: usize = 8 + 32 + 32 + 1 + 8 + 32;
+
+    pub fn allows(&self, owner: &Pubkey, scope: u8, now: i64) -> bool {
+        self.owner == *owner && self.scope & scope == scope && now < self.expires_at
+    }
+}
+
+/// Assertion by the owners of two aliases that they are the same identity.
+/// `alias_a` is always the lower address so each pair has a single link.
+#[account]
+pub struct AliasLink {
+    pub alias_a: Pubkey,
+    pub alias_b: Pubkey,
+    pub created_at: i64,
+}
+
+impl AliasLink {
+    pub const SPACE: usize = 8 + 32 + 32 + 8;
+
+    /// The alias on the other side of the link from `alias`, if it is one
+    /// of the pair.
+    pub fn other(&self, alias: &Pubkey) -> Option<Pubkey> {
+        if self.alias_a == *alias {
+            Some(self.alias_b)
+        } else if self.alias_b == *alias {
+            Some(self.alias_a)
+        } else {
+            None
+        }
+    }
+}
+
+/// Admin-set weight of a suffix's scores when reputation is aggregated
+/// across linked aliases. A suffix without one weighs
+/// `DEFAULT_SUFFIX_WEIGHT_BPS`.
+#[account]
+pub struct SuffixWeight {
+    pub suffix: String,
+    pub weight_bps: u16,
+}
+
+impl SuffixWeight {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 2
+    }
+
+    /// Reads the weight at `info`, which callers constrain to the suffix's
+    /// weight address, falling back to the default.
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidAliasLink);
+        Self::try_deserialize(&mut &info.data.borrow()[..])
+    }
+}
+
+impl Default for SuffixWeight {
+    fn default() -> Self {
+        Self {
+            suffix: String::new(),
+            weight_bps: DEFAULT_SUFFIX_WEIGHT_BPS,
+        }
+    }
+}
+
+/// Cached result of `cache_aggregate_reputation` for an alias and the
+/// linked aliases it was computed over.
+#[account]
+pub struct AggregateReputation {
+    pub alias: Pubkey,
+    pub score: i64,
+    /// Aliases the score covers, including `alias` itself.
+    pub alias_count: u8,
+    pub updated_at: i64,
+}
+
+impl AggregateReputation {
+    pub const SPACE: usize = 8 + 32 + 8 + 1 + 8;
+}
+
+/// `reputation` as it stands at `now` after decaying since `updated_at`:
+/// halved for every full `half_life`, and linearly in between. A zero
+/// half-life disables decay.
+pub fn decay_reputation(reputation: i64, updated_at: i64, now: i64, half_life: i64) -> i64 {
+    let half_life = half_life as i128;
+    let elapsed = now.saturating_sub(updated_at) as i128;
+    if half_life <= 0 || elapsed <= 0 {
+        return reputation;
+    }
+    let halvings = elapsed / half_life;
+    if halvings >= 64 {
+        return 0;
+    }
+    let halved = reputation as i128 / (1 << halvings);
+    (halved - halved * (elapsed % half_life) / (2 * half_life)) as i64
+}
+
+/// Named reputation categories of a suffix. Category IDs start at 1, in
+/// the order they were added. A suffix without one has no categories.
+#[account]
+#[derive(Default)]
+pub struct ReputationCategories {
+    pub categories: Vec<ReputationCategory>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReputationCategory {
+    pub name: String,
+    /// Seconds for scores in this category to lose half their weight; zero
+    /// disables decay.
+    pub half_life: i64,
+}
+
+impl ReputationCategories {
+    pub const SPACE: usize = 8 + 4 + MAX_REPUTATION_CATEGORIES * (4 + MAX_REPUTATION_CATEGORY_NAME_LENGTH + 8);
+
+    /// Reads the categories at `info`, which callers constrain to the
+    /// suffix's categories address, falling back to none.
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidReputationCategory);
+        Self::try_deserialize(&mut &info.data.borrow()[..])
+    }
+
+    /// Decay half-life of `category`, taking the general score's from `config`.
+    pub fn half_life(&self, category: u8, config: &ReputationConfig) -> Result<i64> {
+        match category {
+            0 => Ok(config.reputation_half_life),
+            id => self.categories.get(id as usize - 1).map(|category| category.half_life).ok_or_else(|| error!(ErrorCode::InvalidReputationCategory)),
+        }
+    }
+}
+
+/// Running alias counts for a suffix, kept by every instruction that creates
+/// or removes one of its aliases.
+#[account]
+pub struct SuffixStats {
+    /// Aliases ever registered under the suffix.
+    pub total_registrations: u64,
+    /// Aliases currently open under the suffix.
+    pub active_aliases: u64,
+    /// Start of the current `REGISTRATION_WINDOW`, and registrations in it
+    /// and in the window before, for the sliding-window rate limit.
+    pub window_start: i64,
+    pub window_count: u32,
+    pub previous_window_count: u32,
+}
+
+impl SuffixStats {
+    pub const SPACE: usize = 8 + 8 + 8 + 8 + 4 + 4;
+
+    /// Like `record_registration`, but first enforces the suffix's total
+    /// quota and its daily rate, estimated as this window's registrations
+    /// plus the previous window's weighted by how much of it still overlaps
+    /// the last `REGISTRATION_WINDOW` seconds.
+    pub fn record_limited_registration(&mut self, config: &SuffixConfig, now: i64) -> Result<()> {
+        if config.max_total_registrations > 0 {
+            require!(self.total_registrations < config.max_total_registrations, ErrorCode::RegistrationQuotaExceeded);
+        }
+
+        let start = now - now.rem_euclid(REGISTRATION_WINDOW);
+        if start != self.window_start {
+            let adjacent = start - self.window_start == REGISTRATION_WINDOW;
+            self.previous_window_count = if adjacent { self.window_count } else { 0 };
+            self.window_count = 0;
+            self.window_start = start;
+        }
+        if config.max_registrations_per_day > 0 {
+            let overlap = REGISTRATION_WINDOW - (now - start);
+            let previous = self.previous_window_count as i64 * overlap / REGISTRATION_WINDOW;
+            require!(
+                previous + (self.window_count as i64) < config.max_registrations_per_day as i64,
+                ErrorCode::RegistrationRateLimited
+            );
+        }
+        self.window_count = self.window_count.saturating_add(1);
+
+        self.record_registration();
+        Ok(())
+    }
+
+    pub fn record_registration(&mut self) {
+        self.total_registrations = self.total_registrations.saturating_add(1);
+        self.active_aliases = self.active_aliases.saturating_add(1);
+    }
+
+    /// Aliases registered before the counter existed were never counted,
+    /// hence the saturation.
+    pub fn record_removal(&mut self) {
+        self.active_aliases = self.active_aliases.saturating_sub(1);
+    }
+}
+
+/// Holds the product owner's share of the suffix's registration fees until
+/// `withdraw_suffix_treasury`.
+#[account]
+pub struct SuffixTreasury {
+    /// Lifetime fees credited to the treasury.
+    pub collected: u64,
+}
+
+impl SuffixTreasury {
+    pub const SPACE: usize = 8 + 8;
+}
+
+/// A backend key the suffix's product owner lets register aliases in its
+/// place, up to `quota` registrations before `expires_at`.
+#[account]
+pub struct Registrar {
+    pub suffix: String,
+    pub registrar: Pubkey,
+    pub quota: u32,
+    pub used: u32,
+    pub expires_at: i64,
+}
+
+impl Registrar {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 32 + 4 + 4 + 8
+    }
+
+    /// Counts one registration against the quota.
+    pub fn consume(&mut self, now: i64) -> Result<()> {
+        require!(now < self.expires_at, ErrorCode::RegistrarExpired);
+        require!(self.used < self.quota, ErrorCode::RegistrarQuotaExceeded);
+        self.used += 1;
+        Ok(())
+    }
+}
+
+/// An oracle the suffix's product owner lets adjust reputation on its
+/// aliases. Its changes are scaled by `weight_bps`, and only apply once it
+/// has bonded `REPUTATION_AUTHORITY_BOND`, held in this account.
+#[account]
+pub struct ReputationAuthority {
+    pub suffix: String,
+    pub authority: Pubkey,
+    pub weight_bps: u16,
+    pub bond: u64,
+}
+
+impl ReputationAuthority {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 32 + 2 + 8
+    }
+
+    pub fn weigh(&self, change: i64) -> i64 {
+        (change as i128 * self.weight_bps as i128 / 10_000) as i64
+    }
+}
+
+/// An account the suffix's product owner lets slash its aliases for
+/// misconduct.
+#[account]
+pub struct ReputationSlasher {
+    pub suffix: String,
+    pub slasher: Pubkey,
+}
+
+impl ReputationSlasher {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 32
+    }
+}
+
+/// A partner, such as an exchange or KYC provider, the suffix's product owner
+/// lets grant temporary reputation boosts of up to `max_boost` to its
+/// aliases.
+#[account]
+pub struct ReputationAttestor {
+    pub suffix: String,
+    pub attestor: Pubkey,
+    pub max_boost: i64,
+}
+
+impl ReputationAttestor {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 32 + 8
+    }
+}
+
+/// A boost to an alias's effective reputation from one attestor, counted
+/// until `expires_at`. The attestor pays its rent and gets it back when the
+/// boost is closed.
+#[account]
+pub struct ReputationBoost {
+    pub alias: Pubkey,
+    pub attestor: Pubkey,
+    pub amount: i64,
+    pub expires_at: i64,
+}
+
+impl ReputationBoost {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Per-suffix registration policy and limits the product owner tunes within
+/// protocol ceilings. A suffix without one uses `SuffixConfig::default()`.
+/// Transferability stays on `SuffixAccount` and the starting score on
+/// `ReputationConfig`.
+#[account]
+pub struct SuffixConfig {
+    pub max_chain_mappings: u8,
+    /// Whether an alias may map more than one address to the same chain ID.
+    pub allow_multiple_per_chain: bool,
+    /// Username length bounds, within the protocol's own.
+    pub min_username_length: u8,
+    pub max_username_length: u8,
+    /// `USERNAME_CHARSET_*` flags for the characters a username may use.
+    pub username_charset: u8,
+    /// Lamports charged for each `register_alias`, split between the
+    /// suffix's treasury and the protocol.
+    pub registration_fee: u64,
+    /// Fees replacing `registration_fee` for the shortest usernames, indexed
+    /// by length above `MIN_USERNAME_LENGTH`; zero keeps the base fee.
+    pub premium_fees: [u64; PREMIUM_LENGTH_BUCKETS],
+    /// Closed registry: `register_alias` only admits owners with an
+    /// `AllowlistEntry` or a proof against the suffix's `Allowlist` root.
+    pub allowlist_mode: bool,
+    /// Caps on registrations under the suffix, per `REGISTRATION_WINDOW` and
+    /// ever; zero means unlimited.
+    pub max_registrations_per_day: u32,
+    pub max_total_registrations: u64,
+    /// Token the registration and renewal fees are charged in instead of
+    /// lamports, in its base units. Token fees skip the treasury and go
+    /// straight to the product owner's and admin's token accounts.
+    pub fee_mint: Option<Pubkey>,
+    /// Whether `register_alias` needs a proof against the circuit of
+    /// `registration_verifying_key`. Vouchers, batches and imports are
+    /// authorized by the product owner and skip it.
+    pub registration_proof_required: bool,
+    /// The `RegistrationVerifyingKey` account registrations prove against.
+    pub registration_verifying_key: Option<Pubkey>,
+}
+
+impl SuffixConfig {
+    pub const SPACE: usize = 8 + 1 + 1 + 1 + 1 + 1 + 8 + 8 * PREMIUM_LENGTH_BUCKETS + 1 + 4 + 8 + 33 + 1 + 33;
+
+    /// What registering `username` costs, in lamports or `fee_mint` units.
+    pub fn registration_fee_for(&self, username: &str) -> u64 {
+        username
+            .len()
+            .checked_sub(MIN_USERNAME_LENGTH)
+            .and_then(|bucket| self.premium_fees.get(bucket))
+            .copied()
+            .filter(|&fee| fee > 0)
+            .unwrap_or(self.registration_fee)
+    }
+
+    /// Reads the config at `info`, which callers constrain to the suffix's
+    /// config address, falling back to the defaults if it was never created.
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidSuffixConfig);
+        Self::try_deserialize(&mut &info.data.borrow()[..])
+    }
+}
+
+impl Default for SuffixConfig {
+    fn default() -> Self {
+        Self {
+            max_chain_mappings: MAX_CHAIN_MAPPINGS as u8,
+            allow_multiple_per_chain: false,
+            min_username_length: MIN_USERNAME_LENGTH as u8,
+            max_username_length: MAX_USERNAME_LENGTH as u8,
+            username_charset: USERNAME_CHARSET_ALL,
+            registration_fee: 0,
+            premium_fees: [0; PREMIUM_LENGTH_BUCKETS],
+            allowlist_mode: false,
+            max_registrations_per_day: 0,
+            max_total_registrations: 0,
+            fee_mint: None,
+            registration_proof_required: false,
+            registration_verifying_key: None,
+        }
+    }
+}
+
+/// How reputation works on a suffix, tuned by its product owner within
+/// protocol ceilings. A suffix without one uses
+/// `ReputationConfig::default()`.
+#[account]
+pub struct ReputationConfig {
+    /// General score a newly registered alias starts with.
+    pub initial_reputation: i64,
+    /// Seconds for an alias's reputation to lose half its weight; zero
+    /// disables decay.
+    pub reputation_half_life: i64,
+    /// Band reputation is clamped to after every update.
+    pub min_reputation: i64,
+    pub max_reputation: i64,
+    /// Largest absolute change a single `update_reputation` may request.
+    pub max_reputation_change: i64,
+    /// Length in seconds of an alias's reputation rate-limit window, and the
+    /// total absolute change allowed within one.
+    pub reputation_window: i64,
+    pub max_reputation_per_window: u64,
+    /// Who besides the admin may resolve disputes over the suffix's
+    /// reputation changes; the default key when only the admin may.
+    pub dispute_arbiter: Pubkey,
+    /// Effective reputation needed for each badge tier, ascending; unused
+    /// trailing tiers are zero.
+    pub tier_thresholds: [i64; MAX_REPUTATION_TIERS],
+    pub tier_hysteresis_bps: u16,
+    /// What happens to an alias's reputation when it changes hands.
+    pub reputation_transfer_policy: ReputationTransferPolicy,
+    /// General reputation a successful `verify_alias_ownership` earns, at
+    /// most once per cooldown; zero disables the reward.
+    pub verification_reward: i64,
+    pub verification_reward_cooldown: i64,
+    /// General score below which a decrease quarantines an alias, and how
+    /// long it stays quarantined unless an appeal succeeds first.
+    pub quarantine_floor: i64,
+    pub rehabilitation_period: i64,
+}
+
+impl ReputationConfig {
+    pub const SPACE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 8 * MAX_REPUTATION_TIERS + 2 + 1 + 8 + 8 + 8 + 8;
+
+    /// Reads the config at `info`, which callers constrain to the suffix's
+    /// reputation config address, falling back to the defaults if it was
+    /// never created.
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidReputationConfig);
+        Self::try_deserialize(&mut &info.data.borrow()[..])
+    }
+
+    /// Applies `change` to `reputation`, failing if the change exceeds the
+    /// per-update limit and clamping the result to the suffix's band.
+    pub fn apply_reputation_change(&self, reputation: i64, change: i64) -> Result<i64> {
+        require!(change.unsigned_abs() <= self.max_reputation_change as u64, ErrorCode::InvalidReputationChange);
+        let updated = reputation.checked_add(change).ok_or(ErrorCode::InvalidReputationChange)?;
+        Ok(updated.clamp(self.min_reputation, self.max_reputation))
+    }
+
+    /// Highest badge tier `reputation` reaches, or 0 for none.
+    pub fn tier_for(&self, reputation: i64) -> u8 {
+        self.tier_thresholds.iter().take_while(|&&threshold| threshold != 0 && reputation >= threshold).count() as u8
+    }
+
+    /// Whether an alias holding `tier` keeps it at `reputation`: it may fall
+    /// up to `tier_hysteresis_bps` below the tier's threshold.
+    pub fn keeps_tier(&self, tier: u8, reputation: i64) -> bool {
+        let Some(&threshold) = (tier as usize).checked_sub(1).and_then(|index| self.tier_thresholds.get(index)) else {
+            return tier == 0;
+        };
+        let margin = (threshold as i128 * self.tier_hysteresis_bps as i128 / 10_000) as i64;
+        threshold != 0 && reputation >= threshold - margin
+    }
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            initial_reputation: INITIAL_REPUTATION,
+            reputation_half_life: 0,
+            min_reputation: MIN_REPUTATION,
+            max_reputation: MAX_REPUTATION,
+            max_reputation_change: MAX_REPUTATION_CHANGE,
+            reputation_window: REPUTATION_RATE_WINDOW,
+            max_reputation_per_window: MAX_REPUTATION_PER_WINDOW,
+            dispute_arbiter: Pubkey::default(),
+            tier_thresholds: REPUTATION_TIER_THRESHOLDS,
+            tier_hysteresis_bps: REPUTATION_TIER_HYSTERESIS_BPS,
+            reputation_transfer_policy: ReputationTransferPolicy::CarryOver,
+            verification_reward: 0,
+            verification_reward_cooldown: VERIFICATION_REWARD_COOLDOWN,
+            quarantine_floor: MIN_REPUTATION,
+            rehabilitation_period: REHABILITATION_PERIOD,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReputationTransferPolicy {
+    /// The new owner keeps the score.
+    CarryOver,
+    /// Scores start over from zero.
+    Reset,
+    /// The general score moves to escrow, where it still counts towards
+    /// effective reputation but decays with `REPUTATION_ESCROW_HALF_LIFE`.
+    EscrowAndDecay,
+}
+
+/// Incremental Merkle tree state behind an alias's `chain_mappings_root`.
+#[account]
+pub struct ChainMappingTree {
+    pub alias: Pubkey,
+    pub tree: IncrementalTree,
+    pub root_history: RootHistory,
+    /// Whether the alias keeps its leaves in a `ChainMappingRegistry`.
+    pub has_registry: bool,
+    /// Live mappings per chain ID, sorted by chain ID, so the suffix's
+    /// per-chain rule can be enforced without seeing the leaves.
+    pub chain_counts: Vec<ChainMappingCount>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ChainMappingCount {
+    pub chain_id: u32,
+    pub count: u8,
+}
+
+impl ChainMappingCount {
+    pub const SPACE: usize = 4 + 1;
+}
+
+impl ChainMappingTree {
+    pub const SPACE: usize = 8 + 32 + IncrementalTree::SPACE + RootHistory::SPACE + 1 + 4 + ChainMappingCount::SPACE * MAX_CHAIN_MAPPINGS;
+
+    /// Counts a new mapping for `chain_id`, failing if the chain already has
+    /// one and `allow_multiple` is off.
+    pub fn claim_chain_id(&mut self, chain_id: u32, allow_multiple: bool) -> Result<()> {
+        match self.chain_counts.binary_search_by_key(&chain_id, |entry| entry.chain_id) {
+            Ok(position) => {
+                require!(allow_multiple, ErrorCode::ChainIDAlreadyExists);
+                self.chain_counts[position].count += 1;
+            }
+            Err(position) => self.chain_counts.insert(position, ChainMappingCount { chain_id, count: 1 }),
+        }
+        Ok(())
+    }
+
+    pub fn release_chain_id(&mut self, chain_id: u32) {
+        if let Ok(position) = self.chain_counts.binary_search_by_key(&chain_id, |entry| entry.chain_id) {
+            self.chain_counts[position].count -= 1;
+            if self.chain_counts[position].count == 0 {
+                self.chain_counts.remove(position);
+            }
+        }
+    }
+
+    /// Fails unless `registry` is passed exactly when the alias has one, so
+    /// the registry never falls out of sync with the root.
+    pub fn require_registry(&self, registry: Option<&ChainMappingRegistry>) -> Result<()> {
+        require!(self.has_registry == registry.is_some(), ErrorCode::ChainMappingRegistryMismatch);
+        Ok(())
+    }
+
+    /// Whether `proof` shows `mapping` is in a usable `alias`'s tree under
+    /// its current root or one of its `depth` most recent earlier roots, and
+    /// whether its attestation has lapsed.
+    pub fn verify(&self, alias: &AliasAccount, mapping: &AttestedChainMapping, proof: &ChainMappingProof, depth: usize, now: i64) -> ChainMappingVerification {
+        let usable = alias.require_usable(now).is_ok();
+        let leaf = hash_chain_mapping(self.tree.hash, mapping);
+        let mut roots = std::iter::once(&alias.chain_mappings_root).chain(self.root_history.recent(depth));
+        let valid = usable && roots.any(|root| verify_path(self.tree.hash, &proof.siblings, root, proof.index, leaf));
+        ChainMappingVerification { valid, stale: valid && mapping.is_stale(now) }
+    }
+
+    /// Like `verify`, for every mapping in `mappings` against a single
+    /// multiproof, hashing each shared node only once.
+    pub fn verify_multi(&self, alias: &AliasAccount, mappings: &[AttestedChainMapping], proof: &ChainMappingMultiProof, depth: usize, now: i64) -> ChainMappingVerification {
+        let usable = alias.require_usable(now).is_ok();
+        let root = (mappings.len() == proof.indices.len())
+            .then(|| {
+                let leaves: Vec<_> = proof.indices.iter().zip(mappings).map(|(&index, mapping)| (index, hash_chain_mapping(self.tree.hash, mapping))).collect();
+                root_from_multiproof(self.tree.hash, &leaves, &proof.siblings)
+            })
+            .flatten();
+        let valid = usable
+            && root.is_some_and(|root| std::iter::once(&alias.chain_mappings_root).chain(self.root_history.recent(depth)).any(|known| *known == root));
+        ChainMappingVerification { valid, stale: valid && mappings.iter().any(|mapping| mapping.is_stale(now)) }
+    }
+}
+
+/// Opt-in copy of an alias's chain mapping leaves, readable without proofs.
+#[account]
+pub struct ChainMappingRegistry {
+    pub alias: Pubkey,
+    pub entries: Vec<RegisteredChainMapping>,
+}
+
+impl ChainMappingRegistry {
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_REGISTRY_MAPPINGS * RegisteredChainMapping::SPACE;
+
+    pub fn insert(&mut self, index: u32, mapping: AttestedChainMapping) -> Result<()> {
+        require!(self.entries.len() < MAX_REGISTRY_MAPPINGS, ErrorCode::MaxChainMappingsReached);
+        self.entries.push(RegisteredChainMapping { index, mapping });
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: u32) {
+        self.entries.retain(|entry| entry.index != index);
+    }
+
+    pub fn replace(&mut self, index: u32, mapping: AttestedChainMapping) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.index == index) {
+            entry.mapping = mapping;
+        }
+    }
+}
+
+/// A registry entry: a mapping and its slot in the alias's tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisteredChainMapping {
+    pub index: u32,
+    pub mapping: AttestedChainMapping,
+}
+
+impl RegisteredChainMapping {
+    pub const SPACE: usize = 4 + 1 + 4 + 4 + MAX_CHAIN_ADDRESS_LENGTH + 4 + MAX_CHAIN_MAPPING_LABEL_LENGTH + 1 + 8 + 8;
+}
+
+/// Ring buffer of the last `MAX_ROOT_HISTORY` chain mapping roots, so proofs
+/// built against a slightly stale root still verify.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RootHistory {
+    pub roots: [[u8; 32]; MAX_ROOT_HISTORY],
+    pub head: u8,
+    pub len: u8,
+}
+
+impl RootHistory {
+    pub const SPACE: usize = 32 * MAX_ROOT_HISTORY + 1 + 1;
+
+    pub fn push(&mut self, root: [u8; 32]) {
+        self.roots[self.head as usize] = root;
+        self.head = ((self.head as usize + 1) % MAX_ROOT_HISTORY) as u8;
+        self.len = (self.len as usize + 1).min(MAX_ROOT_HISTORY) as u8;
+    }
+
+    /// The `depth` most recent roots, newest first.
+    pub fn recent(&self, depth: usize) -> impl Iterator<Item = &[u8; 32]> {
+        (1..=depth.min(self.len as usize)).map(move |i| &self.roots[(self.head as usize + MAX_ROOT_HISTORY - i) % MAX_ROOT_HISTORY])
+    }
+}
+
+/// Ring buffer of an alias's last `MAX_REPUTATION_HISTORY` reputation
+/// changes, so auditors can see who changed a score and why.
+#[account]
+pub struct ReputationHistory {
+    pub alias: Pubkey,
+    pub entries: [ReputationEntry; MAX_REPUTATION_HISTORY],
+    pub head: u8,
+    pub len: u8,
+}
+
+impl ReputationHistory {
+    pub const SPACE: usize = 8 + 32 + ReputationEntry::SPACE * MAX_REPUTATION_HISTORY + 1 + 1;
+
+    pub fn push(&mut self, entry: ReputationEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = ((self.head as usize + 1) % MAX_REPUTATION_HISTORY) as u8;
+        self.len = (self.len as usize + 1).min(MAX_REPUTATION_HISTORY) as u8;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ReputationEntry {
+    pub timestamp: i64,
+    /// Change to the stored score, after weighting and clamping.
+    pub delta: i64,
+    pub authority: Pubkey,
+    /// Suffix-defined code for why the score changed.
+    pub reason: u16,
+    pub category: u8,
+}
+
+impl ReputationEntry {
+    pub const SPACE: usize = 8 + 8 + 32 + 2 + 1;
+}
+
+/// The badge tier an alias has claimed, from 1 (Bronze) up to
+/// `MAX_REPUTATION_TIERS`; 0 once revoked.
+#[account]
+pub struct Badge {
+    pub alias: Pubkey,
+    pub tier: u8,
+    pub updated_at: i64,
+}
+
+impl Badge {
+    pub const SPACE: usize = 8 + 32 + 1 + 8;
+}
+
+/// An alias owner's challenge to one entry of its reputation history. The
+/// account holds `bond` until an arbiter resolves it, and is kept afterwards
+/// so the same entry cannot be disputed twice.
+#[account]
+pub struct Dispute {
+    pub alias: Pubkey,
+    pub opener: Pubkey,
+    /// Slot of the disputed entry in the alias's `ReputationHistory`.
+    pub history_index: u8,
+    pub entry: ReputationEntry,
+    pub bond: u64,
+    pub opened_at: i64,
+    pub status: DisputeStatus,
+    /// Whether this is a third party's `challenge_update`, which slashes the
+    /// entry's reputation authority if upheld for the challenger.
+    pub challenge: bool,
+}
+
+impl Dispute {
+    pub const SPACE: usize = 8 + 32 + 32 + 1 + ReputationEntry::SPACE + 8 + 8 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Reverted,
+}
+
+/// SOL bonded behind an alias's reputation, held in this account on top of
+/// its rent. Stakers own shares of `total_staked`, so a slash cuts every
+/// stake pro rata.
+#[account]
+#[derive(Default)]
+pub struct ReputationVault {
+    pub alias: Pubkey,
+    pub total_staked: u64,
+    pub total_shares: u64,
+}
+
+impl ReputationVault {
+    pub const SPACE: usize = 8 + 32 + 8 + 8;
+
+    /// Reads the vault at `info`, which callers constrain to the alias's
+    /// vault address, falling back to an empty one.
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidStake);
+        Self::try_deserialize(&mut &info.data.borrow()[..])
+    }
+
+    pub fn bonus(&self) -> i64 {
+        ((self.total_staked / LAMPORTS_PER_STAKED_POINT) as i64).min(MAX_STAKE_BONUS)
+    }
+
+    /// Shares minted for staking `amount` more lamports.
+    pub fn shares_for(&self, amount: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(amount);
+        }
+        // Once a vault is slashed to nothing its shares are worthless, and new
+        // stake would be diluted into them.
+        require!(self.total_staked > 0, ErrorCode::InvalidStake);
+        Ok((amount as u128 * self.total_shares as u128 / self.total_staked as u128) as u64)
+    }
+
+    /// Cuts `slash_bps` of the staked lamports, leaving shares as they are,
+    /// and returns how many were cut.
+    pub fn slash(&mut self, slash_bps: u16) -> u64 {
+        let cut = (self.total_staked as u128 * slash_bps as u128 / 10_000) as u64;
+        self.total_staked -= cut;
+        cut
+    }
+
+    /// Lamports `shares` are currently worth.
+    pub fn value_of(&self, shares: u64) -> u64 {
+        if self.total_shares == 0 {
+            return 0;
+        }
+        (shares as u128 * self.total_staked as u128 / self.total_shares as u128) as u64
+    }
+}
+
+/// One staker's shares in an alias's `ReputationVault`.
+#[account]
+pub struct ReputationStake {
+    pub alias: Pubkey,
+    pub staker: Pubkey,
+    pub shares: u64,
+    /// When the stake can be withdrawn; zero until `unstake` is called.
+    pub unlocks_at: i64,
+}
+
+impl ReputationStake {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8;
+}
+
+/// One alias vouching for another until `expires_at`.
+#[account]
+pub struct Endorsement {
+    pub endorser: Pubkey,
+    pub endorsee: Pubkey,
+    pub weight: u16,
+    pub expires_at: i64,
+}
+
+impl Endorsement {
+    pub const SPACE: usize = 8 + 32 + 32 + 2 + 8;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Endorsements an alias has given and not yet revoked, expired or not,
+/// capped at `MAX_ENDORSEMENTS_PER_ALIAS`.
+#[account]
+pub struct EndorsementQuota {
+    pub alias: Pubkey,
+    pub given: u8,
+}
+
+impl EndorsementQuota {
+    pub const SPACE: usize = 8 + 32 + 1;
+}
+
+/// Left behind at a closed or renamed alias address so resolvers can follow
+/// the redirect. `successor` is `None` when the alias is gone for good.
+#[account]
+pub struct Tombstone {
+    pub alias: Pubkey,
+    pub successor: Option<Pubkey>,
+    pub created_at: i64,
+}
+
+impl Tombstone {
+    pub const SPACE: usize = 8 + 32 + 1 + 32 + 8;
+}
+
+/// Merkle root of the owners a suffix in allowlist mode admits, with one
+/// `allowlist_leaf` per owner key.
+#[account]
+pub struct Allowlist {
+    pub root: [u8; 32],
+    pub committed_at: i64,
+}
+
+impl Allowlist {
+    pub const SPACE: usize = 8 + 32 + 8;
+}
+
+/// Admits `user` to a suffix in allowlist mode without a proof.
+#[account]
+pub struct AllowlistEntry {
+    pub user: Pubkey,
+    pub added_at: i64,
+}
+
+impl AllowlistEntry {
+    pub const SPACE: usize = 8 + 32 + 8;
+}
+
+/// The allowlist tree leaf for `user`, domain-separated from interior nodes.
+pub fn allowlist_leaf(user: &Pubkey) -> [u8; 32] {
+    hashv(&[b"allowlist_leaf", user.as_ref()]).to_bytes()
+}
+
+/// Merkle root of an identity snapshot being imported under `suffix`.
+#[account]
+pub struct ImportRoot {
+    pub suffix: String,
+    pub root: [u8; 32],
+    pub committed_by: Pubkey,
+    pub committed_at: i64,
+}
+
+impl ImportRoot {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 32 + 32 + 8
+    }
+}
+
+/// Marks an import snapshot leaf as claimed, so the leaf cannot recreate
+/// its alias once that alias is closed or reclaimed.
+#[account]
+pub struct ImportClaim {
+    pub alias: Pubkey,
+    pub claimed_at: i64,
+}
+
+impl ImportClaim {
+    pub const SPACE: usize = 8 + 32 + 8;
+}
+
+/// Reputation an alias has delegated to another alias for governance, and
+/// the total delegated to it. An alias delegates to at most one other alias
+/// at a time; one that never took part reads as
+/// `ReputationDelegation::default()`.
+#[account]
+#[derive(Default)]
+pub struct ReputationDelegation {
+    pub alias: Pubkey,
+    /// The alias receiving `delegated`; the default key when none.
+    pub delegate: Pubkey,
+    pub delegated: i64,
+    pub received: i64,
+}
+
+impl ReputationDelegation {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8;
+
+    /// Reads the delegation at `info`, which callers constrain to the
+    /// alias's delegation address, falling back to none.
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidDelegation);
+        Self::try_deserialize(&mut &info.data.borrow()[..])
+    }
+
+    /// `score` less what the alias delegated, plus what it received.
+    pub fn apply(&self, score: i64) -> i64 {
+        score.saturating_sub(self.delegated).saturating_add(self.received)
+    }
+}
+
+/// Merkle root of a suffix's general reputation scores at `slot`, committed
+/// by the admin or product owner for `epoch`. Scores are taken after
+/// `ReputationDelegation::apply`, as in aggregate reputation. Leaves are
+/// `ReputationSnapshot::leaf`, and once committed an epoch cannot change.
+#[account]
+pub struct ReputationSnapshot {
+    pub suffix: String,
+    pub epoch: u64,
+    pub root: [u8; 32],
+    pub slot: u64,
+    pub committed_by: Pubkey,
+    pub committed_at: i64,
+}
+
+impl ReputationSnapshot {
+    pub fn space(suffix: &str) -> usize {
+        8 + 4 + suffix.len() + 8 + 32 + 8 + 32 + 8
+    }
+
+    /// The snapshot leaf for `alias` scoring `score`, domain-separated from
+    /// interior nodes.
+    pub fn leaf(alias: &Pubkey, score: i64) -> [u8; 32] {
+        hashv(&[b"reputation_snapshot_leaf", alias.as_ref(), &score.to_le_bytes()]).to_bytes()
+    }
+}
+
+/// Admin-set verifying key of the reputation threshold circuit, the hook
+/// through which `verify_reputation_threshold` accepts proofs.
+#[account]
+pub struct ReputationVerifyingKey {
+    pub verifying_key: Groth16VerifyingKey,
+}
+
+impl ReputationVerifyingKey {
+    pub const SPACE: usize = 8 + Groth16VerifyingKey::SPACE;
+}
+
+/// Verifying key of a registration circuit, such as membership or
+/// uniqueness, that suffixes reference from their config. It cannot be
+/// replaced once created, so a referencing suffix always proves against the
+/// circuit it chose.
+#[account]
+pub struct RegistrationVerifyingKey {
+    pub authority: Pubkey,
+    pub verifying_key: Groth16VerifyingKey,
+    /// Circuit-specific public input, such as a truncated membership root.
+    pub predicate: [u8; 16],
+}
+
+impl RegistrationVerifyingKey {
+    pub const SPACE: usize = 8 + 32 + Groth16VerifyingKey::SPACE + 16;
+}
+
+/// Proof on record that `alias` scored at least `threshold` in its suffix's
+/// snapshot for `epoch`. Other programs derive it from the alias and
+/// threshold and check the epoch is recent enough for them.
+#[account]
+pub struct ReputationThresholdReceipt {
+    pub alias: Pubkey,
+    pub threshold: u64,
+    pub epoch: u64,
+    pub verified_at: i64,
+}
+
+impl ReputationThresholdReceipt {
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8;
+}
+
+/// Bitmap of consumed voucher nonces for one suffix, `VOUCHER_NONCES_PER_PAGE`
+/// nonces per page.
+#[account]
+pub struct VoucherNonces {
+    pub page: u64,
+    pub bits: [u8; 128],
+}
+
+impl VoucherNonces {
+    pub const SPACE: usize = 8 + 8 + (VOUCHER_NONCES_PER_PAGE / 8) as usize;
+
+    /// Marks `nonce` as used, returning false if it already was.
+    pub fn consume(&mut self, nonce: u64) -> bool {
+        let bit = (nonce % VOUCHER_NONCES_PER_PAGE) as usize;
+        let mask = 1u8 << (bit % 8);
+        if self.bits[bit / 8] & mask != 0 {
+            return false;
+        }
+        self.bits[bit / 8] |= mask;
+        true
+    }
+}
+
+/// Bounded log of retired owner keys so verifiers can still validate
+/// signatures made before a rotation. Oldest entries are dropped first.
+#[account]
+pub struct KeyHistory {
+    pub alias: Pubkey,
+    pub entries: Vec<RetiredKey>,
+}
+
+impl KeyHistory {
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_KEY_HISTORY * (32 + 8);
+
+    pub fn push(&mut self, entry: RetiredKey) {
+        if self.entries.len() >= MAX_KEY_HISTORY {
+            self.entries.remove(0);
+        }
+        self.entries.push(entry);
+    }
+}
+
+/// Guardians nominated by the alias owner to recover it if the owner key is lost.
+#[account]
+pub struct GuardianSet {
+    pub alias: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub recovery: Option<RecoveryRequest>,
+    /// Owner that nominated the guardians; the set only acts while this is
+    /// still the alias owner, so it does not survive a transfer or sale.
+    pub owner: Pubkey,
+}
+
+impl GuardianSet {
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_GUARDIANS * 32 + 1 + 1 + RecoveryRequest::SPACE + 32;
+
+    pub fn guardian_index(&self, key: &Pubkey) -> Option<usize> {
+        self.guardians.iter().position(|g| g == key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct RecoveryRequest {
+    pub new_owner: Pubkey,
+    /// Bitmask over guardian indices that approved the request.
+    pub approvals: u8,
+    pub initiated_at: i64,
+}
+
+impl RecoveryRequest {
+    pub const SPACE: usize = 32 + 1 + 8;
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct RetiredKey {
+    pub key: Pubkey,
+    pub retired_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct TextRecord {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ChainInfo {
+    pub name: String,
+    pub address: String, 
+    pub chain_id: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChainType {
+    Evm,
+    Solana,
+    Cosmos,
+    Bitcoin,
+    Aptos,
+}
+
+/// An additional address the alias owner claims on another chain. `address`
+/// holds raw bytes for EVM, Solana and Aptos, and the encoded address string
+/// for Cosmos and Bitcoin.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ChainMapping {
+    pub chain_type: ChainType,
+    pub chain_id: u32,
+    pub address: Vec<u8>,
+    /// Owner-chosen name for the address, e.g. "cold storage".
+    pub label: String,
+    /// Whether wallets should list the mapping in the alias's address book.
+    pub visible: bool,
+}
+
+/// A chain mapping as committed to the tree, stamped by the program when it
+/// was last attested.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct AttestedChainMapping {
+    pub mapping: ChainMapping,
+    pub attested_at: i64,
+    pub expires_at: i64,
+}
+
+impl AttestedChainMapping {
+    pub fn new(mapping: ChainMapping, now: i64) -> Self {
+        Self { mapping, attested_at: now, expires_at: now.saturating_add(CHAIN_MAPPING_ATTESTATION_PERIOD) }
+    }
+
+    pub fn is_stale(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Return data of `verify_chain_mapping` and `verify_chain_mappings_multi`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChainMappingVerification {
+    pub valid: bool,
+    /// Set when the mapping is valid but past its attestation expiry. For a
+    /// multiproof, set when any of its mappings is.
+    pub stale: bool,
+}
+
+/// Position of a leaf in an alias's chain mapping tree and its sibling path.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChainMappingProof {
+    /// `LeafCodec` version the proof's leaves were encoded with.
+    pub leaf_version: u8,
+    pub index: u32,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// Proof for several leaves of one tree at once. `indices` are strictly
+/// increasing and `siblings` holds, level by level from the leaves up, only
+/// the nodes that cannot be computed from the proven leaves themselves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChainMappingMultiProof {
+    /// `LeafCodec` version the proof's leaves were encoded with.
+    pub leaf_version: u8,
+    pub indices: Vec<u32>,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct AliasMetadata {
+    pub name: String,
+    pub image_url: String,
+}
+
+#[event]
+pub struct AliasRegistered {
+    pub username: String,
+    pub display_name: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub metadata: AliasMetadata,
+    pub user_address: Pubkey,
+    /// Branding of the suffix's product owner, if it published a profile.
+    pub operator: Option<ProductOwnerBranding>,
+}
+
+#[event]
+pub struct AliasReputationUpdated {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub metadata: AliasMetadata,
+    pub user_address: Pubkey,
+}
+
+#[event]
+pub struct AliasTransferred {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct AliasReputationTransferred {
+    pub alias: Pubkey,
+    pub policy: ReputationTransferPolicy,
+    pub previous_reputation: i64,
+    pub reputation: i64,
+    pub escrowed_reputation: i64,
+}
+
+#[event]
+pub struct AliasTransferProposed {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct AliasTransferCancelled {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AliasClosed {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub owner: Pubkey,
+    pub closed_by: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct AliasRenewed {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub expires_at: i64,
+    pub fee_paid: u64,
+}
+
+#[event]
+pub struct AliasRenamed {
+    pub old_username: String,
+    pub new_username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct NameReservationUpdated {
+    pub suffix: String,
+    pub name_hash: [u8; 32],
+    pub authority: Pubkey,
+    pub reserved: bool,
+}
+
+#[event]
+pub struct PrimaryAliasUpdated {
+    pub owner: Pubkey,
+    pub alias: Option<Pubkey>,
+}
+
+#[event]
+pub struct AliasControllerUpdated {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub owner: Pubkey,
+    pub controller: Pubkey,
+}
+
+#[event]
+pub struct AliasMetadataUpdated {
+    pub username: String,
+    pub display_name: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub metadata: AliasMetadata,
+}
+
+#[event]
+pub struct AliasRecordUpdated {
+    pub alias: Pubkey,
+    pub key: String,
+    pub value: Option<String>,
+}
+
+#[event]
+pub struct ContentHashUpdated {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub content_hash: [u8; 34],
+}
+
+#[event]
+pub struct AliasFreezeUpdated {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub authority: Pubkey,
+    pub is_frozen: bool,
+}
+
+#[event]
+pub struct AliasSuffixMigrated {
+    pub username: String,
+    pub old_suffix: String,
+    pub new_suffix: String,
+    pub chain_info: ChainInfo,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AliasAccountUpgraded {
+    pub alias: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+/// An unversioned account rewritten to its current layout, identified by
+/// the sizes before and after.
+#[event]
+pub struct AccountLayoutUpgraded {
+    pub account: Pubkey,
+    pub from_len: u32,
+    pub to_len: u32,
+}
+
+#[event]
+pub struct OwnerKeyRotated {
+    pub username: String,
+    pub suffix: String,
+    pub chain_info: ChainInfo,
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+}
+
+#[event]
+pub struct GuardiansUpdated {
+    pub alias: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct RecoveryInitiated {
+    pub alias: Pubkey,
+    pub guardian: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct RecoveryApproved {
+    pub alias: Pubkey,
+    pub guardian: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub alias: Pubkey,
+}
+
+#[event]
+pub struct RecoveryExecuted {
+    pub alias: Pubkey,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct SubAliasRegistered {
+    pub parent: Pubkey,
+    pub label: String,
+    pub owner: Pubkey,
+    pub reputation_share_bps: u16,
+}
+
+#[event]
+pub struct SubAliasClosed {
+    pub parent: Pubkey,
+    pub label: String,
+}
+
+#[event]
+pub struct AliasTokenMinted {
+    pub alias: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AliasListed {
+    pub alias: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+}
+
+#[event]
+pub struct AliasListingCancelled {
+    pub alias: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct AliasSold {
+    pub alias: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub royalty: u64,
+}
+
+#[event]
+pub struct AliasKeyUpdated {
+    pub alias: Pubkey,
+    pub purpose: KeyPurpose,
+    pub key: Pubkey,
+    pub added: bool,
+}
+
+#[event]
+pub struct AliasNonceAdvanced {
+    pub alias: Pubkey,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct ReputationCategoryUpdated {
+    pub suffix: String,
+    pub category: u8,
+    pub name: String,
+    pub half_life: i64,
+}
+
+#[event]
+pub struct ReputationStaked {
+    pub alias: Pubkey,
+    pub staker: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+}
+
+#[event]
+pub struct ReputationUnstakeRequested {
+    pub alias: Pubkey,
+    pub staker: Pubkey,
+    pub shares: u64,
+    pub unlocks_at: i64,
+}
+
+#[event]
+pub struct ReputationStakeWithdrawn {
+    pub alias: Pubkey,
+    pub staker: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EndorsementUpdated {
+    pub endorser: Pubkey,
+    pub endorsee: Pubkey,
+    pub weight: u16,
+    pub expires_at: i64,
+    pub revoked: bool,
+}
+
+#[event]
+pub struct ReputationSlasherUpdated {
+    pub suffix: String,
+    pub slasher: Pubkey,
+    pub removed: bool,
+}
+
+#[event]
+pub struct BadgeUpdated {
+    pub alias: Pubkey,
+    pub tier: u8,
+    pub previous_tier: u8,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub alias: Pubkey,
+    pub opener: Pubkey,
+    pub history_index: u8,
+    pub bond: u64,
+}
+
+#[event]
+pub struct ProductOwnerBondUpdated {
+    pub owner: Pubkey,
+    pub bond: u64,
+    /// Lamports the admin took from the bond, if any.
+    pub slashed: u64,
+}
+
+#[event]
+pub struct ReputationBondUpdated {
+    pub suffix: String,
+    pub authority: Pubkey,
+    pub bond: u64,
+    /// Lamports paid out of the bond to a successful challenger, if any.
+    pub slashed: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub alias: Pubkey,
+    pub arbiter: Pubkey,
+    pub history_index: u8,
+    pub reverted: bool,
+}
+
+#[event]
+pub struct Slashed {
+    pub alias: Pubkey,
+    pub slasher: Pubkey,
+    pub slash_bps: u16,
+    pub reputation_cut: i64,
+    pub stake_cut: u64,
+    /// Hash of the misconduct report, such as the digest of its IPFS CID.
+    pub evidence_hash: [u8; 32],
+}
+
+#[event]
+pub struct ReputationAuthorityUpdated {
+    pub suffix: String,
+    pub authority: Pubkey,
+    pub weight_bps: u16,
+    pub removed: bool,
+}
+
+#[event]
+pub struct RegistrationFeePaid {
+    pub suffix: String,
+    pub alias: Pubkey,
+    /// Length of the username, which selects its price tier.
+    pub username_length: u8,
+    pub fee: u64,
+    pub protocol_fee: u64,
+}
+
+#[event]
+pub struct SuffixTreasuryWithdrawn {
+    pub suffix: String,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RegistrarUpdated {
+    pub suffix: String,
+    pub registrar: Pubkey,
+    pub quota: u32,
+    pub expires_at: i64,
+    pub removed: bool,
+}
+
+#[event]
+pub struct SessionUpdated {
+    pub alias: Pubkey,
+    pub key: Pubkey,
+    pub scope: u8,
+    pub expires_at: i64,
+    pub revoked: bool,
+}
+
+#[event]
+pub struct AliasesLinked {
+    pub alias_a: Pubkey,
+    pub alias_b: Pubkey,
+}
+
+#[event]
+pub struct SuffixWeightUpdated {
+    pub suffix: String,
+    pub weight_bps: u16,
+}
+
+/// Every change to a stored score, for indexers. Decay is emitted as its own
+/// change under `DECAY_REASON` when it is applied.
+#[event]
+pub struct ReputationChanged {
+    pub alias: Pubkey,
+    /// 0 for the general score, or one of the suffix's categories.
+    pub category: u8,
+    pub old: i64,
+    pub new: i64,
+    pub delta: i64,
+    /// Who made the change; the default key for decay.
+    pub authority: Pubkey,
+    pub reason: u16,
+}
+
+#[event]
+pub struct ReputationAttestorUpdated {
+    pub suffix: String,
+    pub attestor: Pubkey,
+    pub max_boost: i64,
+    pub removed: bool,
+}
+
+#[event]
+pub struct ReputationBoostUpdated {
+    pub alias: Pubkey,
+    pub attestor: Pubkey,
+    /// Zero when the boost is closed.
+    pub amount: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct ReputationDelegated {
+    pub alias: Pubkey,
+    pub delegate: Pubkey,
+    /// Zero when the delegation is withdrawn.
+    pub amount: i64,
+}
+
+#[event]
+pub struct AliasQuarantineUpdated {
+    pub alias: Pubkey,
+    pub is_quarantined: bool,
+    pub reputation: i64,
+    /// The updater whose change quarantined the alias, or who lifted it.
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct VerificationRewarded {
+    pub alias: Pubkey,
+    pub change: i64,
+    pub reputation: i64,
+}
+
+#[event]
+pub struct AggregateReputationUpdated {
+    pub alias: Pubkey,
+    pub score: i64,
+    pub alias_count: u8,
+}
+
+#[event]
+pub struct AliasesUnlinked {
+    pub alias_a: Pubkey,
+    pub alias_b: Pubkey,
+    pub unlinked_by: Pubkey,
+}
+
+#[event]
+pub struct TombstoneWritten {
+    pub alias: Pubkey,
+    pub successor: Option<Pubkey>,
+}
+
+#[event]
+pub struct ProductOwnerControlRenounced {
+    pub alias: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ReferralRecorded {
+    pub alias: Pubkey,
+    pub referrer: Pubkey,
+}
+
+#[event]
+pub struct ProductOwnerProfileUpdated {
+    pub owner: Pubkey,
+    pub branding: ProductOwnerBranding,
+}
+
+#[event]
+pub struct AllowlistRootCommitted {
+    pub suffix: String,
+    pub root: [u8; 32],
+}
+
+#[event]
+pub struct AllowlistEntryUpdated {
+    pub suffix: String,
+    pub user: Pubkey,
+    pub removed: bool,
+}
+
+#[event]
+pub struct ImportRootCommitted {
+    pub suffix: String,
+    pub root: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReputationSnapshotCreated {
+    pub suffix: String,
+    pub epoch: u64,
+    pub root: [u8; 32],
+    pub slot: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReputationThresholdVerified {
+    pub alias: Pubkey,
+    pub threshold: u64,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct ImportedAliasClaimed {
+    pub alias: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct DisplayNameUpdated {
+    pub alias: Pubkey,
+    pub username: String,
+    pub display_name: String,
+}
+
+/// Chain mapping events carry each leaf's hash and slot so an off-chain
+/// indexer can rebuild the tree and serve proofs. `RootUpdated` follows every
+/// mutation with the resulting root.
+#[event]
+pub struct ChainMappingAdded {
+    pub alias: Pubkey,
+    pub index: u32,
+    pub leaf: [u8; 32],
+    pub mapping: AttestedChainMapping,
+}
+
+#[event]
+pub struct ChainMappingRemoved {
+    pub alias: Pubkey,
+    pub index: u32,
+    pub leaf: [u8; 32],
+    pub mapping: AttestedChainMapping,
+}
+
+#[event]
+pub struct ChainMappingRotated {
+    pub alias: Pubkey,
+    pub index: u32,
+    /// The new leaf now in slot `index`.
+    pub leaf: [u8; 32],
+    pub chain_id: u32,
+    pub old_address: Vec<u8>,
+    pub new_address: Vec<u8>,
+    pub attested_at: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct ChainMappingReattested {
+    pub alias: Pubkey,
+    pub index: u32,
+    /// The new leaf now in slot `index`.
+    pub leaf: [u8; 32],
+    pub chain_id: u32,
+    pub attested_at: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct RootUpdated {
+    pub alias: Pubkey,
+    pub chain_mappings_root: [u8; 32],
+    pub chain_mapping_count: u8,
+    pub chain_mappings_version: u32,
+}
+
+#[event]
+pub struct SuffixLeaseUpdated {
+    pub suffix: String,
+    pub owner: Pubkey,
+    pub expires_at: i64,
+    pub fee_paid: u64,
+}
+
+#[event]
+pub struct SubSuffixCreated {
+    pub suffix: String,
+    pub parent: String,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct RegistrationVerifyingKeyCreated {
+    pub key: Pubkey,
+    pub authority: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct SuffixUpdated {
+    pub suffix: String,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ProductOwnerStatusUpdated {
+    pub suffix: String,
+    pub owner: Pubkey,
+    pub is_active: bool,
+    pub is_frozen: bool,
+}
+
+#[event]
+pub struct SuffixPauseUpdated {
+    pub suffix: String,
+    pub is_paused: bool,
+}
+
+#[event]
+pub struct ProductOwnerKeyRotationProposed {
+    pub suffix: String,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct SuffixTransferred {
+    pub suffix: String,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+    /// Whether the admin arbitrated instead of the previous owner signing.
+    pub by_admin: bool,
+}
+
+#[event]
+pub struct ProductOwnerKeyRotated {
+    pub suffix: String,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ProductOwnerRemoved {
     pub suffix: String,
     pub owner: Pubkey,
 }
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use crate::error::EchoIDError as ErrorCode;
+use crate::state::{ChainType, ProductOwnerBranding, SuffixConfig, MAX_CHAIN_ADDRESS_LENGTH, MAX_CHAIN_MAPPING_LABEL_LENGTH, MAX_PRODUCT_OWNER_NAME_LENGTH, MAX_PRODUCT_OWNER_URI_LENGTH};
+
+pub const MIN_USERNAME_LENGTH: usize = 3;
+// PDA seeds are limited to 32 bytes each.
+pub const MAX_USERNAME_LENGTH: usize = 32;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+pub const MIN_SUFFIX_LENGTH: usize = 2;
+pub const MAX_SUFFIX_LENGTH: usize = 16;
+// A sub-suffix's full dotted path still seeds PDAs on its own.
+pub const MAX_SUFFIX_PATH_LENGTH: usize = 32;
+/// Suffixes no product owner may claim, as they would read as the protocol's
+/// own or the chain's namespace.
+pub const RESERVED_SUFFIXES: &[&str] = &["sol", "admin", "system", "root", "echo"];
+
+// Character classes a suffix may allow in usernames.
+pub const USERNAME_CHARSET_LETTERS: u8 = 1 << 0;
+pub const USERNAME_CHARSET_DIGITS: u8 = 1 << 1;
+pub const USERNAME_CHARSET_SEPARATORS: u8 = 1 << 2;
+pub const USERNAME_CHARSET_ALL: u8 = USERNAME_CHARSET_LETTERS | USERNAME_CHARSET_DIGITS | USERNAME_CHARSET_SEPARATORS;
+
+fn is_separator(c: char) -> bool {
+    c == '-' || c == '_'
+}
+
+/// Characters a display name may add on top of the canonical username.
+fn is_display_only(c: char) -> bool {
+    c == '.' || c == ' '
+}
+
+/// Enforces the canonical username form: lowercase ASCII alphanumerics with
+/// single `-`/`_` separators that never start or end the name.
+pub fn validate_username(username: &str) -> Result<()> {
+    require!(username.len() >= MIN_USERNAME_LENGTH, ErrorCode::UsernameTooShort);
+    require!(username.len() <= MAX_USERNAME_LENGTH, ErrorCode::UsernameTooLong);
+
+    let mut previous: Option<char> = None;
+    for c in username.chars() {
+        require!(c.is_ascii(), ErrorCode::UsernameConfusableCharacter);
+        require!(!c.is_ascii_uppercase(), ErrorCode::UsernameNotLowercase);
+        require!(c.is_ascii_lowercase() || c.is_ascii_digit() || is_separator(c), ErrorCode::UsernameInvalidCharacter);
+        if is_separator(c) {
+            require!(previous.is_some_and(|p| !is_separator(p)), ErrorCode::UsernameInvalidSeparator);
+        }
+        previous = Some(c);
+    }
+    require!(previous.is_some_and(|p| !is_separator(p)), ErrorCode::UsernameInvalidSeparator);
+
+    Ok(())
+}
+
+/// Suffixes are lowercase ASCII alphanumerics only. The suffix PDA is seeded
+/// by the exact bytes, so a single canonical form is what keeps two owners
+/// from holding names that differ only in case.
+pub fn validate_suffix(suffix: &str) -> Result<()> {
+    require!((MIN_SUFFIX_LENGTH..=MAX_SUFFIX_LENGTH).contains(&suffix.len()), ErrorCode::InvalidSuffixName);
+    require!(suffix.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()), ErrorCode::InvalidSuffixName);
+    require!(!RESERVED_SUFFIXES.contains(&suffix), ErrorCode::SuffixReserved);
+    Ok(())
+}
+
+/// The full dotted path of the child namespace `label` under `parent`, e.g.
+/// `team.acme`.
+pub fn sub_suffix_path(label: &str, parent: &str) -> String {
+    format!("{label}.{parent}")
+}
+
+/// Labels follow the suffix character rules, without the reserved list,
+/// which only guards top-level names; the full path must fit in a seed.
+pub fn validate_sub_suffix(label: &str, parent: &str) -> Result<()> {
+    require!((MIN_SUFFIX_LENGTH..=MAX_SUFFIX_LENGTH).contains(&label.len()), ErrorCode::InvalidSuffixName);
+    require!(label.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()), ErrorCode::InvalidSuffixName);
+    require!(sub_suffix_path(label, parent).len() <= MAX_SUFFIX_PATH_LENGTH, ErrorCode::SuffixPathTooLong);
+    Ok(())
+}
+
+/// Enforces the canonical username form, then the suffix's own length bounds
+/// and character classes.
+pub fn validate_suffix_username(username: &str, config: &SuffixConfig) -> Result<()> {
+    validate_username(username)?;
+    require!(username.len() >= config.min_username_length as usize, ErrorCode::UsernameTooShort);
+    require!(username.len() <= config.max_username_length as usize, ErrorCode::UsernameTooLong);
+
+    for c in username.chars() {
+        let class = if c.is_ascii_lowercase() {
+            USERNAME_CHARSET_LETTERS
+        } else if c.is_ascii_digit() {
+            USERNAME_CHARSET_DIGITS
+        } else {
+            USERNAME_CHARSET_SEPARATORS
+        };
+        require!(config.username_charset & class != 0, ErrorCode::UsernameInvalidCharacter);
+    }
+
+    Ok(())
+}
+
+/// Checks a product owner's registration policy against the protocol's own
+/// username rules; a suffix may narrow them but never widen them.
+pub fn validate_username_policy(config: &SuffixConfig) -> Result<()> {
+    let (min, max) = (config.min_username_length as usize, config.max_username_length as usize);
+    require!(min >= MIN_USERNAME_LENGTH && min <= max && max <= MAX_USERNAME_LENGTH, ErrorCode::InvalidSuffixConfig);
+    require!(config.username_charset & !USERNAME_CHARSET_ALL == 0, ErrorCode::InvalidSuffixConfig);
+    require!(config.username_charset & (USERNAME_CHARSET_LETTERS | USERNAME_CHARSET_DIGITS) != 0, ErrorCode::InvalidSuffixConfig);
+
+    Ok(())
+}
+
+/// A display name may only differ from `username` in letter case and in
+/// `.`/space characters, so it can never render as a different name. Non-ASCII
+/// characters are rejected outright to rule out homoglyphs.
+pub fn validate_display_name(display_name: &str, username: &str) -> Result<()> {
+    require!(display_name.len() <= MAX_DISPLAY_NAME_LENGTH, ErrorCode::DisplayNameTooLong);
+    require!(display_name.is_ascii(), ErrorCode::UsernameConfusableCharacter);
+
+    let normalized: String = display_name
+        .chars()
+        .filter(|c| !is_display_only(*c))
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    require!(normalized == username, ErrorCode::DisplayNameMismatch);
+
+    Ok(())
+}
+
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const BASE58_CHARSET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = (checksum & 0x1ff_ffff) << 5 ^ value as u32;
+        (0..5).filter(|i| top >> i & 1 == 1).fold(checksum, |c, i| c ^ GENERATOR[i])
+    })
+}
+
+/// Returns the human-readable part of a lowercase bech32 or bech32m string
+/// with a valid checksum.
+fn bech32_hrp(address: &[u8]) -> Option<&[u8]> {
+    if address.len() > MAX_CHAIN_ADDRESS_LENGTH || address.iter().any(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let separator = address.iter().rposition(|&c| c == b'1')?;
+    let (hrp, data) = (&address[..separator], &address[separator + 1..]);
+    if hrp.is_empty() || data.len() < 6 || hrp.iter().any(|c| !(33..=126).contains(c)) {
+        return None;
+    }
+    let data = data
+        .iter()
+        .map(|c| BECH32_CHARSET.iter().position(|x| x == c).map(|v| v as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    let expanded = hrp.iter().map(|c| c >> 5).chain([0]).chain(hrp.iter().map(|c| c & 31));
+    let checksum = bech32_polymod(expanded.chain(data));
+    (checksum == BECH32_CONST || checksum == BECH32M_CONST).then_some(hrp)
+}
+
+/// Rejects addresses that cannot belong to `chain_type`, so malformed
+/// mappings never reach the mappings root.
+pub fn validate_chain_address(chain_type: ChainType, address: &[u8]) -> Result<()> {
+    match chain_type {
+        ChainType::Evm => require!(address.len() == 20, ErrorCode::InvalidEvmAddress),
+        ChainType::Solana => require!(address.len() == 32, ErrorCode::InvalidSolanaAddress),
+        ChainType::Aptos => require!(address.len() == 32, ErrorCode::InvalidAptosAddress),
+        ChainType::Cosmos => {
+            let hrp = bech32_hrp(address).ok_or(ErrorCode::InvalidCosmosAddress)?;
+            require!(hrp.iter().all(|c| c.is_ascii_lowercase()), ErrorCode::InvalidCosmosAddress);
+        }
+        ChainType::Bitcoin => {
+            let valid = match bech32_hrp(address) {
+                Some(hrp) => hrp == b"bc" || hrp == b"tb",
+                None => (25..=35).contains(&address.len()) && address.iter().all(|c| BASE58_CHARSET.contains(c)),
+            };
+            require!(valid, ErrorCode::InvalidBitcoinAddress);
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_chain_mapping_label(label: &str) -> Result<()> {
+    require!(label.len() <= MAX_CHAIN_MAPPING_LABEL_LENGTH, ErrorCode::ChainMappingLabelTooLong);
+    Ok(())
+}
+
+pub fn validate_product_owner_branding(branding: &ProductOwnerBranding) -> Result<()> {
+    require!(branding.display_name.len() <= MAX_PRODUCT_OWNER_NAME_LENGTH, ErrorCode::ProductOwnerProfileTooLong);
+    require!(branding.logo_uri.len() <= MAX_PRODUCT_OWNER_URI_LENGTH, ErrorCode::ProductOwnerProfileTooLong);
+    require!(branding.website.len() <= MAX_PRODUCT_OWNER_URI_LENGTH, ErrorCode::ProductOwnerProfileTooLong);
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing};
+use anchor_lang::solana_program::hash::hashv;
+use crate::error::EchoIDError as ErrorCode;
+
+/// Public inputs of the reputation threshold circuit, in order: the alias
+/// key and the snapshot root, each split into two 16-byte halves so they fit
+/// the BN254 scalar field, then the threshold.
+pub const THRESHOLD_PUBLIC_INPUTS: usize = 5;
+
+/// Public inputs of a suffix's registration circuit, in order: the owner key
+/// and the name hash, each split into two 16-byte halves, then the predicate.
+pub const REGISTRATION_PUBLIC_INPUTS: usize = 5;
+
+/// Public inputs a stored verifying key has room for; circuits with fewer
+/// leave the trailing `ic` points unused.
+pub const MAX_PUBLIC_INPUTS: usize = if THRESHOLD_PUBLIC_INPUTS > REGISTRATION_PUBLIC_INPUTS {
+    THRESHOLD_PUBLIC_INPUTS
+} else {
+    REGISTRATION_PUBLIC_INPUTS
+};
+
+/// BN254 base field modulus, big-endian.
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// A Groth16 proof over BN254, points encoded big-endian as the alt_bn128
+/// syscalls expect.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Groth16Proof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
+
+/// Verifying key of a circuit with up to `MAX_PUBLIC_INPUTS` public inputs,
+/// produced by the circuit's setup: the reputation threshold circuit, or a
+/// suffix's registration circuit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Groth16VerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+    pub ic: [[u8; 64]; MAX_PUBLIC_INPUTS + 1],
+}
+
+impl Groth16VerifyingKey {
+    pub const SPACE: usize = 64 + 128 * 3 + 64 * (MAX_PUBLIC_INPUTS + 1);
+}
+
+/// Public inputs binding a threshold proof to `alias`, `snapshot_root` and
+/// `threshold`, as big-endian field elements.
+pub fn threshold_public_inputs(alias: &Pubkey, snapshot_root: &[u8; 32], threshold: u64) -> [[u8; 32]; THRESHOLD_PUBLIC_INPUTS] {
+    let mut inputs = [[0u8; 32]; THRESHOLD_PUBLIC_INPUTS];
+    for (input, half) in inputs.iter_mut().zip(alias.as_ref().chunks_exact(16).chain(snapshot_root.chunks_exact(16))) {
+        input[16..].copy_from_slice(half);
+    }
+    inputs[4][24..].copy_from_slice(&threshold.to_be_bytes());
+    inputs
+}
+
+/// Public inputs binding a registration proof to the owner, the name being
+/// registered and the circuit's `predicate`, laid out as described on
+/// `REGISTRATION_PUBLIC_INPUTS`.
+pub fn registration_public_inputs(owner: &Pubkey, username: &str, suffix: &str, predicate: &[u8; 16]) -> [[u8; 32]; REGISTRATION_PUBLIC_INPUTS] {
+    let name = hashv(&[username.as_bytes(), b"@", suffix.as_bytes()]).to_bytes();
+    let mut inputs = [[0u8; 32]; REGISTRATION_PUBLIC_INPUTS];
+    for (input, half) in inputs.iter_mut().zip(owner.as_ref().chunks_exact(16).chain(name.chunks_exact(16))) {
+        input[16..].copy_from_slice(half);
+    }
+    inputs[4][16..].copy_from_slice(predicate);
+    inputs
+}
+
+/// Negates a G1 point by replacing y with p - y; the point at infinity is
+/// its own negation.
+fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    let mut negated = *point;
+    if point.iter().all(|&b| b == 0) {
+        return negated;
+    }
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let difference = FIELD_MODULUS[i] as i16 - point[32 + i] as i16 - borrow;
+        borrow = (difference < 0) as i16;
+        negated[32 + i] = difference.rem_euclid(256) as u8;
+    }
+    negated
+}
+
+/// Checks `proof` against `inputs` with the pairing equation
+/// e(-A, B) · e(alpha, beta) · e(vk_x, gamma) · e(C, delta) = 1. Malformed
+/// points fail with `error`, the caller's invalid-proof error, rather than
+/// returning false.
+pub fn verify_groth16(vk: &Groth16VerifyingKey, proof: &Groth16Proof, inputs: &[[u8; 32]], error: ErrorCode) -> Result<bool> {
+    if inputs.len() >= vk.ic.len() {
+        return Err(error.into());
+    }
+    let invalid = |_| error;
+    let mut vk_x = vk.ic[0].to_vec();
+    for (input, ic) in inputs.iter().zip(&vk.ic[1..]) {
+        let term = alt_bn128_multiplication(&[&ic[..], &input[..]].concat()).map_err(invalid)?;
+        vk_x = alt_bn128_addition(&[&vk_x[..], &term[..]].concat()).map_err(invalid)?;
+    }
+
+    let pairing_input = [
+        &negate_g1(&proof.a)[..], &proof.b[..],
+        &vk.alpha_g1[..], &vk.beta_g2[..],
+        &vk_x[..], &vk.gamma_g2[..],
+        &proof.c[..], &vk.delta_g2[..],
+    ]
+    .concat();
+    let result = alt_bn128_pairing(&pairing_input).map_err(invalid)?;
+    Ok(result.last() == Some(&1))
+}
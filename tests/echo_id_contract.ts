@@ -1,8 +1,16 @@
 import * as anchor from "@coral-xyz/anchor";
 import { AnchorError, type Program } from "@coral-xyz/anchor";
-import { Keypair, SystemProgram, PublicKey } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  Secp256k1Program,
+  SystemProgram,
+  PublicKey,
+} from "@solana/web3.js";
 import { EchoIdContract } from "../target/types/echo_id_contract";
 import { expect } from "chai";
+import { createHash } from "crypto";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 describe("echo_id_contract", () => {
   console.log("Setting up test environment...");
@@ -23,6 +31,287 @@ describe("echo_id_contract", () => {
     return keypair;
   }
 
+  function reservedNamePda(name: string, suffix = projectSuffix): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reserved"),
+        Buffer.from(suffix),
+        createHash("sha256").update(name).digest(),
+      ],
+      program.programId
+    )[0];
+  }
+
+  const MAPPING_TREE_DEPTH = 8;
+
+  const CHAIN_TYPES = ["evm", "solana", "cosmos", "bitcoin", "aptos"];
+  const LEAF_VERSION = 2;
+
+  type TreeHash = (data: Buffer) => Buffer;
+  const sha256: TreeHash = (data) => createHash("sha256").update(data).digest();
+  const keccak256: TreeHash = (data) => Buffer.from(keccak_256(data));
+
+  function mappingLeaf(
+    attested: {
+      mapping: {
+        chainType: object;
+        chainId: number;
+        address: Buffer;
+        label: string;
+        visible: boolean;
+      };
+      attestedAt: anchor.BN;
+      expiresAt: anchor.BN;
+    },
+    hash: TreeHash = sha256
+  ): Buffer {
+    const { mapping } = attested;
+    const chainType = CHAIN_TYPES.indexOf(Object.keys(mapping.chainType)[0]);
+    const chainId = Buffer.alloc(4);
+    chainId.writeUInt32LE(mapping.chainId);
+    const addressLength = Buffer.alloc(2);
+    addressLength.writeUInt16LE(mapping.address.length);
+    const label = Buffer.from(mapping.label);
+    const labelLength = Buffer.alloc(2);
+    labelLength.writeUInt16LE(label.length);
+    return hash(
+      Buffer.concat([
+        Buffer.from("chain_mapping"),
+        Buffer.from([LEAF_VERSION, chainType]),
+        chainId,
+        addressLength,
+        mapping.address,
+        labelLength,
+        label,
+        Buffer.from([mapping.visible ? 1 : 0]),
+        attested.attestedAt.toArrayLike(Buffer, "le", 8),
+        attested.expiresAt.toArrayLike(Buffer, "le", 8),
+      ])
+    );
+  }
+
+  // Events the program emitted in a confirmed transaction, as an off-chain
+  // indexer would read them.
+  async function emittedEvents(signature: string, name: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(tx.meta.logMessages)]
+      .filter((event) => event.name.toLowerCase() === name.toLowerCase())
+      .map((event) => event.data as any);
+  }
+
+  // Mappings as the program stamped them into the tree.
+  async function addedMappings(signature: string) {
+    const events = await emittedEvents(signature, "ChainMappingAdded");
+    return events.map((event) => event.mapping);
+  }
+
+  // Sibling path for slot `index` of the fixed-depth chain mapping tree,
+  // with empty slots holding an all-zero leaf.
+  function mappingProof(
+    leaves: Buffer[],
+    index: number,
+    hash: TreeHash = sha256
+  ) {
+    let level = Array.from(
+      { length: 1 << MAPPING_TREE_DEPTH },
+      (_, i) => leaves[i] ?? Buffer.alloc(32)
+    );
+    const siblings: number[][] = [];
+    for (let depth = 0; depth < MAPPING_TREE_DEPTH; depth++) {
+      siblings.push([...level[(index >> depth) ^ 1]]);
+      const next: Buffer[] = [];
+      for (let i = 0; i < level.length; i += 2) {
+        next.push(hash(Buffer.concat([level[i], level[i + 1]])));
+      }
+      level = next;
+    }
+    return { leafVersion: LEAF_VERSION, index, siblings };
+  }
+
+  // Sets the suffix config, with every field not in `overrides` at the
+  // protocol default.
+  function setSuffixConfig(
+    overrides: Partial<
+      Parameters<typeof program.methods.setSuffixConfig>[1]
+    > = {}
+  ) {
+    return program.methods
+      .setSuffixConfig(projectSuffix, {
+        maxChainMappings: 32,
+        allowMultiplePerChain: false,
+        minUsernameLength: 3,
+        maxUsernameLength: 32,
+        usernameCharset: 0b111,
+        registrationFee: new anchor.BN(0),
+        premiumFees: [0, 0, 0, 0].map((fee) => new anchor.BN(fee)),
+        allowlistMode: false,
+        maxRegistrationsPerDay: 0,
+        maxTotalRegistrations: new anchor.BN(0),
+        feeMint: null,
+        registrationProofRequired: false,
+        registrationVerifyingKey: null,
+        ...overrides,
+      })
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+  }
+
+  // Sets the reputation config, with every field not in `overrides` at the
+  // protocol default.
+  function setReputationConfig(
+    overrides: Partial<
+      Parameters<typeof program.methods.setReputationConfig>[1]
+    > = {}
+  ) {
+    return program.methods
+      .setReputationConfig(projectSuffix, {
+        initialReputation: new anchor.BN(10),
+        reputationHalfLife: new anchor.BN(0),
+        minReputation: new anchor.BN(-1_000_000),
+        maxReputation: new anchor.BN(1_000_000),
+        maxReputationChange: new anchor.BN(10_000),
+        reputationWindow: new anchor.BN(24 * 60 * 60),
+        maxReputationPerWindow: new anchor.BN(50_000),
+        disputeArbiter: PublicKey.default,
+        tierThresholds: [100, 1_000, 10_000, 100_000].map(
+          (threshold) => new anchor.BN(threshold)
+        ),
+        tierHysteresisBps: 1_000,
+        reputationTransferPolicy: { carryOver: {} },
+        verificationReward: new anchor.BN(0),
+        verificationRewardCooldown: new anchor.BN(24 * 60 * 60),
+        quarantineFloor: new anchor.BN(-1_000_000),
+        rehabilitationPeriod: new anchor.BN(30 * 24 * 60 * 60),
+        ...overrides,
+      })
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+  }
+
+  // Multiproof for the slots in `indices`: level by level, only the
+  // siblings that are not on the path of another proven leaf.
+  function mappingMultiProof(
+    leaves: Buffer[],
+    indices: number[],
+    hash: TreeHash = sha256
+  ) {
+    let level = Array.from(
+      { length: 1 << MAPPING_TREE_DEPTH },
+      (_, i) => leaves[i] ?? Buffer.alloc(32)
+    );
+    let known = indices;
+    const siblings: number[][] = [];
+    for (let depth = 0; depth < MAPPING_TREE_DEPTH; depth++) {
+      for (const index of known) {
+        if (!known.includes(index ^ 1)) {
+          siblings.push([...level[index ^ 1]]);
+        }
+      }
+      known = [...new Set(known.map((index) => index >> 1))];
+      const next: Buffer[] = [];
+      for (let i = 0; i < level.length; i += 2) {
+        next.push(hash(Buffer.concat([level[i], level[i + 1]])));
+      }
+      level = next;
+    }
+    return { leafVersion: LEAF_VERSION, indices, siblings };
+  }
+
+  function registrationDigest(
+    name: string,
+    chainName: string,
+    owner: PublicKey
+  ): Buffer {
+    return createHash("sha256")
+      .update(name)
+      .update("@")
+      .update(projectSuffix)
+      .update(chainName)
+      .update(owner.toBuffer())
+      .digest();
+  }
+
+  function registrationMessage(
+    domain: string,
+    name: string,
+    chainName: string,
+    owner: PublicKey,
+    expiresAt: anchor.BN
+  ): Buffer {
+    return createHash("sha256")
+      .update(domain)
+      .update(registrationDigest(name, chainName, owner))
+      .update(expiresAt.toArrayLike(Buffer, "le", 8))
+      .digest();
+  }
+
+  async function registerTestAlias(
+    name: string,
+    owner: Keypair,
+    chainName = "evm",
+    allowlistProof: number[][] | null = null
+  ): Promise<PublicKey> {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(name),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from(chainName),
+      ],
+      program.programId
+    );
+    await program.methods
+      .registerAlias({
+        username: name,
+        suffix: projectSuffix,
+        chainInfo: {
+          name: chainName,
+          address: "0x1234567890123456789012345678901234567890",
+          chainId: 1,
+        },
+        metadata: { name, imageUrl: "" },
+        userAddress: owner.publicKey,
+        salt: null,
+        ownerManaged: true,
+        referrer: null,
+        allowlistProof,
+        registrationProof: null,
+        signatureExpiresAt: new anchor.BN(0),
+      })
+      .accounts({
+        user: owner.publicKey,
+        payer: owner.publicKey,
+        reservedName: reservedNamePda(name),
+        commitmentAccount: null,
+        referrerAlias: null,
+        instructions: null,
+        productOwner: null,
+        protocolTreasury: adminKeypair.publicKey,
+        registrar: null,
+        registrationVerifyingKey: null,
+        feeMint: null,
+        tokenProgram: null,
+        payerTokenAccount: null,
+        protocolTokenAccount: null,
+        ownerTokenAccount: null,
+      })
+      .signers([owner])
+      .rpc();
+    return pda;
+  }
+
   let adminPda: PublicKey;
   let adminKeypair: Keypair;
   let productOwnerPda: PublicKey;
@@ -31,7 +320,7 @@ describe("echo_id_contract", () => {
   let aliasOwnerKeypair: Keypair;
   let aliasPda: PublicKey;
   let aliasPdaEvm: PublicKey;
-  const projectSuffix = "echoId";
+  const projectSuffix = "echoid";
   const username = "saurabh";
 
   before(async () => {
@@ -96,7 +385,7 @@ describe("echo_id_contract", () => {
         suffixAccount: suffixPda,
         systemProgram: SystemProgram.programId,
       })
-      .signers([adminKeypair])
+      .signers([adminKeypair, productOwnerKeypair])
       .rpc();
     console.log("Register product owner transaction:", tx);
 
@@ -114,12 +403,12 @@ describe("echo_id_contract", () => {
     console.log("Testing product owner registration with suffix...");
 
     const [newsuffixPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("suffix"), Buffer.from("echoId2")],
+      [Buffer.from("suffix"), Buffer.from("echoid2")],
       program.programId
     );
 
     const tx = await program.methods
-      .registerProductOwner("echoId2")
+      .registerProductOwner("echoid2")
       .accounts({
         admin: adminKeypair.publicKey,
         adminConfig: adminPda,
@@ -128,7 +417,7 @@ describe("echo_id_contract", () => {
         suffixAccount: newsuffixPda,
         systemProgram: SystemProgram.programId,
       })
-      .signers([adminKeypair])
+      .signers([adminKeypair, productOwnerKeypair])
       .rpc();
     console.log("Register product owner transaction:", tx);
 
@@ -140,7 +429,7 @@ describe("echo_id_contract", () => {
       productOwnerKeypair.publicKey.toBase58()
     );
     expect(suffixAccount.isActive).to.be.true;
-    expect(suffixAccount.suffix).to.equal("echoId2");
+    expect(suffixAccount.suffix).to.equal("echoid2");
 
     console.log("Product owner registered with suffix successfully");
   });
@@ -183,10 +472,29 @@ describe("echo_id_contract", () => {
         chainInfo,
         metadata,
         userAddress: aliasOwnerKeypair.publicKey,
+        salt: null,
+        ownerManaged: true,
+        referrer: null,
+        allowlistProof: null,
+        registrationProof: null,
+        signatureExpiresAt: new anchor.BN(0),
       })
       .accounts({
         user: aliasOwnerKeypair.publicKey,
         payer: aliasOwnerKeypair.publicKey,
+        reservedName: reservedNamePda(username),
+        commitmentAccount: null,
+        referrerAlias: null,
+        instructions: null,
+        productOwner: null,
+        protocolTreasury: adminKeypair.publicKey,
+        registrar: null,
+        registrationVerifyingKey: null,
+        feeMint: null,
+        tokenProgram: null,
+        payerTokenAccount: null,
+        protocolTokenAccount: null,
+        ownerTokenAccount: null,
         // suffixAccount: suffixPda,
         // aliasAccount: aliasPdaEvm,
         // systemProgram: SystemProgram.programId,
@@ -245,10 +553,29 @@ describe("echo_id_contract", () => {
         chainInfo,
         metadata,
         userAddress: aliasOwnerKeypair.publicKey,
+        salt: null,
+        ownerManaged: true,
+        referrer: null,
+        allowlistProof: null,
+        registrationProof: null,
+        signatureExpiresAt: new anchor.BN(0),
       })
       .accounts({
         user: aliasOwnerKeypair.publicKey,
         payer: aliasOwnerKeypair.publicKey,
+        reservedName: reservedNamePda(username),
+        commitmentAccount: null,
+        referrerAlias: null,
+        instructions: null,
+        productOwner: null,
+        protocolTreasury: adminKeypair.publicKey,
+        registrar: null,
+        registrationVerifyingKey: null,
+        feeMint: null,
+        tokenProgram: null,
+        payerTokenAccount: null,
+        protocolTokenAccount: null,
+        ownerTokenAccount: null,
         // suffixAccount: suffixPda,
         // aliasAccount: aliasPdaSolana,
         // systemProgram: SystemProgram.programId,
@@ -281,12 +608,15 @@ describe("echo_id_contract", () => {
         username,
         projectSuffix,
         chainName,
-        new anchor.BN(reputationChange)
+        new anchor.BN(reputationChange),
+        0,
+        0
       )
       .accounts({
-        admin: adminKeypair.publicKey,
+        authority: adminKeypair.publicKey,
         adminConfig: adminPda,
         aliasAccount: aliasPdaEvm,
+        reputationAuthority: null,
       })
       .signers([adminKeypair])
       .rpc();
@@ -302,4 +632,4519 @@ describe("echo_id_contract", () => {
     expect(updatedAliasAccount.reputation.toNumber()).to.equal(30); // 10 (initial) + 20 (change)
     console.log("Reputation updated successfully");
   });
+
+  it("Transfers an alias to a new owner", async () => {
+    console.log("Testing alias transfer...");
+    const newOwner = Keypair.generate();
+    const transferPda = await registerTestAlias(
+      "transferme",
+      aliasOwnerKeypair
+    );
+
+    const tx = await program.methods
+      .transferAlias("transferme", projectSuffix, "evm", newOwner.publicKey)
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        productOwner: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    console.log("Transfer alias transaction:", tx);
+
+    const aliasAccount = await program.account.aliasAccount.fetch(transferPda);
+    expect(aliasAccount.owner.toBase58()).to.equal(
+      newOwner.publicKey.toBase58()
+    );
+    console.log("Alias transferred successfully");
+  });
+
+  it("Proposes and accepts a two-step alias transfer", async () => {
+    console.log("Testing two-step alias transfer...");
+    const newOwner = Keypair.generate();
+    const handoffPda = await registerTestAlias("handoff", aliasOwnerKeypair);
+
+    await program.methods
+      .proposeTransfer("handoff", projectSuffix, "evm", newOwner.publicKey)
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        productOwner: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    let aliasAccount = await program.account.aliasAccount.fetch(handoffPda);
+    expect(aliasAccount.pendingOwner.toBase58()).to.equal(
+      newOwner.publicKey.toBase58()
+    );
+
+    await program.methods
+      .acceptTransfer("handoff", projectSuffix, "evm")
+      .accounts({
+        newOwner: newOwner.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([newOwner])
+      .rpc();
+
+    aliasAccount = await program.account.aliasAccount.fetch(handoffPda);
+    expect(aliasAccount.owner.toBase58()).to.equal(
+      newOwner.publicKey.toBase58()
+    );
+    expect(aliasAccount.pendingOwner).to.be.null;
+    console.log("Two-step transfer completed successfully");
+  });
+
+  it("Closes an alias and reclaims rent", async () => {
+    console.log("Testing alias close...");
+    const closePda = await registerTestAlias("closeme", aliasOwnerKeypair);
+    const recipient = Keypair.generate();
+
+    const tx = await program.methods
+      .closeAlias("closeme", projectSuffix, "evm")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        recipient: recipient.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+        tombstone: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    console.log("Close alias transaction:", tx);
+
+    const closedAccount = await provider.connection.getAccountInfo(closePda);
+    expect(closedAccount).to.be.null;
+    const recipientBalance = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    expect(recipientBalance).to.be.greaterThan(0);
+    console.log("Alias closed successfully");
+  });
+
+  it("Renews an alias term", async () => {
+    console.log("Testing alias renewal...");
+    const renewPda = await registerTestAlias("renewme", aliasOwnerKeypair);
+    const before = await program.account.aliasAccount.fetch(renewPda);
+
+    await program.methods
+      .renewAlias("renewme", projectSuffix, "evm")
+      .accounts({
+        payer: aliasOwnerKeypair.publicKey,
+        productOwner: productOwnerKeypair.publicKey,
+        feeMint: null,
+        tokenProgram: null,
+        payerTokenAccount: null,
+        ownerTokenAccount: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const after = await program.account.aliasAccount.fetch(renewPda);
+    expect(after.expiresAt.toNumber()).to.equal(
+      before.expiresAt.toNumber() + 365 * 24 * 60 * 60
+    );
+    console.log("Alias renewed successfully");
+  });
+
+  it("Renames an alias and keeps its reputation", async () => {
+    console.log("Testing alias rename...");
+    const oldPda = await registerTestAlias("typoname", aliasOwnerKeypair);
+    const [newPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("fixedname"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    const [tombstonePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), oldPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .renameAlias("typoname", projectSuffix, "evm", "fixedname")
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        reservedName: reservedNamePda("fixedname"),
+        tombstone: tombstonePda,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(oldPda)).to.be.null;
+    const renamed = await program.account.aliasAccount.fetch(newPda);
+    expect(renamed.username).to.equal("fixedname");
+    expect(renamed.reputation.toNumber()).to.equal(10);
+    const tombstone = await program.account.tombstone.fetch(tombstonePda);
+    expect(tombstone.successor.toBase58()).to.equal(newPda.toBase58());
+    console.log("Alias renamed successfully");
+  });
+
+  it("Registers aliases in a batch (product owner only)", async () => {
+    console.log("Testing batch alias registration...");
+    const names = ["batchone", "batchtwo"];
+    const signatureExpiresAt = new anchor.BN(
+      Math.floor(Date.now() / 1000) + 3600
+    );
+    const params = names.map((name) => ({
+      username: name,
+      suffix: projectSuffix,
+      chainInfo: {
+        name: "evm",
+        address: "0x1234567890123456789012345678901234567890",
+        chainId: 1,
+      },
+      metadata: { name, imageUrl: "" },
+      userAddress: aliasOwnerKeypair.publicKey,
+      salt: null,
+      ownerManaged: true,
+      referrer: null,
+      allowlistProof: null,
+      registrationProof: null,
+      signatureExpiresAt,
+    }));
+    const pdas = names.map(
+      (name) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from(name),
+            Buffer.from("@"),
+            Buffer.from(projectSuffix),
+            Buffer.from("evm"),
+          ],
+          program.programId
+        )[0]
+    );
+
+    await program.methods
+      .registerAliasesBatch(projectSuffix, params)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .remainingAccounts(
+        pdas.flatMap((pubkey, i) => [
+          { pubkey, isWritable: true, isSigner: false },
+          {
+            pubkey: reservedNamePda(names[i]),
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+      )
+      .preInstructions(
+        names.map((name) =>
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: aliasOwnerKeypair.secretKey,
+            message: registrationMessage(
+              "registration_consent",
+              name,
+              "evm",
+              aliasOwnerKeypair.publicKey,
+              signatureExpiresAt
+            ),
+          })
+        )
+      )
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    for (const [i, pda] of pdas.entries()) {
+      const aliasAccount = await program.account.aliasAccount.fetch(pda);
+      expect(aliasAccount.username).to.equal(names[i]);
+      expect(aliasAccount.owner.toBase58()).to.equal(
+        aliasOwnerKeypair.publicKey.toBase58()
+      );
+    }
+    console.log("Batch registration completed successfully");
+  });
+
+  it("Rejects a username that is not in canonical form", async () => {
+    console.log("Testing username policy...");
+    try {
+      await registerTestAlias("Not-Canonical", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "UsernameNotLowercase"
+      );
+    }
+    console.log("Non-canonical username rejected");
+  });
+
+  it("Blocks registration of a reserved name", async () => {
+    console.log("Testing reserved names...");
+    const signatureExpiresAt = new anchor.BN(
+      Math.floor(Date.now() / 1000) + 3600
+    );
+    const nameHash = Array.from(
+      createHash("sha256").update("trademark").digest()
+    );
+
+    await program.methods
+      .reserveName(projectSuffix, nameHash)
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    try {
+      await registerTestAlias("trademark", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "NameReserved"
+      );
+    }
+
+    const [trademarkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("trademark"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    try {
+      await program.methods
+        .registerAliasesBatch(projectSuffix, [
+          {
+            username: "trademark",
+            suffix: projectSuffix,
+            chainInfo: {
+              name: "evm",
+              address: "0x1234567890123456789012345678901234567890",
+              chainId: 1,
+            },
+            metadata: { name: "trademark", imageUrl: "" },
+            userAddress: aliasOwnerKeypair.publicKey,
+            salt: null,
+            ownerManaged: true,
+            referrer: null,
+            allowlistProof: null,
+            registrationProof: null,
+            signatureExpiresAt,
+          },
+        ])
+        .accounts({
+          productOwner: productOwnerKeypair.publicKey,
+          payer: productOwnerKeypair.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: trademarkPda, isWritable: true, isSigner: false },
+          {
+            pubkey: reservedNamePda("trademark"),
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: aliasOwnerKeypair.secretKey,
+            message: registrationMessage(
+              "registration_consent",
+              "trademark",
+              "evm",
+              aliasOwnerKeypair.publicKey,
+              signatureExpiresAt
+            ),
+          }),
+        ])
+        .signers([productOwnerKeypair])
+        .rpc();
+      expect.fail("batch registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("NameReserved");
+    }
+
+    await program.methods
+      .releaseName(projectSuffix, nameHash)
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await registerTestAlias("trademark", aliasOwnerKeypair);
+    console.log("Reserved name released and registered");
+  });
+
+  it("Sets a primary alias and clears it when the alias is closed", async () => {
+    console.log("Testing reverse records...");
+    const primaryPda = await registerTestAlias("primary", aliasOwnerKeypair);
+    const [reversePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reverse"), aliasOwnerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .setPrimaryAlias("primary", projectSuffix, "evm")
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    let reverseRecord = await program.account.reverseRecord.fetch(reversePda);
+    expect(reverseRecord.alias.toBase58()).to.equal(primaryPda.toBase58());
+
+    await program.methods
+      .closeAlias("primary", projectSuffix, "evm")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        recipient: aliasOwnerKeypair.publicKey,
+        reverseRecord: reversePda,
+        tokenAccount: null,
+        tokenProgram: null,
+        tombstone: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    reverseRecord = await program.account.reverseRecord.fetch(reversePda);
+    expect(reverseRecord.alias).to.be.null;
+    console.log("Reverse record cleared on close");
+  });
+
+  it("Lets a controller key update alias metadata", async () => {
+    console.log("Testing controller key...");
+    const controller = await createAndFundKeypair();
+    const controlledPda = await registerTestAlias(
+      "controlled",
+      aliasOwnerKeypair
+    );
+
+    await program.methods
+      .setController("controlled", projectSuffix, "evm", controller.publicKey)
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const metadata = { name: "controlled", imageUrl: "https://example.com" };
+    await program.methods
+      .updateAliasMetadata("controlled", projectSuffix, "evm", metadata)
+      .accounts({
+        authority: controller.publicKey,
+      })
+      .signers([controller])
+      .rpc();
+
+    const aliasAccount = await program.account.aliasAccount.fetch(
+      controlledPda
+    );
+    expect(aliasAccount.controller.toBase58()).to.equal(
+      controller.publicKey.toBase58()
+    );
+    expect(aliasAccount.metadata.imageUrl).to.equal(metadata.imageUrl);
+    console.log("Controller updated metadata successfully");
+  });
+
+  it("Sets and deletes alias text records", async () => {
+    console.log("Testing text records...");
+    const recordsAliasPda = await registerTestAlias(
+      "withrecords",
+      aliasOwnerKeypair
+    );
+    const [recordsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("records"), recordsAliasPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .setRecord("withrecords", projectSuffix, "evm", "twitter", "@echoid")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    let records = await program.account.aliasRecords.fetch(recordsPda);
+    expect(records.records).to.deep.equal([
+      { key: "twitter", value: "@echoid" },
+    ]);
+
+    await program.methods
+      .deleteRecord("withrecords", projectSuffix, "evm", "twitter")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    records = await program.account.aliasRecords.fetch(recordsPda);
+    expect(records.records).to.be.empty;
+    console.log("Text records updated successfully");
+  });
+
+  it("Registers an alias with a product owner voucher", async () => {
+    console.log("Testing voucher registration...");
+    const relayer = await createAndFundKeypair();
+    const name = "vouched";
+    const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const nonce = new anchor.BN(7);
+    const voucherMessage = createHash("sha256")
+      .update("voucher")
+      .update(registrationDigest(name, "evm", aliasOwnerKeypair.publicKey))
+      .update(expiresAt.toArrayLike(Buffer, "le", 8))
+      .update(nonce.toArrayLike(Buffer, "le", 8))
+      .digest();
+    const [voucherNoncesPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("voucher_nonces"),
+        Buffer.from(projectSuffix),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .registerAliasWithVoucher(
+        {
+          username: name,
+          suffix: projectSuffix,
+          chainInfo: {
+            name: "evm",
+            address: "0x1234567890123456789012345678901234567890",
+            chainId: 1,
+          },
+          metadata: { name, imageUrl: "" },
+          userAddress: aliasOwnerKeypair.publicKey,
+          salt: null,
+          ownerManaged: true,
+          referrer: null,
+          allowlistProof: null,
+          registrationProof: null,
+          signatureExpiresAt: new anchor.BN(0),
+        },
+        { expiresAt, nonce }
+      )
+      .accounts({
+        relayer: relayer.publicKey,
+        user: aliasOwnerKeypair.publicKey,
+        reservedName: reservedNamePda(name),
+        voucherNonces: voucherNoncesPda,
+        referrerAlias: null,
+      })
+      .preInstructions([
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: productOwnerKeypair.secretKey,
+          message: voucherMessage,
+        }),
+      ])
+      .signers([relayer, aliasOwnerKeypair])
+      .rpc();
+
+    const voucherNonces = await program.account.voucherNonces.fetch(
+      voucherNoncesPda
+    );
+    expect(voucherNonces.bits[0]).to.equal(1 << 7);
+    console.log("Voucher registration completed successfully");
+  });
+
+  it("Freezes an alias and blocks reputation updates", async () => {
+    console.log("Testing alias freeze...");
+    const frozenPda = await registerTestAlias("frozen", aliasOwnerKeypair);
+
+    await program.methods
+      .freezeAlias("frozen", projectSuffix, "evm")
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    expect((await program.account.aliasAccount.fetch(frozenPda)).isFrozen).to
+      .be.true;
+
+    try {
+      await program.methods
+        .updateReputation(
+          "frozen",
+          projectSuffix,
+          "evm",
+          new anchor.BN(5),
+          0,
+          0
+        )
+        .accounts({
+          authority: adminKeypair.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: frozenPda,
+          reputationAuthority: null,
+        })
+        .signers([adminKeypair])
+        .rpc();
+      expect.fail("reputation update should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "AliasFrozen"
+      );
+    }
+
+    await program.methods
+      .unfreezeAlias("frozen", projectSuffix, "evm")
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    expect((await program.account.aliasAccount.fetch(frozenPda)).isFrozen).to
+      .be.false;
+    console.log("Alias freeze and unfreeze succeeded");
+  });
+
+  it("Migrates an alias to another suffix", async () => {
+    console.log("Testing suffix migration...");
+    const sourcePda = await registerTestAlias("migrant", aliasOwnerKeypair);
+    const [destinationPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("migrant"),
+        Buffer.from("@"),
+        Buffer.from("echoid2"),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    const [destinationReservedPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reserved"),
+        Buffer.from("echoid2"),
+        createHash("sha256").update("migrant").digest(),
+      ],
+      program.programId
+    );
+    const [tombstonePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), sourcePda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .migrateAliasSuffix("migrant", projectSuffix, "evm", "echoid2")
+      .accounts({
+        sourceProductOwner: productOwnerKeypair.publicKey,
+        destinationProductOwner: productOwnerKeypair.publicKey,
+        owner: aliasOwnerKeypair.publicKey,
+        reservedName: destinationReservedPda,
+        tombstone: tombstonePda,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(sourcePda)).to.be.null;
+    const tombstone = await program.account.tombstone.fetch(tombstonePda);
+    expect(tombstone.successor.toBase58()).to.equal(destinationPda.toBase58());
+    const migrated = await program.account.aliasAccount.fetch(destinationPda);
+    expect(migrated.productSuffix).to.equal("echoid2");
+    expect(migrated.reputation.toNumber()).to.equal(10);
+    console.log("Alias migrated successfully");
+  });
+
+  it("Rotates the owner key and records the retired key", async () => {
+    console.log("Testing owner key rotation...");
+    const rotator = await createAndFundKeypair();
+    const newKey = Keypair.generate();
+    const rotatedPda = await registerTestAlias("rotated", rotator);
+    const [keyHistoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("key_history"), rotatedPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .rotateOwnerKey("rotated", projectSuffix, "evm")
+      .accounts({
+        owner: rotator.publicKey,
+        newOwner: newKey.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([rotator, newKey])
+      .rpc();
+
+    const aliasAccount = await program.account.aliasAccount.fetch(rotatedPda);
+    expect(aliasAccount.owner.toBase58()).to.equal(newKey.publicKey.toBase58());
+    const keyHistory = await program.account.keyHistory.fetch(keyHistoryPda);
+    expect(keyHistory.entries[0].key.toBase58()).to.equal(
+      rotator.publicKey.toBase58()
+    );
+    console.log("Owner key rotated successfully");
+  });
+
+  it("Collects guardian approvals for a recovery", async () => {
+    console.log("Testing social recovery...");
+    const guardians = [Keypair.generate(), Keypair.generate()];
+    const recoveredOwner = Keypair.generate();
+    const recoverablePda = await registerTestAlias(
+      "recoverable",
+      aliasOwnerKeypair
+    );
+    const [guardianSetPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("guardians"), recoverablePda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .setGuardians(
+        "recoverable",
+        projectSuffix,
+        "evm",
+        guardians.map((g) => g.publicKey),
+        2
+      )
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    await program.methods
+      .initiateRecovery(
+        "recoverable",
+        projectSuffix,
+        "evm",
+        recoveredOwner.publicKey
+      )
+      .accounts({
+        guardian: guardians[0].publicKey,
+      })
+      .signers([guardians[0]])
+      .rpc();
+
+    await program.methods
+      .approveRecovery("recoverable", projectSuffix, "evm")
+      .accounts({
+        guardian: guardians[1].publicKey,
+      })
+      .signers([guardians[1]])
+      .rpc();
+
+    const guardianSet = await program.account.guardianSet.fetch(
+      guardianSetPda
+    );
+    expect(guardianSet.recovery.newOwner.toBase58()).to.equal(
+      recoveredOwner.publicKey.toBase58()
+    );
+    expect(guardianSet.recovery.approvals).to.equal(0b11);
+    console.log("Guardian approvals recorded; execution awaits the timelock");
+  });
+
+  it("Registers and closes a sub-alias", async () => {
+    console.log("Testing sub-aliases...");
+    const parentPda = await registerTestAlias("parent", aliasOwnerKeypair);
+    const [subAliasPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pay"), parentPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .registerSubAlias("parent", projectSuffix, "evm", {
+        label: "pay",
+        owner: aliasOwnerKeypair.publicKey,
+        reputationShareBps: 5000,
+      })
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const subAlias = await program.account.subAliasAccount.fetch(subAliasPda);
+    expect(subAlias.parent.toBase58()).to.equal(parentPda.toBase58());
+    expect(subAlias.label).to.equal("pay");
+
+    await program.methods
+      .closeSubAlias("parent", projectSuffix, "evm", "pay")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(subAliasPda)).to.be.null;
+    console.log("Sub-alias lifecycle completed");
+  });
+
+  it("Checks alias availability", async () => {
+    console.log("Testing availability check...");
+    const taken = await program.methods
+      .checkAvailability("parent", projectSuffix, "evm")
+      .accounts({ reservedName: reservedNamePda("parent") })
+      .view();
+    expect(taken).to.deep.equal({ taken: {} });
+
+    const available = await program.methods
+      .checkAvailability("freshname", projectSuffix, "evm")
+      .accounts({ reservedName: reservedNamePda("freshname") })
+      .view();
+    expect(available).to.deep.equal({ available: {} });
+
+    const invalid = await program.methods
+      .checkAvailability("Bad Name", projectSuffix, "evm")
+      .accounts({ reservedName: reservedNamePda("Bad Name") })
+      .view();
+    expect(invalid).to.deep.equal({ invalidUsername: {} });
+    console.log("Availability check completed");
+  });
+
+  it("Mints a soulbound alias token and burns it on close", async () => {
+    console.log("Testing alias token...");
+    const TOKEN_2022 = new PublicKey(
+      "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+    );
+    const ASSOCIATED_TOKEN = new PublicKey(
+      "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+    );
+    const aliasPda = await registerTestAlias("tokenized", aliasOwnerKeypair);
+    const [mintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("alias_mint"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const [tokenAccount] = PublicKey.findProgramAddressSync(
+      [
+        aliasOwnerKeypair.publicKey.toBuffer(),
+        TOKEN_2022.toBuffer(),
+        mintPda.toBuffer(),
+      ],
+      ASSOCIATED_TOKEN
+    );
+
+    await program.methods
+      .mintAliasToken("tokenized", projectSuffix, "evm")
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        tokenAccount,
+        tokenProgram: TOKEN_2022,
+        associatedTokenProgram: ASSOCIATED_TOKEN,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    let balance = await provider.connection.getTokenAccountBalance(
+      tokenAccount
+    );
+    expect(balance.value.amount).to.equal("1");
+
+    await program.methods
+      .closeAlias("tokenized", projectSuffix, "evm")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        recipient: aliasOwnerKeypair.publicKey,
+        aliasMint: mintPda,
+        tokenAccount,
+        tokenProgram: TOKEN_2022,
+        tombstone: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    balance = await provider.connection.getTokenAccountBalance(tokenAccount);
+    expect(balance.value.amount).to.equal("0");
+    expect(await provider.connection.getAccountInfo(mintPda)).to.be.null;
+    console.log("Alias token burned on close");
+  });
+
+  it("Lists and sells an alias through the marketplace", async () => {
+    console.log("Testing alias marketplace...");
+    await program.methods
+      .setSuffixPolicy(projectSuffix, {
+        transferRequiresCosign: false,
+        renewalFee: new anchor.BN(0),
+        requireCommitment: false,
+        requireRegistrationApproval: false,
+        transferable: true,
+        royaltyBps: 500,
+        transferLockDuration: new anchor.BN(0),
+      })
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const aliasPda = await registerTestAlias("forsale", aliasOwnerKeypair);
+    const [listingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("listing"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const price = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+
+    await program.methods
+      .listAlias("forsale", projectSuffix, "evm", price)
+      .accounts({ owner: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    let alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.owner.toBase58()).to.equal(listingPda.toBase58());
+    const listing = await program.account.listing.fetch(listingPda);
+    expect(listing.aliasCreatedAt.toString()).to.equal(
+      alias.createdAt.toString()
+    );
+
+    const buyer = await createAndFundKeypair();
+    const royaltyBefore = await provider.connection.getBalance(
+      productOwnerKeypair.publicKey
+    );
+    await program.methods
+      .buyAlias("forsale", projectSuffix, "evm", price)
+      .accounts({
+        buyer: buyer.publicKey,
+        admin: adminKeypair.publicKey,
+        productOwner: productOwnerKeypair.publicKey,
+        seller: aliasOwnerKeypair.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([buyer])
+      .rpc();
+
+    alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.owner.toBase58()).to.equal(buyer.publicKey.toBase58());
+    const royaltyAfter = await provider.connection.getBalance(
+      productOwnerKeypair.publicKey
+    );
+    expect(royaltyAfter - royaltyBefore).to.equal(
+      anchor.web3.LAMPORTS_PER_SOL * 0.05
+    );
+    expect(await provider.connection.getAccountInfo(listingPda)).to.be.null;
+    console.log("Alias sold through the marketplace");
+  });
+
+  it("Verifies ownership proofs from a delegated signing key", async () => {
+    console.log("Testing alias keys...");
+    const aliasPda = await registerTestAlias("multikey", aliasOwnerKeypair);
+    const signingKey = Keypair.generate();
+
+    await program.methods
+      .addKey(
+        "multikey",
+        projectSuffix,
+        "evm",
+        { signing: {} },
+        signingKey.publicKey
+      )
+      .accounts({ owner: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const [aliasKeysPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("keys"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const challenge = createHash("sha256").update("challenge").digest();
+    const message = createHash("sha256")
+      .update(aliasPda.toBuffer())
+      .update(Buffer.alloc(8))
+      .update(challenge)
+      .digest();
+    const verify = () =>
+      program.methods
+        .verifyAliasOwnership(
+          "multikey",
+          projectSuffix,
+          "evm",
+          { signing: {} },
+          Array.from(challenge)
+        )
+        .accounts({ aliasKeys: aliasKeysPda, session: null })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: signingKey.secretKey,
+            message,
+          }),
+        ]);
+    const signer = await verify().view();
+    expect(signer.toBase58()).to.equal(signingKey.publicKey.toBase58());
+
+    // Submitting the proof consumes the nonce, so it cannot be replayed.
+    await verify().rpc();
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.nonce.toNumber()).to.equal(1);
+    try {
+      await verify().rpc();
+      expect.fail("replayed proof should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "SignatureVerificationFailed"
+      );
+    }
+
+    await program.methods
+      .removeKey(
+        "multikey",
+        projectSuffix,
+        "evm",
+        { signing: {} },
+        signingKey.publicKey
+      )
+      .accounts({ owner: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    const aliasKeys = await program.account.aliasKeys.fetch(aliasKeysPda);
+    expect(aliasKeys.keys).to.be.empty;
+    console.log("Alias keys verified");
+  });
+
+  it("Lets a scoped session key set records until revoked", async () => {
+    console.log("Testing session keys...");
+    const aliasPda = await registerTestAlias("withsession", aliasOwnerKeypair);
+    const sessionKeypair = await createAndFundKeypair();
+    const [sessionPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("session"),
+        aliasPda.toBuffer(),
+        sessionKeypair.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+    await program.methods
+      .createSession(
+        "withsession",
+        projectSuffix,
+        "evm",
+        sessionKeypair.publicKey,
+        2, // SESSION_SCOPE_SET_RECORD
+        expiresAt
+      )
+      .accounts({ owner: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    await program.methods
+      .setRecord("withsession", projectSuffix, "evm", "url", "https://echo.id")
+      .accounts({
+        authority: sessionKeypair.publicKey,
+        session: sessionPda,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await program.methods
+      .revokeSession(
+        "withsession",
+        projectSuffix,
+        "evm",
+        sessionKeypair.publicKey
+      )
+      .accounts({ owner: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(sessionPda)).to.be.null;
+    console.log("Session key lifecycle completed");
+  });
+
+  it("Links and unlinks two aliases", async () => {
+    console.log("Testing alias links...");
+    const otherOwner = await createAndFundKeypair();
+    let a = {
+      pda: await registerTestAlias("linka", aliasOwnerKeypair),
+      owner: aliasOwnerKeypair,
+    };
+    let b = {
+      pda: await registerTestAlias("linkb", otherOwner),
+      owner: otherOwner,
+    };
+    if (Buffer.compare(a.pda.toBuffer(), b.pda.toBuffer()) > 0) {
+      [a, b] = [b, a];
+    }
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("link"), a.pda.toBuffer(), b.pda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .linkAliases()
+      .accounts({
+        ownerA: a.owner.publicKey,
+        ownerB: b.owner.publicKey,
+        aliasA: a.pda,
+        aliasB: b.pda,
+      })
+      .signers([a.owner, b.owner])
+      .rpc();
+
+    const link = await program.account.aliasLink.fetch(linkPda);
+    expect(link.aliasA.toBase58()).to.equal(a.pda.toBase58());
+    expect(link.aliasB.toBase58()).to.equal(b.pda.toBase58());
+
+    await program.methods
+      .unlinkAliases()
+      .accounts({
+        authority: b.owner.publicKey,
+        aliasA: a.pda,
+        aliasB: b.pda,
+      })
+      .signers([b.owner])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(linkPda)).to.be.null;
+    console.log("Alias link lifecycle completed");
+  });
+
+  it("Blocks product owner freezes once control is renounced", async () => {
+    console.log("Testing product owner control renouncement...");
+    const aliasPda = await registerTestAlias("selfmanaged", aliasOwnerKeypair);
+
+    await program.methods
+      .renounceProductOwnerControl("selfmanaged", projectSuffix, "evm")
+      .accounts({ owner: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect((await program.account.aliasAccount.fetch(aliasPda)).ownerManaged)
+      .to.be.false;
+
+    try {
+      await program.methods
+        .freezeAlias("selfmanaged", projectSuffix, "evm")
+        .accounts({ authority: productOwnerKeypair.publicKey })
+        .signers([productOwnerKeypair])
+        .rpc();
+      expect.fail("product owner freeze should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ProductOwnerControlRenounced"
+      );
+    }
+    console.log("Product owner control renounced");
+  });
+
+  it("Records the referrer of a new alias", async () => {
+    console.log("Testing referral tracking...");
+    const referrerPda = await registerTestAlias("referrer", aliasOwnerKeypair);
+    const referred = await createAndFundKeypair();
+    const [referredPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referred"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .registerAlias({
+        username: "referred",
+        suffix: projectSuffix,
+        chainInfo: {
+          name: "evm",
+          address: "0x1234567890123456789012345678901234567890",
+          chainId: 1,
+        },
+        metadata: { name: "referred", imageUrl: "" },
+        userAddress: referred.publicKey,
+        salt: null,
+        ownerManaged: true,
+        referrer: referrerPda,
+        allowlistProof: null,
+        registrationProof: null,
+        signatureExpiresAt: new anchor.BN(0),
+      })
+      .accounts({
+        user: referred.publicKey,
+        payer: referred.publicKey,
+        reservedName: reservedNamePda("referred"),
+        commitmentAccount: null,
+        referrerAlias: referrerPda,
+        instructions: null,
+        productOwner: null,
+        protocolTreasury: adminKeypair.publicKey,
+        registrar: null,
+        registrationVerifyingKey: null,
+        feeMint: null,
+        tokenProgram: null,
+        payerTokenAccount: null,
+        protocolTokenAccount: null,
+        ownerTokenAccount: null,
+      })
+      .signers([referred])
+      .rpc();
+
+    const alias = await program.account.aliasAccount.fetch(referredPda);
+    expect(alias.referrer.toBase58()).to.equal(referrerPda.toBase58());
+    console.log("Referral recorded");
+  });
+
+  it("Claims an alias from a committed import snapshot", async () => {
+    console.log("Testing snapshot import...");
+    const importer = await createAndFundKeypair();
+    const leaves = ["imported-a", "imported-b"].map((name) => ({
+      username: name,
+      chainInfo: {
+        name: "evm",
+        address: "0x1234567890123456789012345678901234567890",
+        chainId: 1,
+      },
+      metadata: { name, imageUrl: "" },
+      owner: importer.publicKey,
+    }));
+    const hashes = leaves.map((leaf) =>
+      createHash("sha256")
+        .update("import_leaf")
+        .update(program.coder.types.encode("ImportedAlias", leaf))
+        .digest()
+    );
+    const root = createHash("sha256")
+      .update(Buffer.concat([...hashes].sort(Buffer.compare)))
+      .digest();
+
+    await program.methods
+      .commitImportRoot(projectSuffix, [...root])
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const [importClaimPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("import_claim"), Buffer.from(projectSuffix), hashes[0]],
+      program.programId
+    );
+    const claim = () =>
+      program.methods
+        .claimImportedAlias(projectSuffix, leaves[0], [[...hashes[1]]])
+        .accounts({
+          payer: importer.publicKey,
+          importClaim: importClaimPda,
+          reservedName: reservedNamePda("imported-a"),
+        })
+        .signers([importer])
+        .rpc();
+    await claim();
+
+    const [aliasPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("imported-a"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.owner.toBase58()).to.equal(importer.publicKey.toBase58());
+    const importClaim = await program.account.importClaim.fetch(
+      importClaimPda
+    );
+    expect(importClaim.alias.toBase58()).to.equal(aliasPda.toBase58());
+
+    // Closing the alias does not free its leaf for another claim.
+    await program.methods
+      .closeAlias("imported-a", projectSuffix, "evm")
+      .accounts({
+        authority: importer.publicKey,
+        recipient: importer.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+        tombstone: null,
+      })
+      .signers([importer])
+      .rpc();
+    try {
+      await claim();
+      expect.fail("second claim of the leaf should have failed");
+    } catch (err) {
+      expect(String(err)).to.include("already in use");
+    }
+    console.log("Imported alias claimed once");
+  });
+
+  it("Rejects transfers inside the suffix transfer lock", async () => {
+    console.log("Testing transfer lock...");
+    const policy = (lock: number) =>
+      program.methods
+        .setSuffixPolicy(projectSuffix, {
+          transferRequiresCosign: false,
+          renewalFee: new anchor.BN(0),
+          requireCommitment: false,
+          requireRegistrationApproval: false,
+          transferable: true,
+          royaltyBps: 500,
+          transferLockDuration: new anchor.BN(lock),
+        })
+        .accounts({ productOwner: productOwnerKeypair.publicKey })
+        .signers([productOwnerKeypair])
+        .rpc();
+
+    await policy(24 * 60 * 60);
+    await registerTestAlias("fresh", aliasOwnerKeypair);
+    try {
+      await program.methods
+        .transferAlias(
+          "fresh",
+          projectSuffix,
+          "evm",
+          Keypair.generate().publicKey
+        )
+        .accounts({
+          owner: aliasOwnerKeypair.publicKey,
+          productOwner: null,
+          tokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("transfer should have been locked");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "TransferLocked"
+      );
+    }
+    await policy(0);
+    console.log("Transfer lock enforced");
+  });
+
+  it("Sets a display name that normalizes to the username", async () => {
+    console.log("Testing display names...");
+    const aliasPda = await registerTestAlias("alicesmith", aliasOwnerKeypair);
+
+    await program.methods
+      .setDisplayName("alicesmith", projectSuffix, "evm", "Alice.Smith")
+      .accounts({ authority: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect(
+      (await program.account.aliasAccount.fetch(aliasPda)).displayName
+    ).to.equal("Alice.Smith");
+
+    try {
+      await program.methods
+        .setDisplayName("alicesmith", projectSuffix, "evm", "Alice.Smyth")
+        .accounts({ authority: aliasOwnerKeypair.publicKey })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("mismatched display name should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "DisplayNameMismatch"
+      );
+    }
+    console.log("Display name set");
+  });
+
+  it("Adds and removes chain mappings", async () => {
+    console.log("Testing chain mappings...");
+    const aliasPda = await registerTestAlias("mapped", aliasOwnerKeypair);
+    const evm = {
+      chainType: { evm: {} },
+      chainId: 1,
+      address: Buffer.from("1234567890123456789012345678901234567890", "hex"),
+      label: "trading wallet",
+      visible: true,
+    };
+    const cosmos = {
+      chainType: { cosmos: {} },
+      chainId: 118,
+      address: Buffer.from("cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02"),
+      label: "cold storage",
+      visible: false,
+    };
+    const stamped = [];
+    for (const mapping of [evm, cosmos]) {
+      const signature = await program.methods
+        .addChainMapping("mapped", projectSuffix, "evm", mapping)
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc({ commitment: "confirmed" });
+      stamped.push(...(await addedMappings(signature)));
+    }
+    expect(
+      (await program.account.aliasAccount.fetch(aliasPda)).chainMappingCount
+    ).to.equal(2);
+    expect(stamped.map(({ mapping }) => mapping.label)).to.deep.equal([
+      "trading wallet",
+      "cold storage",
+    ]);
+    expect(stamped[1].mapping.visible).to.be.false;
+
+    try {
+      await program.methods
+        .addChainMapping("mapped", projectSuffix, "evm", {
+          ...evm,
+          chainId: 10,
+          label: "x".repeat(33),
+        })
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ChainMappingLabelTooLong"
+      );
+    }
+
+    const leaves = stamped.map((mapping) => mappingLeaf(mapping));
+    await program.methods
+      .removeChainMapping(
+        "mapped",
+        projectSuffix,
+        "evm",
+        stamped[0],
+        mappingProof(leaves, 0)
+      )
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    try {
+      await program.methods
+        .removeChainMapping("mapped", projectSuffix, "evm", stamped[1], {
+          ...mappingProof([Buffer.alloc(32), leaves[1]], 1),
+          leafVersion: LEAF_VERSION - 1,
+        })
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping removal should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "LeafVersionMismatch"
+      );
+    }
+
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.chainMappingCount).to.equal(1);
+    const verification = await program.methods
+      .verifyChainMapping(
+        "mapped",
+        projectSuffix,
+        "evm",
+        stamped[1],
+        mappingProof([Buffer.alloc(32), leaves[1]], 1)
+      )
+      .view();
+    expect(verification.valid).to.be.true;
+    expect(verification.stale).to.be.false;
+    console.log("Chain mapping removed");
+  });
+
+  let rotatingAlias: PublicKey;
+  let originalMapping;
+  let rotatedMapping;
+
+  it("Rotates a mapped chain address", async () => {
+    console.log("Testing chain mapping rotation...");
+    rotatingAlias = await registerTestAlias("rotating", aliasOwnerKeypair);
+    const signature = await program.methods
+      .addChainMapping("rotating", projectSuffix, "evm", {
+        chainType: { evm: {} },
+        chainId: 1,
+        address: Buffer.from("1234567890123456789012345678901234567890", "hex"),
+        label: "",
+        visible: true,
+      })
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc({ commitment: "confirmed" });
+    [originalMapping] = await addedMappings(signature);
+
+    const newAddress = Buffer.from(
+      "abcdefabcdefabcdefabcdefabcdefabcdefabcd",
+      "hex"
+    );
+    const rotation = await program.methods
+      .updateChainMapping(
+        "rotating",
+        projectSuffix,
+        "evm",
+        originalMapping,
+        newAddress,
+        mappingProof([mappingLeaf(originalMapping)], 0)
+      )
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc({ commitment: "confirmed" });
+    const [rotated] = await emittedEvents(rotation, "ChainMappingRotated");
+    rotatedMapping = {
+      mapping: { ...originalMapping.mapping, address: newAddress },
+      attestedAt: rotated.attestedAt,
+      expiresAt: rotated.expiresAt,
+    };
+
+    const alias = await program.account.aliasAccount.fetch(rotatingAlias);
+    expect(alias.chainMappingCount).to.equal(1);
+    expect(alias.chainMappingsVersion).to.equal(2);
+    console.log("Chain mapping rotated");
+  });
+
+  it("Verifies chain mappings against recent roots", async () => {
+    console.log("Testing chain mapping verification...");
+    const current = await program.methods
+      .verifyChainMapping(
+        "rotating",
+        projectSuffix,
+        "evm",
+        rotatedMapping,
+        mappingProof([mappingLeaf(rotatedMapping)], 0)
+      )
+      .view();
+    expect(current.valid).to.be.true;
+
+    const recent = await program.methods
+      .verifyChainMapping(
+        "rotating",
+        projectSuffix,
+        "evm",
+        originalMapping,
+        mappingProof([mappingLeaf(originalMapping)], 0)
+      )
+      .view();
+    expect(recent.valid).to.be.true;
+
+    await program.methods
+      .setRootHistoryDepth(1)
+      .accounts({ admin: adminKeypair.publicKey })
+      .signers([adminKeypair])
+      .rpc();
+    const outdated = await program.methods
+      .verifyChainMapping(
+        "rotating",
+        projectSuffix,
+        "evm",
+        originalMapping,
+        mappingProof([mappingLeaf(originalMapping)], 0)
+      )
+      .view();
+    expect(outdated.valid).to.be.false;
+    console.log("Chain mapping verified");
+  });
+
+  it("Re-attests a chain mapping", async () => {
+    console.log("Testing chain mapping re-attestation...");
+    const signature = await program.methods
+      .reattestChainMapping(
+        "rotating",
+        projectSuffix,
+        "evm",
+        rotatedMapping,
+        mappingProof([mappingLeaf(rotatedMapping)], 0)
+      )
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc({ commitment: "confirmed" });
+    const [reattested] = await emittedEvents(
+      signature,
+      "ChainMappingReattested"
+    );
+    expect(reattested.expiresAt.gte(rotatedMapping.expiresAt)).to.be.true;
+
+    const alias = await program.account.aliasAccount.fetch(rotatingAlias);
+    expect(alias.chainMappingsVersion).to.equal(3);
+    console.log("Chain mapping re-attested");
+  });
+
+  it("Adds several chain mappings in one instruction", async () => {
+    console.log("Testing batched chain mappings...");
+    const aliasPda = await registerTestAlias("multichain", aliasOwnerKeypair);
+    const mappings = [1, 10, 137].map((chainId) => ({
+      chainType: { evm: {} },
+      chainId,
+      address: Buffer.from("1234567890123456789012345678901234567890", "hex"),
+      label: "",
+      visible: true,
+    }));
+
+    const signature = await program.methods
+      .addChainMappingsBatch("multichain", projectSuffix, "evm", mappings)
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc({ commitment: "confirmed" });
+    const stamped = await addedMappings(signature);
+
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.chainMappingCount).to.equal(3);
+    expect(alias.chainMappingsVersion).to.equal(1);
+
+    // Indexers get each leaf and one root update for the whole batch.
+    const added = await emittedEvents(signature, "ChainMappingAdded");
+    expect(added.map((event) => event.index)).to.deep.equal([0, 1, 2]);
+    expect(Buffer.from(added[2].leaf)).to.deep.equal(mappingLeaf(stamped[2]));
+    const rootUpdates = await emittedEvents(signature, "RootUpdated");
+    expect(rootUpdates).to.have.lengthOf(1);
+    expect(rootUpdates[0].chainMappingsRoot).to.deep.equal(
+      alias.chainMappingsRoot
+    );
+    const verification = await program.methods
+      .verifyChainMapping(
+        "multichain",
+        projectSuffix,
+        "evm",
+        stamped[2],
+        mappingProof(stamped.map((mapping) => mappingLeaf(mapping)), 2)
+      )
+      .view();
+    expect(verification.valid).to.be.true;
+
+    const leaves = stamped.map((mapping) => mappingLeaf(mapping));
+    const multiproof = mappingMultiProof(leaves, [0, 2]);
+    // Slots 1 and 3 are needed at the leaves; above that the paths merge.
+    expect(multiproof.siblings).to.have.lengthOf(MAPPING_TREE_DEPTH);
+    const multi = await program.methods
+      .verifyChainMappingsMulti(
+        "multichain",
+        projectSuffix,
+        "evm",
+        [stamped[0], stamped[2]],
+        multiproof
+      )
+      .view();
+    expect(multi.valid).to.be.true;
+    const swapped = await program.methods
+      .verifyChainMappingsMulti(
+        "multichain",
+        projectSuffix,
+        "evm",
+        [stamped[2], stamped[0]],
+        multiproof
+      )
+      .view();
+    expect(swapped.valid).to.be.false;
+    console.log("Chain mappings added in batch");
+  });
+
+  it("Adds an EVM chain mapping proven by its key", async () => {
+    console.log("Testing proven chain mappings...");
+    const aliasPda = await registerTestAlias("evmproven", aliasOwnerKeypair);
+    const privateKey = Keypair.generate().secretKey.slice(0, 32);
+    // The address sits right after the offsets header of the instruction.
+    const address = Buffer.from(
+      Secp256k1Program.createInstructionWithPrivateKey({
+        privateKey,
+        message: Buffer.alloc(0),
+      }).data.subarray(12, 32)
+    );
+    const mapping = {
+      chainType: { evm: {} },
+      chainId: 1,
+      address,
+      label: "",
+      visible: true,
+    };
+    const chainId = Buffer.alloc(4);
+    chainId.writeUInt32LE(mapping.chainId);
+    const message = createHash("sha256")
+      .update(Buffer.concat([aliasPda.toBuffer(), chainId, address]))
+      .digest();
+
+    await program.methods
+      .addChainMappingWithProof("evmproven", projectSuffix, "evm", mapping)
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .preInstructions([
+        Secp256k1Program.createInstructionWithPrivateKey({
+          privateKey,
+          message,
+        }),
+      ])
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.chainMappingCount).to.equal(1);
+    console.log("Proven chain mapping added");
+  });
+
+  it("Requires a signature from a mapped Solana key", async () => {
+    console.log("Testing Solana chain mapping proofs...");
+    const aliasPda = await registerTestAlias("solproven", aliasOwnerKeypair);
+    const mappedKey = Keypair.generate();
+    const mapping = {
+      chainType: { solana: {} },
+      chainId: 101,
+      address: mappedKey.publicKey.toBuffer(),
+      label: "",
+      visible: true,
+    };
+    try {
+      await program.methods
+        .addChainMapping("solproven", projectSuffix, "evm", mapping)
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ChainMappingProofRequired"
+      );
+    }
+
+    const chainId = Buffer.alloc(4);
+    chainId.writeUInt32LE(mapping.chainId);
+    const message = createHash("sha256")
+      .update(Buffer.concat([aliasPda.toBuffer(), chainId, mapping.address]))
+      .digest();
+    await program.methods
+      .addChainMappingWithProof("solproven", projectSuffix, "evm", mapping)
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .preInstructions([
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: mappedKey.secretKey,
+          message,
+        }),
+      ])
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.chainMappingCount).to.equal(1);
+    console.log("Solana chain mapping proven");
+  });
+
+  it("Rejects a malformed chain mapping address", async () => {
+    console.log("Testing chain mapping address validation...");
+    const mapping = {
+      chainType: { evm: {} },
+      chainId: 1,
+      address: Buffer.alloc(19, 1),
+      label: "",
+      visible: true,
+    };
+    try {
+      await program.methods
+        .addChainMapping("multichain", projectSuffix, "evm", mapping)
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "InvalidEvmAddress"
+      );
+    }
+    console.log("Malformed chain mapping address rejected");
+  });
+
+  it("Keeps chain mappings readable in a registry", async () => {
+    console.log("Testing chain mapping registry...");
+    const aliasPda = await registerTestAlias("registered", aliasOwnerKeypair);
+    const [registryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mapping_registry"), aliasPda.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .createChainMappingRegistry("registered", projectSuffix, "evm")
+      .accounts({ authority: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const mapping = {
+      chainType: { evm: {} },
+      chainId: 1,
+      address: Buffer.from("1234567890123456789012345678901234567890", "hex"),
+      label: "",
+      visible: true,
+    };
+    try {
+      await program.methods
+        .addChainMapping("registered", projectSuffix, "evm", mapping)
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ChainMappingRegistryMismatch"
+      );
+    }
+
+    await program.methods
+      .addChainMapping("registered", projectSuffix, "evm", mapping)
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: registryPda,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const registry = await program.account.chainMappingRegistry.fetch(
+      registryPda
+    );
+    expect(registry.entries).to.have.lengthOf(1);
+    const [entry] = registry.entries;
+    expect(entry.index).to.equal(0);
+    expect(Buffer.from(entry.mapping.mapping.address)).to.deep.equal(
+      mapping.address
+    );
+    console.log("Chain mapping registry kept in sync");
+  });
+
+  it("Rejects a second mapping for the same chain ID", async () => {
+    console.log("Testing duplicate chain IDs...");
+    const mapping = {
+      chainType: { evm: {} },
+      chainId: 10,
+      address: Buffer.from("abcdefabcdefabcdefabcdefabcdefabcdefabcd", "hex"),
+      label: "",
+      visible: true,
+    };
+    try {
+      await program.methods
+        .addChainMapping("multichain", projectSuffix, "evm", mapping)
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ChainIDAlreadyExists"
+      );
+    }
+    console.log("Duplicate chain ID rejected");
+  });
+
+  it("Commits chain mappings with keccak256 for EVM verifiers", async () => {
+    console.log("Testing keccak256 chain mapping trees...");
+    await registerTestAlias("keccakmapped", aliasOwnerKeypair);
+    await program.methods
+      .setChainMappingHash("keccakmapped", projectSuffix, "evm", {
+        keccak256: {},
+      })
+      .accounts({ authority: aliasOwnerKeypair.publicKey })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const signature = await program.methods
+      .addChainMapping("keccakmapped", projectSuffix, "evm", {
+        chainType: { evm: {} },
+        chainId: 1,
+        address: Buffer.from("1234567890123456789012345678901234567890", "hex"),
+        label: "",
+        visible: true,
+      })
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc({ commitment: "confirmed" });
+    const [mapping] = await addedMappings(signature);
+
+    const leaf = mappingLeaf(mapping, keccak256);
+    const verification = await program.methods
+      .verifyChainMapping(
+        "keccakmapped",
+        projectSuffix,
+        "evm",
+        mapping,
+        mappingProof([leaf], 0, keccak256)
+      )
+      .view();
+    expect(verification.valid).to.be.true;
+    console.log("Keccak256 chain mapping verified");
+  });
+
+  it("Enforces the suffix's chain mapping limit", async () => {
+    console.log("Testing per-suffix chain mapping limits...");
+    const setLimit = (maxChainMappings: number) =>
+      setSuffixConfig({ maxChainMappings });
+    await setLimit(3);
+
+    try {
+      await program.methods
+        .addChainMapping("multichain", projectSuffix, "evm", {
+          chainType: { evm: {} },
+          chainId: 42161,
+          address: Buffer.from(
+            "1234567890123456789012345678901234567890",
+            "hex"
+          ),
+          label: "",
+          visible: true,
+        })
+        .accounts({
+          authority: aliasOwnerKeypair.publicKey,
+          session: null,
+          chainMappingRegistry: null,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("chain mapping should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "MaxChainMappingsReached"
+      );
+    }
+
+    await setLimit(32);
+    console.log("Suffix chain mapping limit enforced");
+  });
+
+  it("Allows several addresses per chain when the suffix opts in", async () => {
+    console.log("Testing per-chain mapping counts...");
+    const setAllowMultiple = (allowMultiplePerChain: boolean) =>
+      setSuffixConfig({ allowMultiplePerChain });
+    await setAllowMultiple(true);
+
+    await program.methods
+      .addChainMapping("multichain", projectSuffix, "evm", {
+        chainType: { evm: {} },
+        chainId: 10,
+        address: Buffer.from("abcdefabcdefabcdefabcdefabcdefabcdefabcd", "hex"),
+        label: "",
+        visible: true,
+      })
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const [aliasPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multichain"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    const [treePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mapping_tree"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const tree = await program.account.chainMappingTree.fetch(treePda);
+    expect(
+      tree.chainCounts.map(({ chainId, count }) => [chainId, count])
+    ).to.deep.equal([
+      [1, 1],
+      [10, 2],
+      [137, 1],
+    ]);
+
+    await setAllowMultiple(false);
+    console.log("Per-chain mapping counts tracked");
+  });
+
+  it("Decays reputation by the suffix's half-life", async () => {
+    console.log("Testing reputation decay...");
+    const setHalfLife = (reputationHalfLife: number) =>
+      setReputationConfig({
+        reputationHalfLife: new anchor.BN(reputationHalfLife),
+      });
+    const aliasPda = await registerTestAlias("decaying", aliasOwnerKeypair);
+    const updateReputation = (change: number) =>
+      program.methods
+        .updateReputation(
+          "decaying",
+          projectSuffix,
+          "evm",
+          new anchor.BN(change),
+          0,
+          0
+        )
+        .accounts({
+          authority: adminKeypair.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: aliasPda,
+          reputationAuthority: null,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    const effectiveReputation = async () =>
+      (
+        await program.methods
+          .getEffectiveReputation("decaying", projectSuffix, "evm", 0)
+          .view()
+      ).toNumber();
+    await updateReputation(1000);
+    const stored = (
+      await program.account.aliasAccount.fetch(aliasPda)
+    ).reputation.toNumber();
+    expect(await effectiveReputation()).to.equal(stored);
+
+    await setHalfLife(1);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    const decayed = await effectiveReputation();
+    expect(decayed).to.be.lessThan(stored / 2);
+
+    // Updates start from the decayed score rather than the stored one.
+    await updateReputation(0);
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputation.toNumber()).to.be.at.most(decayed);
+
+    await setHalfLife(0);
+    console.log("Reputation decay applied");
+  });
+
+  it("Applies reputation changes from weighted authorities", async () => {
+    console.log("Testing reputation authorities...");
+    const aliasPda = await registerTestAlias("oracled", aliasOwnerKeypair);
+    const oracle = await createAndFundKeypair();
+    const [oraclePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reputation_authority"),
+        Buffer.from(projectSuffix),
+        oracle.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .addReputationAuthority(projectSuffix, oracle.publicKey, 5000)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await program.methods
+      .postReputationBond(projectSuffix)
+      .accounts({ authority: oracle.publicKey })
+      .signers([oracle])
+      .rpc();
+
+    const updateReputation = (reputationAuthority: PublicKey | null) =>
+      program.methods
+        .updateReputation(
+          "oracled",
+          projectSuffix,
+          "evm",
+          new anchor.BN(100),
+          7,
+          0
+        )
+        .accounts({
+          authority: oracle.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: aliasPda,
+          reputationAuthority,
+        })
+        .signers([oracle])
+        .rpc();
+    const before = (await program.account.aliasAccount.fetch(aliasPda))
+      .reputation;
+    await updateReputation(oraclePda);
+    const after = (await program.account.aliasAccount.fetch(aliasPda))
+      .reputation;
+    expect(after.sub(before).toNumber()).to.equal(50);
+
+    const [historyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation_history"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const history = await program.account.reputationHistory.fetch(historyPda);
+    const latest = history.entries[history.head - 1];
+    expect(latest.delta.toNumber()).to.equal(50);
+    expect(latest.authority.toBase58()).to.equal(oracle.publicKey.toBase58());
+    expect(latest.reason).to.equal(7);
+
+    await program.methods
+      .removeReputationAuthority(projectSuffix, oracle.publicKey)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        authorityWallet: oracle.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    try {
+      await updateReputation(null);
+      expect.fail("reputation update should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "Unauthorized"
+      );
+    }
+    console.log("Reputation authorities applied by weight");
+  });
+
+  it("Bounds reputation changes by the suffix's band and limit", async () => {
+    console.log("Testing reputation bounds...");
+    const aliasPda = await registerTestAlias("bounded", aliasOwnerKeypair);
+    const updateReputation = (change: number) =>
+      program.methods
+        .updateReputation(
+          "bounded",
+          projectSuffix,
+          "evm",
+          new anchor.BN(change),
+          0,
+          0
+        )
+        .accounts({
+          authority: adminKeypair.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: aliasPda,
+          reputationAuthority: null,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    await setReputationConfig({
+      maxReputation: new anchor.BN(100),
+      maxReputationChange: new anchor.BN(60),
+    });
+
+    try {
+      await updateReputation(-61);
+      expect.fail("reputation update should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "InvalidReputationChange"
+      );
+    }
+
+    await updateReputation(60);
+    await updateReputation(60);
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputation.toNumber()).to.equal(100);
+
+    await setReputationConfig();
+    console.log("Reputation bounded");
+  });
+
+  it("Rate limits reputation changes per alias", async () => {
+    console.log("Testing reputation rate limits...");
+    const aliasPda = await registerTestAlias("ratelimited", aliasOwnerKeypair);
+    const updateReputation = (change: number) =>
+      program.methods
+        .updateReputation(
+          "ratelimited",
+          projectSuffix,
+          "evm",
+          new anchor.BN(change),
+          0,
+          0
+        )
+        .accounts({
+          authority: adminKeypair.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: aliasPda,
+          reputationAuthority: null,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    await setReputationConfig({ maxReputationPerWindow: new anchor.BN(100) });
+
+    // Decreases count against the window as much as increases.
+    await updateReputation(80);
+    await updateReputation(-20);
+    try {
+      await updateReputation(1);
+      expect.fail("reputation update should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ReputationRateLimited"
+      );
+    }
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputationWindowChange.toNumber()).to.equal(100);
+
+    await setReputationConfig();
+    console.log("Reputation rate limited");
+  });
+
+  it("Updates reputation for several aliases in one instruction", async () => {
+    console.log("Testing batched reputation updates...");
+    const names = ["scoredone", "scoredtwo"];
+    const aliasPdas: PublicKey[] = [];
+    for (const name of names) {
+      aliasPdas.push(await registerTestAlias(name, aliasOwnerKeypair));
+    }
+    const before = await Promise.all(
+      aliasPdas.map((pda) => program.account.aliasAccount.fetch(pda))
+    );
+    const historyPda = (alias: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation_history"), alias.toBuffer()],
+        program.programId
+      )[0];
+
+    await program.methods
+      .updateReputationBatch(projectSuffix, [
+        {
+          alias: aliasPdas[0],
+          change: new anchor.BN(15),
+          reason: 1,
+          category: 0,
+        },
+        {
+          alias: aliasPdas[1],
+          change: new anchor.BN(-5),
+          reason: 2,
+          category: 0,
+        },
+      ])
+      .accounts({
+        authority: adminKeypair.publicKey,
+        reputationAuthority: null,
+      })
+      .remainingAccounts(
+        aliasPdas.flatMap((alias) =>
+          [alias, historyPda(alias)].map((pubkey) => ({
+            pubkey,
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+      )
+      .signers([adminKeypair])
+      .rpc();
+
+    const after = await Promise.all(
+      aliasPdas.map((pda) => program.account.aliasAccount.fetch(pda))
+    );
+    const changes = after.map((alias, i) =>
+      alias.reputation.sub(before[i].reputation).toNumber()
+    );
+    expect(changes).to.deep.equal([15, -5]);
+    const history = await program.account.reputationHistory.fetch(
+      historyPda(aliasPdas[1])
+    );
+    expect(history.len).to.equal(1);
+    expect(history.entries[0].reason).to.equal(2);
+    console.log("Reputation updated in batch");
+  });
+
+  it("Keeps reputation per suffix-defined category", async () => {
+    console.log("Testing reputation categories...");
+    const aliasPda = await registerTestAlias("categorized", aliasOwnerKeypair);
+    await program.methods
+      .setReputationCategory(projectSuffix, 1, "trading", new anchor.BN(0))
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    const updateReputation = (category: number) =>
+      program.methods
+        .updateReputation(
+          "categorized",
+          projectSuffix,
+          "evm",
+          new anchor.BN(40),
+          0,
+          category
+        )
+        .accounts({
+          authority: adminKeypair.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: aliasPda,
+          reputationAuthority: null,
+        })
+        .signers([adminKeypair])
+        .rpc();
+
+    const before = await program.account.aliasAccount.fetch(aliasPda);
+    await updateReputation(1);
+    const after = await program.account.aliasAccount.fetch(aliasPda);
+    expect(after.reputation.toNumber()).to.equal(before.reputation.toNumber());
+    expect(after.categoryReputation[0].toNumber()).to.equal(40);
+    const trading = await program.methods
+      .getEffectiveReputation("categorized", projectSuffix, "evm", 1)
+      .view();
+    expect(trading.toNumber()).to.equal(40);
+
+    try {
+      await updateReputation(2);
+      expect.fail("reputation update should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "InvalidReputationCategory"
+      );
+    }
+    console.log("Reputation categories kept apart");
+  });
+
+  it("Adds endorsements to the endorsee's effective reputation", async () => {
+    console.log("Testing endorsements...");
+    const endorser = await registerTestAlias("endorser", aliasOwnerKeypair);
+    const endorsee = await registerTestAlias("endorsee", aliasOwnerKeypair);
+    const [endorsementPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("endorsement"), endorser.toBuffer(), endorsee.toBuffer()],
+      program.programId
+    );
+    const effectiveReputation = async (endorsements: PublicKey[]) =>
+      (
+        await program.methods
+          .getEffectiveReputation("endorsee", projectSuffix, "evm", 0)
+          .remainingAccounts(
+            endorsements.map((pubkey) => ({
+              pubkey,
+              isWritable: false,
+              isSigner: false,
+            }))
+          )
+          .view()
+      ).toNumber();
+    const base = await effectiveReputation([]);
+
+    const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+    await program.methods
+      .endorseAlias(40, new anchor.BN(expiresAt))
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        endorser,
+        endorsee,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect(await effectiveReputation([endorsementPda])).to.equal(base + 40);
+
+    await program.methods
+      .revokeEndorsement()
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        endorser,
+        endorsement: endorsementPda,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    const [quotaPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("endorsement_quota"), endorser.toBuffer()],
+      program.programId
+    );
+    const quota = await program.account.endorsementQuota.fetch(quotaPda);
+    expect(quota.given).to.equal(0);
+    console.log("Endorsements applied and revoked");
+  });
+
+  it("Boosts effective reputation with a cooling-down stake", async () => {
+    console.log("Testing reputation staking...");
+    const alias = await registerTestAlias("staked", aliasOwnerKeypair);
+    const effectiveReputation = async () =>
+      (
+        await program.methods
+          .getEffectiveReputation("staked", projectSuffix, "evm", 0)
+          .view()
+      ).toNumber();
+    const base = await effectiveReputation();
+
+    // 10_000_000 lamports per point of boost.
+    await program.methods
+      .stakeForReputation(new anchor.BN(200_000_000))
+      .accounts({
+        staker: aliasOwnerKeypair.publicKey,
+        aliasAccount: alias,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect(await effectiveReputation()).to.equal(base + 20);
+
+    await program.methods
+      .unstake()
+      .accounts({ staker: aliasOwnerKeypair.publicKey, alias })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    try {
+      await program.methods
+        .withdrawStake()
+        .accounts({ staker: aliasOwnerKeypair.publicKey, alias })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("withdrawal should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "StakeLocked"
+      );
+    }
+    console.log("Stake locked through its cooldown");
+  });
+
+  it("Slashes reputation and stake with evidence", async () => {
+    console.log("Testing slashing...");
+    const aliasPda = await registerTestAlias("slashed", aliasOwnerKeypair);
+    await program.methods
+      .updateReputation(
+        "slashed",
+        projectSuffix,
+        "evm",
+        new anchor.BN(100),
+        0,
+        0
+      )
+      .accounts({
+        authority: adminKeypair.publicKey,
+        adminConfig: adminPda,
+        aliasAccount: aliasPda,
+        reputationAuthority: null,
+      })
+      .signers([adminKeypair])
+      .rpc();
+    await program.methods
+      .stakeForReputation(new anchor.BN(100_000_000))
+      .accounts({
+        staker: aliasOwnerKeypair.publicKey,
+        aliasAccount: aliasPda,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const slasher = await createAndFundKeypair();
+    await program.methods
+      .addReputationSlasher(projectSuffix, slasher.publicKey)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    const [vaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation_vault"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const before = await program.account.aliasAccount.fetch(aliasPda);
+    await program.methods
+      .slashReputation(
+        "slashed",
+        projectSuffix,
+        "evm",
+        5000,
+        Array.from(Buffer.alloc(32, 7))
+      )
+      .accounts({
+        slasher: slasher.publicKey,
+        admin: adminKeypair.publicKey,
+        aliasAccount: aliasPda,
+        reputationVault: vaultPda,
+      })
+      .signers([slasher])
+      .rpc();
+
+    const after = await program.account.aliasAccount.fetch(aliasPda);
+    expect(after.reputation.toNumber()).to.equal(
+      before.reputation.toNumber() / 2
+    );
+    const vault = await program.account.reputationVault.fetch(vaultPda);
+    expect(vault.totalStaked.toNumber()).to.equal(50_000_000);
+    console.log("Reputation and stake slashed");
+  });
+
+  it("Reverts a disputed reputation change", async () => {
+    console.log("Testing reputation disputes...");
+    const aliasPda = await registerTestAlias("disputed", aliasOwnerKeypair);
+    const before = await program.account.aliasAccount.fetch(aliasPda);
+    await program.methods
+      .updateReputation(
+        "disputed",
+        projectSuffix,
+        "evm",
+        new anchor.BN(-300),
+        3,
+        0
+      )
+      .accounts({
+        authority: adminKeypair.publicKey,
+        adminConfig: adminPda,
+        aliasAccount: aliasPda,
+        reputationAuthority: null,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const [disputePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dispute"), aliasPda.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+    await program.methods
+      .openDispute(0)
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        aliasAccount: aliasPda,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    await program.methods
+      .resolveDispute(true)
+      .accounts({
+        arbiter: adminKeypair.publicKey,
+        aliasAccount: aliasPda,
+        dispute: disputePda,
+        reputationAuthority: null,
+        opener: aliasOwnerKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const after = await program.account.aliasAccount.fetch(aliasPda);
+    expect(after.reputation.toNumber()).to.equal(
+      before.reputation.toNumber()
+    );
+    const dispute = await program.account.dispute.fetch(disputePda);
+    expect(dispute.status).to.deep.equal({ reverted: {} });
+    expect(dispute.bond.toNumber()).to.equal(0);
+    console.log("Disputed change reverted");
+  });
+
+  it("Claims and revokes badges with hysteresis", async () => {
+    console.log("Testing reputation badges...");
+    const aliasPda = await registerTestAlias("badged", aliasOwnerKeypair);
+    const updateReputation = (change: number) =>
+      program.methods
+        .updateReputation(
+          "badged",
+          projectSuffix,
+          "evm",
+          new anchor.BN(change),
+          0,
+          0
+        )
+        .accounts({
+          authority: adminKeypair.publicKey,
+          adminConfig: adminPda,
+          aliasAccount: aliasPda,
+          reputationAuthority: null,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    const revokeBadge = () =>
+      program.methods.revokeBadge().accounts({ aliasAccount: aliasPda }).rpc();
+    const [badgePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("badge"), aliasPda.toBuffer()],
+      program.programId
+    );
+
+    // Registration starts every alias at 10.
+    await updateReputation(140);
+    await program.methods
+      .claimBadge()
+      .accounts({
+        payer: aliasOwnerKeypair.publicKey,
+        aliasAccount: aliasPda,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    expect((await program.account.badge.fetch(badgePda)).tier).to.equal(1);
+
+    // Bronze is 100, kept down to 90.
+    await updateReputation(-60);
+    try {
+      await revokeBadge();
+      expect.fail("badge revocation should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "BadgeUnchanged"
+      );
+    }
+
+    await updateReputation(-10);
+    await revokeBadge();
+    expect((await program.account.badge.fetch(badgePda)).tier).to.equal(0);
+    console.log("Badge claimed and revoked");
+  });
+
+  it("Proves scores against a reputation snapshot", async () => {
+    console.log("Testing reputation snapshots...");
+    const voters = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    const scoreLeaf = (alias: PublicKey, score: number) => {
+      const encoded = Buffer.alloc(8);
+      encoded.writeBigInt64LE(BigInt(score));
+      return createHash("sha256")
+        .update("reputation_snapshot_leaf")
+        .update(alias.toBuffer())
+        .update(encoded)
+        .digest();
+    };
+    const hashes = [scoreLeaf(voters[0], 120), scoreLeaf(voters[1], -5)];
+    const root = createHash("sha256")
+      .update(Buffer.concat([...hashes].sort(Buffer.compare)))
+      .digest();
+
+    const epoch = new anchor.BN(1);
+    await program.methods
+      .createReputationSnapshot(projectSuffix, epoch, [...root])
+      .accounts({ authority: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const verify = (score: number) =>
+      program.methods
+        .verifySnapshotScore(
+          projectSuffix,
+          epoch,
+          voters[0],
+          new anchor.BN(score),
+          [[...hashes[1]]]
+        )
+        .view();
+    expect(await verify(120)).to.equal(true);
+    expect(await verify(121)).to.equal(false);
+    console.log("Snapshot scores verified");
+  });
+
+  it("Rejects an invalid reputation threshold proof", async () => {
+    console.log("Testing reputation threshold proofs...");
+    const aliasPda = await registerTestAlias("zkproven", aliasOwnerKeypair);
+    const zeros = (length: number) => Array(length).fill(0);
+    await program.methods
+      .setReputationVerifyingKey({
+        alphaG1: zeros(64),
+        betaG2: zeros(128),
+        gammaG2: zeros(128),
+        deltaG2: zeros(128),
+        ic: Array.from({ length: 6 }, () => zeros(64)),
+      })
+      .accounts({ admin: adminKeypair.publicKey })
+      .signers([adminKeypair])
+      .rpc();
+
+    try {
+      // Snapshot epoch 1 was committed by the snapshot test above.
+      await program.methods
+        .verifyReputationThreshold(new anchor.BN(1), new anchor.BN(100), {
+          a: Array(64).fill(1),
+          b: zeros(128),
+          c: zeros(64),
+        })
+        .accounts({
+          payer: aliasOwnerKeypair.publicKey,
+          aliasAccount: aliasPda,
+        })
+        .signers([aliasOwnerKeypair])
+        .rpc();
+      expect.fail("threshold proof should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "InvalidThresholdProof"
+      );
+    }
+    console.log("Invalid threshold proof rejected");
+  });
+
+  it("Escrows reputation when an alias changes hands", async () => {
+    console.log("Testing reputation transfer policy...");
+    const aliasPda = await registerTestAlias("escrowed", aliasOwnerKeypair);
+    await setReputationConfig({
+      reputationTransferPolicy: { escrowAndDecay: {} },
+    });
+    await program.methods
+      .transferAlias(
+        "escrowed",
+        projectSuffix,
+        "evm",
+        Keypair.generate().publicKey
+      )
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        productOwner: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputation.toNumber()).to.equal(0);
+    expect(alias.escrowedReputation.toNumber()).to.equal(10);
+    await setReputationConfig();
+    console.log("Reputation escrowed on transfer");
+  });
+
+  it("Slashes an authority's bond for a successful challenge", async () => {
+    console.log("Testing reputation update challenges...");
+    const aliasPda = await registerTestAlias("challenged", aliasOwnerKeypair);
+    const oracle = await createAndFundKeypair();
+    const challenger = await createAndFundKeypair();
+    const [oraclePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reputation_authority"),
+        Buffer.from(projectSuffix),
+        oracle.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .addReputationAuthority(projectSuffix, oracle.publicKey, 10_000)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await program.methods
+      .postReputationBond(projectSuffix)
+      .accounts({ authority: oracle.publicKey })
+      .signers([oracle])
+      .rpc();
+    await program.methods
+      .updateReputation(
+        "challenged",
+        projectSuffix,
+        "evm",
+        new anchor.BN(100),
+        0,
+        0
+      )
+      .accounts({
+        authority: oracle.publicKey,
+        adminConfig: adminPda,
+        aliasAccount: aliasPda,
+        reputationAuthority: oraclePda,
+      })
+      .signers([oracle])
+      .rpc();
+
+    const [disputePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dispute"), aliasPda.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+    await program.methods
+      .challengeUpdate(0)
+      .accounts({
+        challenger: challenger.publicKey,
+        aliasAccount: aliasPda,
+        reputationAuthority: oraclePda,
+      })
+      .signers([challenger])
+      .rpc();
+    await program.methods
+      .resolveDispute(true)
+      .accounts({
+        arbiter: adminKeypair.publicKey,
+        aliasAccount: aliasPda,
+        dispute: disputePda,
+        reputationAuthority: oraclePda,
+        opener: challenger.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const authority = await program.account.reputationAuthority.fetch(
+      oraclePda
+    );
+    expect(authority.bond.toNumber()).to.equal(250_000_000);
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputation.toNumber()).to.equal(10);
+    console.log("Challenged update reverted and bond slashed");
+  });
+
+  it("Aggregates reputation across linked aliases", async () => {
+    console.log("Testing aggregate reputation...");
+    const aggregated = await registerTestAlias("aggra", aliasOwnerKeypair);
+    const linked = await registerTestAlias("aggrb", aliasOwnerKeypair);
+    const [aliasA, aliasB] = [aggregated, linked].sort((x, y) =>
+      Buffer.compare(x.toBuffer(), y.toBuffer())
+    );
+    await program.methods
+      .linkAliases()
+      .accounts({
+        ownerA: aliasOwnerKeypair.publicKey,
+        ownerB: aliasOwnerKeypair.publicKey,
+        aliasA,
+        aliasB,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    await program.methods
+      .updateReputation(
+        "aggrb",
+        projectSuffix,
+        "evm",
+        new anchor.BN(90),
+        0,
+        0
+      )
+      .accounts({
+        authority: adminKeypair.publicKey,
+        adminConfig: adminPda,
+        aliasAccount: linked,
+        reputationAuthority: null,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const pda = (seeds: Buffer[]) =>
+      PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+    const suffix = Buffer.from(projectSuffix);
+    const score = await program.methods
+      .aggregateReputation("aggra", projectSuffix, "evm")
+      .remainingAccounts(
+        [
+          pda([Buffer.from("link"), aliasA.toBuffer(), aliasB.toBuffer()]),
+          linked,
+          pda([Buffer.from("reputation_config"), suffix]),
+          pda([Buffer.from("suffix_weight"), suffix]),
+          pda([Buffer.from("reputation_delegation"), linked.toBuffer()]),
+        ].map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+      )
+      .view();
+    // Both start at 10; the linked alias gained 90. Equal default weights.
+    expect(score.toNumber()).to.equal(55);
+    console.log("Aggregate reputation computed");
+  });
+
+  it("Gates on a minimum reputation", async () => {
+    console.log("Testing minimum reputation guard...");
+    await registerTestAlias("gated", aliasOwnerKeypair);
+    await program.methods
+      .requireMinReputation("gated", projectSuffix, "evm", new anchor.BN(10))
+      .rpc();
+
+    try {
+      await program.methods
+        .requireMinReputation("gated", projectSuffix, "evm", new anchor.BN(11))
+        .rpc();
+      expect.fail("reputation guard should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "InsufficientReputation"
+      );
+    }
+    console.log("Minimum reputation enforced");
+  });
+
+  it("Rewards ownership verification once per cooldown", async () => {
+    console.log("Testing verification rewards...");
+    const aliasPda = await registerTestAlias("verified", aliasOwnerKeypair);
+    await setReputationConfig({ verificationReward: new anchor.BN(5) });
+
+    const challenge = createHash("sha256").update("reward").digest();
+    const verify = async () => {
+      const { nonce } = await program.account.aliasAccount.fetch(aliasPda);
+      const message = createHash("sha256")
+        .update(aliasPda.toBuffer())
+        .update(nonce.toArrayLike(Buffer, "le", 8))
+        .update(challenge)
+        .digest();
+      await program.methods
+        .verifyAliasOwnership(
+          "verified",
+          projectSuffix,
+          "evm",
+          { signing: {} },
+          Array.from(challenge)
+        )
+        .accounts({ aliasKeys: null, session: null })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: aliasOwnerKeypair.secretKey,
+            message,
+          }),
+        ])
+        .rpc();
+    };
+    await verify();
+    await verify();
+
+    // The second verification falls within the cooldown.
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputation.toNumber()).to.equal(15);
+    await setReputationConfig();
+    console.log("Verification rewarded");
+  });
+
+  it("Quarantines an alias below the floor until an appeal", async () => {
+    console.log("Testing reputation quarantine...");
+    const aliasPda = await registerTestAlias("quarantined", aliasOwnerKeypair);
+    await setReputationConfig({ quarantineFloor: new anchor.BN(0) });
+    await program.methods
+      .updateReputation(
+        "quarantined",
+        projectSuffix,
+        "evm",
+        new anchor.BN(-20),
+        0,
+        0
+      )
+      .accounts({
+        authority: adminKeypair.publicKey,
+        adminConfig: adminPda,
+        aliasAccount: aliasPda,
+        reputationAuthority: null,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    let alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.isQuarantined).to.be.true;
+
+    const lift = (authority: Keypair) =>
+      program.methods
+        .liftQuarantine("quarantined", projectSuffix, "evm")
+        .accounts({ authority: authority.publicKey, adminConfig: adminPda })
+        .signers([authority])
+        .rpc();
+    try {
+      await lift(aliasOwnerKeypair);
+      expect.fail("early lift should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "RehabilitationPending"
+      );
+    }
+    await lift(adminKeypair);
+    alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.isQuarantined).to.be.false;
+    await setReputationConfig();
+    console.log("Quarantine lifted on appeal");
+  });
+
+  it("Starts new aliases at the suffix's initial reputation", async () => {
+    console.log("Testing reputation config...");
+    await setReputationConfig({ initialReputation: new anchor.BN(25) });
+    const aliasPda = await registerTestAlias("seasoned", aliasOwnerKeypair);
+    const alias = await program.account.aliasAccount.fetch(aliasPda);
+    expect(alias.reputation.toNumber()).to.equal(25);
+    await setReputationConfig();
+    console.log("Initial reputation applied");
+  });
+
+  it("Emits reputation changes for indexers", async () => {
+    console.log("Testing reputation change events...");
+    const aliasPda = await registerTestAlias("indexed", aliasOwnerKeypair);
+    const signature = await program.methods
+      .updateReputation("indexed", projectSuffix, "evm", new anchor.BN(5), 7, 0)
+      .accounts({
+        authority: adminKeypair.publicKey,
+        adminConfig: adminPda,
+        aliasAccount: aliasPda,
+        reputationAuthority: null,
+      })
+      .signers([adminKeypair])
+      .rpc({ commitment: "confirmed" });
+
+    const [changed] = await emittedEvents(signature, "ReputationChanged");
+    expect(changed.alias.toBase58()).to.equal(aliasPda.toBase58());
+    expect(changed.old.toNumber()).to.equal(10);
+    expect(changed.new.toNumber()).to.equal(15);
+    expect(changed.delta.toNumber()).to.equal(5);
+    expect(changed.authority.toBase58()).to.equal(
+      adminKeypair.publicKey.toBase58()
+    );
+    expect(changed.reason).to.equal(7);
+    console.log("Reputation change emitted");
+  });
+
+  it("Delegates reputation to another alias", async () => {
+    console.log("Testing reputation delegation...");
+    const delegator = await registerTestAlias("delegator", aliasOwnerKeypair);
+    const delegate = await registerTestAlias("delegatee", aliasOwnerKeypair);
+    const [delegationPda, targetDelegationPda] = [delegator, delegate].map(
+      (alias) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("reputation_delegation"), alias.toBuffer()],
+          program.programId
+        )[0]
+    );
+    await program.methods
+      .delegateReputation(new anchor.BN(4))
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        aliasAccount: delegator,
+        targetAlias: delegate,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+
+    let received = await program.account.reputationDelegation.fetch(
+      targetDelegationPda
+    );
+    expect(received.received.toNumber()).to.equal(4);
+    const score = await program.methods
+      .aggregateReputation("delegatee", projectSuffix, "evm")
+      .view();
+    expect(score.toNumber()).to.equal(14);
+
+    await program.methods
+      .undelegate()
+      .accounts({
+        owner: aliasOwnerKeypair.publicKey,
+        aliasAccount: delegator,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    const delegation = await program.account.reputationDelegation.fetch(
+      delegationPda
+    );
+    expect(delegation.delegated.toNumber()).to.equal(0);
+    received = await program.account.reputationDelegation.fetch(
+      targetDelegationPda
+    );
+    expect(received.received.toNumber()).to.equal(0);
+    console.log("Reputation delegated and withdrawn");
+  });
+
+  it("Counts unexpired boosts from registered attestors", async () => {
+    console.log("Testing reputation boosts...");
+    const aliasPda = await registerTestAlias("boosted", aliasOwnerKeypair);
+    const attestor = await createAndFundKeypair();
+    await program.methods
+      .addReputationAttestor(
+        projectSuffix,
+        attestor.publicKey,
+        new anchor.BN(50)
+      )
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await program.methods
+      .grantReputationBoost(
+        new anchor.BN(30),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600)
+      )
+      .accounts({ attestor: attestor.publicKey, aliasAccount: aliasPda })
+      .signers([attestor])
+      .rpc();
+
+    const [boostPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reputation_boost"),
+        aliasPda.toBuffer(),
+        attestor.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const effective = await program.methods
+      .getEffectiveReputation("boosted", projectSuffix, "evm", 0)
+      .remainingAccounts([
+        { pubkey: boostPda, isWritable: false, isSigner: false },
+      ])
+      .view();
+    expect(effective.toNumber()).to.equal(40);
+
+    await program.methods
+      .closeReputationBoost()
+      .accounts({
+        closer: attestor.publicKey,
+        reputationBoost: boostPda,
+        attestor: attestor.publicKey,
+      })
+      .signers([attestor])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(boostPda)).to.be.null;
+    console.log("Reputation boost counted and closed");
+  });
+
+  it("Deactivates, freezes and removes a product owner", async () => {
+    console.log("Testing product owner off-ramp...");
+    const [retiredPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix"), Buffer.from("retired")],
+      program.programId
+    );
+    await program.methods
+      .registerProductOwner("retired")
+      .accounts({
+        admin: adminKeypair.publicKey,
+        newProductOwner: productOwnerKeypair.publicKey,
+      })
+      .signers([adminKeypair, productOwnerKeypair])
+      .rpc();
+    await program.methods
+      .deactivateProductOwner("retired", true)
+      .accounts({ admin: adminKeypair.publicKey })
+      .signers([adminKeypair])
+      .rpc();
+
+    const suffixAccount = await program.account.suffixAccount.fetch(
+      retiredPda
+    );
+    expect(suffixAccount.isActive).to.be.false;
+    expect(suffixAccount.isFrozen).to.be.true;
+    try {
+      await program.methods
+        .reactivateProductOwner("retired")
+        .accounts({ admin: adminKeypair.publicKey })
+        .signers([adminKeypair])
+        .rpc();
+      expect.fail("reactivation should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "ReactivationCooldown"
+      );
+    }
+
+    await program.methods
+      .removeProductOwner("retired")
+      .accounts({
+        admin: adminKeypair.publicKey,
+        productOwnerAccount: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("product_owner"),
+            productOwnerKeypair.publicKey.toBuffer(),
+          ],
+          program.programId
+        )[0],
+      })
+      .signers([adminKeypair])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(retiredPda)).to.be.null;
+    console.log("Product owner removed");
+  });
+
+  it("Rotates a product owner key in two steps", async () => {
+    console.log("Testing product owner key rotation...");
+    const [rotatedPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix"), Buffer.from("rotated")],
+      program.programId
+    );
+    await program.methods
+      .registerProductOwner("rotated")
+      .accounts({
+        admin: adminKeypair.publicKey,
+        newProductOwner: productOwnerKeypair.publicKey,
+      })
+      .signers([adminKeypair, productOwnerKeypair])
+      .rpc();
+    const newKey = await createAndFundKeypair();
+    await program.methods
+      .proposeOwnerKeyRotation("rotated", newKey.publicKey)
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await program.methods
+      .acceptOwnerKeyRotation("rotated")
+      .accounts({ newOwner: newKey.publicKey })
+      .signers([newKey])
+      .rpc();
+
+    const suffixAccount = await program.account.suffixAccount.fetch(
+      rotatedPda
+    );
+    expect(suffixAccount.owner.toBase58()).to.equal(
+      newKey.publicKey.toBase58()
+    );
+    expect(suffixAccount.pendingOwner).to.be.null;
+    console.log("Product owner key rotated");
+  });
+
+  it("Creates an additional suffix from the product owner", async () => {
+    console.log("Testing suffix creation by a product owner...");
+    const [extraPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix"), Buffer.from("extra")],
+      program.programId
+    );
+    const [productOwnerAccountPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("product_owner"), productOwnerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const before = await program.account.productOwner.fetch(
+      productOwnerAccountPda
+    );
+
+    await program.methods
+      .createSuffix("extra")
+      .accounts({ owner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const suffixAccount = await program.account.suffixAccount.fetch(extraPda);
+    expect(suffixAccount.owner.toBase58()).to.equal(
+      productOwnerKeypair.publicKey.toBase58()
+    );
+    expect(suffixAccount.isActive).to.be.true;
+    const after = await program.account.productOwner.fetch(
+      productOwnerAccountPda
+    );
+    expect(after.suffixCount).to.equal(before.suffixCount + 1);
+
+    const stranger = await createAndFundKeypair();
+    try {
+      await program.methods
+        .createSuffix("stranger")
+        .accounts({ owner: stranger.publicKey })
+        .signers([stranger])
+        .rpc();
+      expect.fail("unregistered owner should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("AccountNotInitialized");
+    }
+    console.log("Additional suffix created");
+  });
+
+  it("Applies the suffix's registration policy", async () => {
+    console.log("Testing per-suffix registration policy...");
+    const fee = 1_000_000;
+    await setSuffixConfig({
+      minUsernameLength: 5,
+      usernameCharset: 0b001,
+      registrationFee: new anchor.BN(fee),
+    });
+
+    for (const username of ["abcd", "abc123"]) {
+      try {
+        await registerTestAlias(username, aliasOwnerKeypair);
+        expect.fail("registration should have failed");
+      } catch (err) {
+        expect(err.toString()).to.match(
+          /UsernameTooShort|UsernameInvalidCharacter/
+        );
+      }
+    }
+
+    const [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix_treasury"), Buffer.from(projectSuffix)],
+      program.programId
+    );
+    const before = await provider.connection.getBalance(treasuryPda);
+    const adminBefore = await provider.connection.getBalance(
+      adminKeypair.publicKey
+    );
+    await registerTestAlias("policyok", aliasOwnerKeypair);
+    const after = await provider.connection.getBalance(treasuryPda);
+    const adminAfter = await provider.connection.getBalance(
+      adminKeypair.publicKey
+    );
+    // The protocol keeps its default 1% cut.
+    expect(adminAfter - adminBefore).to.equal(fee / 100);
+    expect(after - before).to.equal(fee - fee / 100);
+
+    const ownerBefore = await provider.connection.getBalance(
+      productOwnerKeypair.publicKey
+    );
+    await program.methods
+      .withdrawSuffixTreasury(projectSuffix, new anchor.BN(after - before))
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+    const ownerAfter = await provider.connection.getBalance(
+      productOwnerKeypair.publicKey
+    );
+    expect(ownerAfter).to.be.greaterThan(ownerBefore);
+
+    await setSuffixConfig();
+    console.log("Registration policy applied");
+  });
+
+  it("Pauses and unpauses a suffix", async () => {
+    console.log("Testing suffix pause...");
+    await program.methods
+      .pauseSuffix(projectSuffix)
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+    const paused = await program.account.suffixAccount.fetch(suffixPda);
+    expect(paused.isPaused).to.be.true;
+
+    try {
+      await registerTestAlias("pausedname", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("SuffixPaused");
+    }
+
+    await program.methods
+      .unpauseSuffix(projectSuffix)
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await registerTestAlias("pausedname", aliasOwnerKeypair);
+    console.log("Suffix paused and unpaused");
+  });
+
+  it("Transfers a suffix to another product owner", async () => {
+    console.log("Testing suffix transfer...");
+    const [extraPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix"), Buffer.from("extra")],
+      program.programId
+    );
+    const [productOwnerAccountPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("product_owner"), productOwnerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const acquirer = await createAndFundKeypair();
+
+    await program.methods
+      .transferSuffix("extra")
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+        newOwner: acquirer.publicKey,
+        productOwnerAccount: productOwnerAccountPda,
+      })
+      .signers([productOwnerKeypair, acquirer])
+      .rpc();
+
+    const suffixAccount = await program.account.suffixAccount.fetch(extraPda);
+    expect(suffixAccount.owner.toBase58()).to.equal(
+      acquirer.publicKey.toBase58()
+    );
+    const [acquirerAccountPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("product_owner"), acquirer.publicKey.toBuffer()],
+      program.programId
+    );
+    const acquirerAccount = await program.account.productOwner.fetch(
+      acquirerAccountPda
+    );
+    expect(acquirerAccount.suffixCount).to.equal(1);
+    console.log("Suffix transferred");
+  });
+
+  it("Lets a registrar register aliases within its quota", async () => {
+    console.log("Testing delegated registrars...");
+    const backend = await createAndFundKeypair();
+    const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+    await program.methods
+      .addRegistrar(
+        projectSuffix,
+        backend.publicKey,
+        1,
+        new anchor.BN(expiresAt)
+      )
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    const [registrarPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("registrar"),
+        Buffer.from(projectSuffix),
+        backend.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const registerViaBackend = (username: string) =>
+      program.methods
+        .registerAlias({
+          username,
+          suffix: projectSuffix,
+          chainInfo: {
+            name: "evm",
+            address: "0x1234567890123456789012345678901234567890",
+            chainId: 1,
+          },
+          metadata: { name: username, imageUrl: "" },
+          userAddress: aliasOwnerKeypair.publicKey,
+          salt: null,
+          ownerManaged: true,
+          referrer: null,
+          allowlistProof: null,
+          registrationProof: null,
+          signatureExpiresAt: new anchor.BN(0),
+        })
+        .accounts({
+          user: aliasOwnerKeypair.publicKey,
+          payer: backend.publicKey,
+          reservedName: reservedNamePda(username),
+          commitmentAccount: null,
+          referrerAlias: null,
+          instructions: null,
+          productOwner: null,
+          protocolTreasury: adminKeypair.publicKey,
+          registrar: registrarPda,
+          registrationVerifyingKey: null,
+          feeMint: null,
+          tokenProgram: null,
+          payerTokenAccount: null,
+          protocolTokenAccount: null,
+          ownerTokenAccount: null,
+        })
+        .signers([backend, aliasOwnerKeypair])
+        .rpc();
+
+    await registerViaBackend("viabackend");
+    const registrar = await program.account.registrar.fetch(registrarPda);
+    expect(registrar.used).to.equal(1);
+
+    try {
+      await registerViaBackend("overquota");
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("RegistrarQuotaExceeded");
+    }
+
+    await program.methods
+      .removeRegistrar(projectSuffix, backend.publicKey)
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+    console.log("Registrar quota enforced");
+  });
+
+  it("Requires token accounts when the suffix charges in a token", async () => {
+    console.log("Testing token-denominated registration fees...");
+    await setSuffixConfig({
+      registrationFee: new anchor.BN(1_000),
+      feeMint: Keypair.generate().publicKey,
+    });
+
+    try {
+      await registerTestAlias("tokenfee", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("FeeTokenAccountsRequired");
+    }
+
+    await setSuffixConfig();
+    console.log("Token fee accounts required");
+  });
+
+  it("Prices short usernames from the premium table", async () => {
+    console.log("Testing premium username pricing...");
+    const base = 100_000;
+    const premium = 5_000_000;
+    await setSuffixConfig({
+      registrationFee: new anchor.BN(base),
+      premiumFees: [premium, 0, 0, 0].map((fee) => new anchor.BN(fee)),
+    });
+    const [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix_treasury"), Buffer.from(projectSuffix)],
+      program.programId
+    );
+    const treasuryGain = async (username: string) => {
+      const before = await provider.connection.getBalance(treasuryPda);
+      await registerTestAlias(username, aliasOwnerKeypair);
+      return (await provider.connection.getBalance(treasuryPda)) - before;
+    };
+
+    // Both net of the protocol's default 1% cut.
+    expect(await treasuryGain("ace")).to.equal(premium - premium / 100);
+    expect(await treasuryGain("regular")).to.equal(base - base / 100);
+
+    await setSuffixConfig();
+    console.log("Premium pricing applied");
+  });
+
+  it("Counts registrations and closures in the suffix stats", async () => {
+    console.log("Testing suffix statistics...");
+    const [statsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix_stats"), Buffer.from(projectSuffix)],
+      program.programId
+    );
+    const before = await program.account.suffixStats.fetch(statsPda);
+
+    await registerTestAlias("counted", aliasOwnerKeypair);
+    let stats = await program.account.suffixStats.fetch(statsPda);
+    expect(stats.totalRegistrations.toNumber()).to.equal(
+      before.totalRegistrations.toNumber() + 1
+    );
+    expect(stats.activeAliases.toNumber()).to.equal(
+      before.activeAliases.toNumber() + 1
+    );
+
+    await program.methods
+      .closeAlias("counted", projectSuffix, "evm")
+      .accounts({
+        authority: aliasOwnerKeypair.publicKey,
+        recipient: aliasOwnerKeypair.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+        tombstone: null,
+      })
+      .signers([aliasOwnerKeypair])
+      .rpc();
+    stats = await program.account.suffixStats.fetch(statsPda);
+    expect(stats.totalRegistrations.toNumber()).to.equal(
+      before.totalRegistrations.toNumber() + 1
+    );
+    expect(stats.activeAliases.toNumber()).to.equal(
+      before.activeAliases.toNumber()
+    );
+    console.log("Suffix stats kept");
+  });
+
+  it("Enforces the suffix's registration quotas", async () => {
+    console.log("Testing suffix registration quotas...");
+    const [statsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("suffix_stats"), Buffer.from(projectSuffix)],
+      program.programId
+    );
+    const stats = await program.account.suffixStats.fetch(statsPda);
+    await setSuffixConfig({
+      maxTotalRegistrations: stats.totalRegistrations.addn(1),
+    });
+
+    await registerTestAlias("lastone", aliasOwnerKeypair);
+    try {
+      await registerTestAlias("onetoomany", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("RegistrationQuotaExceeded");
+    }
+
+    await setSuffixConfig({ maxRegistrationsPerDay: 1 });
+    try {
+      await registerTestAlias("ratelimited", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("RegistrationRateLimited");
+    }
+
+    await setSuffixConfig();
+    console.log("Registration quotas enforced");
+  });
+
+  it("Rejects non-canonical and reserved suffixes", async () => {
+    console.log("Testing suffix name validation...");
+    for (const [suffix, error] of [
+      ["Extra2", "InvalidSuffixName"],
+      ["admin", "SuffixReserved"],
+    ]) {
+      try {
+        await program.methods
+          .createSuffix(suffix)
+          .accounts({ owner: productOwnerKeypair.publicKey })
+          .signers([productOwnerKeypair])
+          .rpc();
+        expect.fail("suffix creation should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include(error);
+      }
+    }
+    console.log("Suffix names validated");
+  });
+
+  it("Bonds, slashes and refunds a product owner", async () => {
+    console.log("Testing product owner bonds...");
+    const operator = await createAndFundKeypair();
+    const [operatorPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("product_owner"), operator.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .registerProductOwner("bonded")
+      .accounts({
+        admin: adminKeypair.publicKey,
+        newProductOwner: operator.publicKey,
+      })
+      .signers([adminKeypair, operator])
+      .rpc();
+    let record = await program.account.productOwner.fetch(operatorPda);
+    const bond = record.bond.toNumber();
+    expect(bond).to.equal(100_000_000);
+
+    await program.methods
+      .slashProductOwnerBond(operator.publicKey, new anchor.BN(bond / 4))
+      .accounts({ admin: adminKeypair.publicKey })
+      .signers([adminKeypair])
+      .rpc();
+    record = await program.account.productOwner.fetch(operatorPda);
+    expect(record.bond.toNumber()).to.equal(bond - bond / 4);
+
+    try {
+      await program.methods
+        .closeProductOwner()
+        .accounts({ owner: operator.publicKey })
+        .signers([operator])
+        .rpc();
+      expect.fail("closing with a live suffix should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("ProductOwnerHasSuffixes");
+    }
+
+    await program.methods
+      .deactivateProductOwner("bonded", false)
+      .accounts({ admin: adminKeypair.publicKey })
+      .signers([adminKeypair])
+      .rpc();
+    await program.methods
+      .removeProductOwner("bonded")
+      .accounts({
+        admin: adminKeypair.publicKey,
+        productOwnerAccount: operatorPda,
+      })
+      .signers([adminKeypair])
+      .rpc();
+    await program.methods
+      .closeProductOwner()
+      .accounts({ owner: operator.publicKey })
+      .signers([operator])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(operatorPda)).to.be.null;
+    console.log("Product owner bond refunded");
+  });
+
+  it("Admits only allowlisted owners to a private suffix", async () => {
+    console.log("Testing suffix allowlist mode...");
+    const listed = await createAndFundKeypair();
+    const proven = await createAndFundKeypair();
+    await setSuffixConfig({ allowlistMode: true });
+
+    try {
+      await registerTestAlias("outsider", listed);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("NotAllowlisted");
+    }
+
+    await program.methods
+      .addAllowlistEntry(projectSuffix, listed.publicKey)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await registerTestAlias("listed", listed);
+
+    // A single-leaf tree: the root is the leaf and the proof is empty.
+    const root = createHash("sha256")
+      .update("allowlist_leaf")
+      .update(proven.publicKey.toBuffer())
+      .digest();
+    await program.methods
+      .commitAllowlistRoot(projectSuffix, [...root])
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    await registerTestAlias("proven", proven, "evm", []);
+
+    await program.methods
+      .removeAllowlistEntry(projectSuffix, listed.publicKey)
+      .accounts({ productOwner: productOwnerKeypair.publicKey })
+      .signers([productOwnerKeypair])
+      .rpc();
+    try {
+      await registerTestAlias("delisted", listed);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("NotAllowlisted");
+    }
+
+    await setSuffixConfig();
+    console.log("Allowlist enforced");
+  });
+
+  it("Publishes product owner branding with registrations", async () => {
+    console.log("Testing product owner profile...");
+    const supportContactHash = createHash("sha256")
+      .update("support@echoid.xyz")
+      .digest();
+    await program.methods
+      .setProductOwnerProfile({
+        displayName: "EchoId Labs",
+        logoUri: "https://echoid.xyz/logo.png",
+        website: "https://echoid.xyz",
+        supportContactHash: [...supportContactHash],
+      })
+      .accounts({
+        owner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const pda = await registerTestAlias("branded", aliasOwnerKeypair);
+    const [{ signature }] =
+      await provider.connection.getSignaturesForAddress(pda);
+    const [registered] = await emittedEvents(signature, "AliasRegistered");
+    expect(registered.operator.displayName).to.equal("EchoId Labs");
+    expect(registered.operator.website).to.equal("https://echoid.xyz");
+    console.log("Product owner branding published");
+  });
+
+  it("Renews a suffix lease and refuses early reclaims", async () => {
+    console.log("Testing suffix leases...");
+    const fee = 10_000_000;
+    await program.methods
+      .setSuffixRenewalFee(new anchor.BN(fee))
+      .accounts({ admin: adminKeypair.publicKey })
+      .signers([adminKeypair])
+      .rpc();
+
+    const before = await program.account.suffixAccount.fetch(suffixPda);
+    const treasuryBefore = await provider.connection.getBalance(
+      adminKeypair.publicKey
+    );
+    await program.methods
+      .renewSuffix(projectSuffix)
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        protocolTreasury: adminKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+    const after = await program.account.suffixAccount.fetch(suffixPda);
+    expect(after.expiresAt.sub(before.expiresAt).toNumber()).to.equal(
+      365 * 24 * 60 * 60
+    );
+    expect(
+      await provider.connection.getBalance(adminKeypair.publicKey)
+    ).to.equal(treasuryBefore + fee);
+
+    const claimant = await createAndFundKeypair();
+    try {
+      await program.methods
+        .reclaimSuffix(projectSuffix)
+        .accounts({
+          admin: adminKeypair.publicKey,
+          newOwner: claimant.publicKey,
+        })
+        .signers([adminKeypair, claimant])
+        .rpc();
+      expect.fail("reclaim should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("SuffixLeaseActive");
+    }
+    console.log("Suffix lease renewed");
+  });
+
+  it("Creates a child namespace under a suffix", async () => {
+    console.log("Testing sub-suffixes...");
+    const pda = (seed: string, suffix: string) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from(seed), Buffer.from(suffix)],
+        program.programId
+      )[0];
+    const child = `team.${projectSuffix}`;
+    await setSuffixConfig({ minUsernameLength: 4 });
+    await program.methods
+      .createSubSuffix(projectSuffix, "team")
+      .accounts({
+        owner: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+        suffixAccount: pda("suffix", child),
+        suffixConfig: pda("suffix_config", child),
+        reputationConfig: pda("reputation_config", child),
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const account = await program.account.suffixAccount.fetch(
+      pda("suffix", child)
+    );
+    expect(account.suffix).to.equal(child);
+    expect(account.parent.toBase58()).to.equal(suffixPda.toBase58());
+    const config = await program.account.suffixConfig.fetch(
+      pda("suffix_config", child)
+    );
+    expect(config.minUsernameLength).to.equal(4);
+
+    try {
+      await program.methods
+        .createSubSuffix(projectSuffix, "Team")
+        .accounts({
+          owner: productOwnerKeypair.publicKey,
+          payer: productOwnerKeypair.publicKey,
+          suffixAccount: pda("suffix", `Team.${projectSuffix}`),
+          suffixConfig: pda("suffix_config", `Team.${projectSuffix}`),
+          reputationConfig: pda("reputation_config", `Team.${projectSuffix}`),
+        })
+        .signers([productOwnerKeypair])
+        .rpc();
+      expect.fail("sub-suffix creation should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("InvalidSuffixName");
+    }
+
+    await setSuffixConfig();
+    console.log("Sub-suffix created");
+  });
+
+  it("Accepts a product owner that only signs", async () => {
+    // A Squads vault or other PDA owner signs through CPI and leaves rent
+    // to a separate payer; a keypair that never pays stands in for it here.
+    console.log("Testing signer-only product owner...");
+    const ownerBalance = () =>
+      provider.connection.getBalance(productOwnerKeypair.publicKey);
+    const before = await ownerBalance();
+
+    const backend = Keypair.generate();
+    const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+    await program.methods
+      .addRegistrar(
+        projectSuffix,
+        backend.publicKey,
+        1,
+        new anchor.BN(expiresAt)
+      )
+      .accounts({
+        productOwner: productOwnerKeypair.publicKey,
+        payer: provider.wallet.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    const member = await createAndFundKeypair();
+    await program.methods
+      .registerAlias({
+        username: "multisig",
+        suffix: projectSuffix,
+        chainInfo: {
+          name: "evm",
+          address: "0x1234567890123456789012345678901234567890",
+          chainId: 1,
+        },
+        metadata: { name: "multisig", imageUrl: "" },
+        userAddress: member.publicKey,
+        salt: null,
+        ownerManaged: true,
+        referrer: null,
+        allowlistProof: null,
+        registrationProof: null,
+        signatureExpiresAt: new anchor.BN(0),
+      })
+      .accounts({
+        user: member.publicKey,
+        payer: member.publicKey,
+        reservedName: reservedNamePda("multisig"),
+        commitmentAccount: null,
+        referrerAlias: null,
+        instructions: null,
+        productOwner: productOwnerKeypair.publicKey,
+        protocolTreasury: adminKeypair.publicKey,
+        registrar: null,
+        registrationVerifyingKey: null,
+        feeMint: null,
+        tokenProgram: null,
+        payerTokenAccount: null,
+        protocolTokenAccount: null,
+        ownerTokenAccount: null,
+      })
+      .signers([member, productOwnerKeypair])
+      .rpc();
+
+    expect(await ownerBalance()).to.equal(before);
+    console.log("Signer-only product owner accepted");
+  });
+
+  it("Requires a registration proof when the suffix asks for one", async () => {
+    console.log("Testing registration proofs...");
+    const id = new anchor.BN(1);
+    const [verifyingKeyPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("registration_verifying_key"),
+        productOwnerKeypair.publicKey.toBuffer(),
+        id.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const zeros = (length: number) => new Array(length).fill(0);
+    await program.methods
+      .createRegistrationVerifyingKey(
+        id,
+        {
+          alphaG1: zeros(64),
+          betaG2: zeros(128),
+          gammaG2: zeros(128),
+          deltaG2: zeros(128),
+          ic: new Array(6).fill(null).map(() => zeros(64)),
+        },
+        zeros(16)
+      )
+      .accounts({
+        authority: productOwnerKeypair.publicKey,
+        payer: productOwnerKeypair.publicKey,
+      })
+      .signers([productOwnerKeypair])
+      .rpc();
+
+    try {
+      await setSuffixConfig({ registrationProofRequired: true });
+      expect.fail("config without a verifying key should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("InvalidSuffixConfig");
+    }
+
+    await setSuffixConfig({
+      registrationProofRequired: true,
+      registrationVerifyingKey: verifyingKeyPda,
+    });
+    try {
+      await registerTestAlias("unproven", aliasOwnerKeypair);
+      expect.fail("registration should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("RegistrationProofRequired");
+    }
+
+    await setSuffixConfig();
+    console.log("Registration proof enforced");
+  });
+
+  it("Ignores the previous owner's guardians after a transfer", async () => {
+    console.log("Testing guardian set owner binding...");
+    const seller = await createAndFundKeypair();
+    const guardian = Keypair.generate();
+    await registerTestAlias("guarded-sale", seller);
+
+    await program.methods
+      .setGuardians(
+        "guarded-sale",
+        projectSuffix,
+        "evm",
+        [guardian.publicKey],
+        1
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+    await program.methods
+      .transferAlias(
+        "guarded-sale",
+        projectSuffix,
+        "evm",
+        Keypair.generate().publicKey
+      )
+      .accounts({
+        owner: seller.publicKey,
+        productOwner: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([seller])
+      .rpc();
+
+    try {
+      await program.methods
+        .initiateRecovery(
+          "guarded-sale",
+          projectSuffix,
+          "evm",
+          seller.publicKey
+        )
+        .accounts({ guardian: guardian.publicKey })
+        .signers([guardian])
+        .rpc();
+      expect.fail("Recovery by the seller's guardians should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "StaleGuardianSet"
+      );
+    }
+    console.log("Stale guardian set rejected");
+  });
+
+  it("Blocks guardian recovery of a listed alias", async () => {
+    console.log("Testing recovery of a listed alias...");
+    const seller = await createAndFundKeypair();
+    const guardian = Keypair.generate();
+    await registerTestAlias("listed-guarded", seller);
+
+    await program.methods
+      .setGuardians(
+        "listed-guarded",
+        projectSuffix,
+        "evm",
+        [guardian.publicKey],
+        1
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+    await program.methods
+      .listAlias(
+        "listed-guarded",
+        projectSuffix,
+        "evm",
+        new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+
+    try {
+      await program.methods
+        .initiateRecovery(
+          "listed-guarded",
+          projectSuffix,
+          "evm",
+          guardian.publicKey
+        )
+        .accounts({ guardian: guardian.publicKey })
+        .signers([guardian])
+        .rpc();
+      expect.fail("Recovery of a listed alias should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "StaleGuardianSet"
+      );
+    }
+    console.log("Recovery of a listed alias rejected");
+  });
+
+  it("Drops delegated keys when the alias changes hands", async () => {
+    console.log("Testing alias key owner binding...");
+    const seller = await createAndFundKeypair();
+    const signingKey = Keypair.generate();
+    const aliasPda = await registerTestAlias("keyed-sale", seller);
+
+    await program.methods
+      .addKey(
+        "keyed-sale",
+        projectSuffix,
+        "evm",
+        { signing: {} },
+        signingKey.publicKey
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+    await program.methods
+      .transferAlias(
+        "keyed-sale",
+        projectSuffix,
+        "evm",
+        Keypair.generate().publicKey
+      )
+      .accounts({
+        owner: seller.publicKey,
+        productOwner: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([seller])
+      .rpc();
+
+    const [aliasKeysPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("keys"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const challenge = createHash("sha256").update("handover").digest();
+    const message = createHash("sha256")
+      .update(aliasPda.toBuffer())
+      .update(Buffer.alloc(8))
+      .update(challenge)
+      .digest();
+    try {
+      await program.methods
+        .verifyAliasOwnership(
+          "keyed-sale",
+          projectSuffix,
+          "evm",
+          { signing: {} },
+          Array.from(challenge)
+        )
+        .accounts({ aliasKeys: aliasKeysPda, session: null })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: signingKey.secretKey,
+            message,
+          }),
+        ])
+        .rpc();
+      expect.fail("Proof from the seller's key should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "SignatureVerificationFailed"
+      );
+    }
+    console.log("Previous owner's delegated key rejected");
+  });
+
+  it("Ends session keys when the alias changes hands", async () => {
+    console.log("Testing session key owner binding...");
+    const seller = await createAndFundKeypair();
+    const sessionKeypair = await createAndFundKeypair();
+    const aliasPda = await registerTestAlias("session-sale", seller);
+    const [sessionPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("session"),
+        aliasPda.toBuffer(),
+        sessionKeypair.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createSession(
+        "session-sale",
+        projectSuffix,
+        "evm",
+        sessionKeypair.publicKey,
+        2, // SESSION_SCOPE_SET_RECORD
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600)
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+    await program.methods
+      .transferAlias(
+        "session-sale",
+        projectSuffix,
+        "evm",
+        Keypair.generate().publicKey
+      )
+      .accounts({
+        owner: seller.publicKey,
+        productOwner: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([seller])
+      .rpc();
+
+    try {
+      await program.methods
+        .setRecord("session-sale", projectSuffix, "evm", "url", "https://x")
+        .accounts({
+          authority: sessionKeypair.publicKey,
+          session: sessionPda,
+        })
+        .signers([sessionKeypair])
+        .rpc();
+      expect.fail("Session granted by the seller should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "Unauthorized"
+      );
+    }
+    console.log("Previous owner's session rejected");
+  });
+
+  it("Returns rent to the alias owner on a product owner close", async () => {
+    console.log("Testing product owner close...");
+    const holder = await createAndFundKeypair();
+    const closePda = await registerTestAlias("managed-close", holder);
+    const close = (recipient: PublicKey) =>
+      program.methods
+        .closeAlias("managed-close", projectSuffix, "evm")
+        .accounts({
+          authority: productOwnerKeypair.publicKey,
+          recipient,
+          tokenAccount: null,
+          tokenProgram: null,
+          tombstone: null,
+        })
+        .signers([productOwnerKeypair])
+        .rpc();
+
+    try {
+      await close(productOwnerKeypair.publicKey);
+      expect.fail("Closing to the product owner should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "InvalidOwner"
+      );
+    }
+
+    const balanceBefore = await provider.connection.getBalance(
+      holder.publicKey
+    );
+    await close(holder.publicKey);
+    expect(await provider.connection.getAccountInfo(closePda)).to.be.null;
+    expect(
+      await provider.connection.getBalance(holder.publicKey)
+    ).to.be.greaterThan(balanceBefore);
+    console.log("Rent returned to the alias owner");
+  });
+
+  it("Carries chain mappings through a rename and closes them", async () => {
+    console.log("Testing chain mapping tree lifecycle...");
+    const holder = await createAndFundKeypair();
+    const oldPda = await registerTestAlias("mapped-before", holder);
+    const treePda = (alias: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("mapping_tree"), alias.toBuffer()],
+        program.programId
+      )[0];
+
+    await program.methods
+      .addChainMapping("mapped-before", projectSuffix, "evm", {
+        chainType: { evm: {} },
+        chainId: 1,
+        address: Buffer.from("1234567890123456789012345678901234567890", "hex"),
+        label: "",
+        visible: true,
+      })
+      .accounts({
+        authority: holder.publicKey,
+        session: null,
+        chainMappingRegistry: null,
+      })
+      .signers([holder])
+      .rpc();
+    await program.methods
+      .renameAlias("mapped-before", projectSuffix, "evm", "mapped-after")
+      .accounts({
+        owner: holder.publicKey,
+        reservedName: reservedNamePda("mapped-after"),
+        tombstone: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([holder])
+      .rpc();
+
+    const [newPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mapped-after"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    expect(await provider.connection.getAccountInfo(treePda(oldPda))).to.be
+      .null;
+    const tree = await program.account.chainMappingTree.fetch(treePda(newPda));
+    expect(tree.alias.toBase58()).to.equal(newPda.toBase58());
+    expect(tree.tree.nextIndex).to.equal(1);
+
+    await program.methods
+      .closeAlias("mapped-after", projectSuffix, "evm")
+      .accounts({
+        authority: holder.publicKey,
+        recipient: holder.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+        tombstone: null,
+      })
+      .signers([holder])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(treePda(newPda))).to.be
+      .null;
+    console.log("Chain mapping tree moved and closed with the alias");
+  });
+
+  it("Reissues the alias token to the owner it moved to", async () => {
+    console.log("Testing alias token reissue...");
+    const TOKEN_2022 = new PublicKey(
+      "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+    );
+    const ASSOCIATED_TOKEN = new PublicKey(
+      "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+    );
+    const seller = await createAndFundKeypair();
+    const buyer = await createAndFundKeypair();
+    const aliasPda = await registerTestAlias("token-sale", seller);
+    const [mintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("alias_mint"), aliasPda.toBuffer()],
+      program.programId
+    );
+    const tokenAccountOf = (owner: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [owner.toBuffer(), TOKEN_2022.toBuffer(), mintPda.toBuffer()],
+        ASSOCIATED_TOKEN
+      )[0];
+    const mint = (owner: Keypair) =>
+      program.methods
+        .mintAliasToken("token-sale", projectSuffix, "evm")
+        .accounts({
+          owner: owner.publicKey,
+          tokenAccount: tokenAccountOf(owner.publicKey),
+          tokenProgram: TOKEN_2022,
+          associatedTokenProgram: ASSOCIATED_TOKEN,
+        })
+        .signers([owner])
+        .rpc();
+
+    await mint(seller);
+    await program.methods
+      .transferAlias("token-sale", projectSuffix, "evm", buyer.publicKey)
+      .accounts({
+        owner: seller.publicKey,
+        productOwner: null,
+        tokenAccount: tokenAccountOf(seller.publicKey),
+        tokenProgram: TOKEN_2022,
+      })
+      .signers([seller])
+      .rpc();
+    let balance = await provider.connection.getTokenAccountBalance(
+      tokenAccountOf(seller.publicKey)
+    );
+    expect(balance.value.amount).to.equal("0");
+
+    await mint(buyer);
+    balance = await provider.connection.getTokenAccountBalance(
+      tokenAccountOf(buyer.publicKey)
+    );
+    expect(balance.value.amount).to.equal("1");
+    console.log("Alias token reissued to the new owner");
+  });
+
+  it("Leaves current admin and suffix layouts alone", async () => {
+    console.log("Testing layout upgrades...");
+    for (const upgrade of [
+      program.methods.upgradeAdminConfig().accounts({
+        payer: adminKeypair.publicKey,
+      }),
+      program.methods.upgradeSuffixAccount().accounts({
+        payer: adminKeypair.publicKey,
+        suffixAccount: suffixPda,
+      }),
+    ]) {
+      try {
+        await upgrade.signers([adminKeypair]).rpc();
+        expect.fail("upgrading a current layout should have failed");
+      } catch (err) {
+        expect((err as AnchorError).error.errorCode.code).to.equal(
+          "AccountLayoutUpToDate"
+        );
+      }
+    }
+    console.log("Current layouts left unchanged");
+  });
+
+  it("Keeps listed aliases open until the listing ends", async () => {
+    console.log("Testing close of a listed alias...");
+    const seller = await createAndFundKeypair();
+    await registerTestAlias("listed-close", seller);
+    await program.methods
+      .listAlias(
+        "listed-close",
+        projectSuffix,
+        "evm",
+        new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+
+    try {
+      await program.methods
+        .closeAlias("listed-close", projectSuffix, "evm")
+        .accounts({
+          authority: productOwnerKeypair.publicKey,
+          recipient: seller.publicKey,
+          tokenAccount: null,
+          tokenProgram: null,
+          tombstone: null,
+        })
+        .signers([productOwnerKeypair])
+        .rpc();
+      expect.fail("Closing a listed alias should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "AliasListed"
+      );
+    }
+    console.log("Listed alias kept open");
+  });
+
+  it("Refuses to migrate a listed alias", async () => {
+    console.log("Testing migration of a listed alias...");
+    const seller = await createAndFundKeypair();
+    const aliasPda = await registerTestAlias("listed-migrant", seller);
+    const [listingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("listing"), aliasPda.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .listAlias(
+        "listed-migrant",
+        projectSuffix,
+        "evm",
+        new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)
+      )
+      .accounts({ owner: seller.publicKey })
+      .signers([seller])
+      .rpc();
+
+    try {
+      await program.methods
+        .migrateAliasSuffix("listed-migrant", projectSuffix, "evm", "echoid2")
+        .accounts({
+          sourceProductOwner: productOwnerKeypair.publicKey,
+          destinationProductOwner: productOwnerKeypair.publicKey,
+          owner: listingPda,
+          reservedName: reservedNamePda("listed-migrant", "echoid2"),
+          tombstone: null,
+          tokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers([productOwnerKeypair])
+        .rpc();
+      expect.fail("Migrating a listed alias should have failed");
+    } catch (err) {
+      expect((err as AnchorError).error.errorCode.code).to.equal(
+        "AliasListed"
+      );
+    }
+    console.log("Listed alias kept in its suffix");
+  });
+
+  it("Carries text records through a rename and closes them", async () => {
+    console.log("Testing text record lifecycle...");
+    const holder = await createAndFundKeypair();
+    const oldPda = await registerTestAlias("records-before", holder);
+    const recordsPda = (alias: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("records"), alias.toBuffer()],
+        program.programId
+      )[0];
+
+    await program.methods
+      .setRecord("records-before", projectSuffix, "evm", "url", "https://x")
+      .accounts({ authority: holder.publicKey, session: null })
+      .signers([holder])
+      .rpc();
+    await program.methods
+      .renameAlias("records-before", projectSuffix, "evm", "records-after")
+      .accounts({
+        owner: holder.publicKey,
+        reservedName: reservedNamePda("records-after"),
+        tombstone: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([holder])
+      .rpc();
+
+    const [newPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("records-after"),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+    expect(await provider.connection.getAccountInfo(recordsPda(oldPda))).to.be
+      .null;
+    const records = await program.account.aliasRecords.fetch(
+      recordsPda(newPda)
+    );
+    expect(records.alias.toBase58()).to.equal(newPda.toBase58());
+    expect(records.records).to.deep.equal([{ key: "url", value: "https://x" }]);
+
+    await program.methods
+      .closeAlias("records-after", projectSuffix, "evm")
+      .accounts({
+        authority: holder.publicKey,
+        recipient: holder.publicKey,
+        tokenAccount: null,
+        tokenProgram: null,
+        tombstone: null,
+      })
+      .signers([holder])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(recordsPda(newPda))).to.be
+      .null;
+    console.log("Text records moved and closed with the alias");
+  });
+
+  it("Rejects an expired registration consent", async () => {
+    console.log("Testing consent expiry...");
+    const name = "late-consent";
+    const signatureExpiresAt = new anchor.BN(
+      Math.floor(Date.now() / 1000) - 60
+    );
+    const [aliasPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(name),
+        Buffer.from("@"),
+        Buffer.from(projectSuffix),
+        Buffer.from("evm"),
+      ],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .registerAliasesBatch(projectSuffix, [
+          {
+            username: name,
+            suffix: projectSuffix,
+            chainInfo: {
+              name: "evm",
+              address: "0x1234567890123456789012345678901234567890",
+              chainId: 1,
+            },
+            metadata: { name, imageUrl: "" },
+            userAddress: aliasOwnerKeypair.publicKey,
+            salt: null,
+            ownerManaged: true,
+            referrer: null,
+            allowlistProof: null,
+            registrationProof: null,
+            signatureExpiresAt,
+          },
+        ])
+        .accounts({
+          productOwner: productOwnerKeypair.publicKey,
+          payer: productOwnerKeypair.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: aliasPda, isWritable: true, isSigner: false },
+          {
+            pubkey: reservedNamePda(name),
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: aliasOwnerKeypair.secretKey,
+            message: registrationMessage(
+              "registration_consent",
+              name,
+              "evm",
+              aliasOwnerKeypair.publicKey,
+              signatureExpiresAt
+            ),
+          }),
+        ])
+        .signers([productOwnerKeypair])
+        .rpc();
+      expect.fail("registration with expired consent should have failed");
+    } catch (err) {
+      expect(err.toString()).to.include("RegistrationSignatureExpired");
+    }
+    console.log("Expired consent rejected");
+  });
 });